@@ -38,6 +38,32 @@ pub enum ErrorCode {
     #[msg("Invalid shareholder account")]
     InvalidShareholder,
 
+    #[msg("Invalid share mint")]
+    InvalidShareMint,
+
+    #[msg("Invalid share token program")]
+    InvalidShareTokenProgram,
+
+    // Transfer Hook Errors
+    #[msg("Transfer hook called outside of a token transfer")]
+    NotTransferring,
+
+    #[msg("Vault share mint has no transfer hook")]
+    TransferHookNotEnabled,
+
+    #[msg("Share transfers for this vault are settled by the transfer hook")]
+    TransferHookManaged,
+
+    // Lifecycle Errors
+    #[msg("Instruction is not allowed in the vault's current status")]
+    InvalidVaultStatus,
+
+    #[msg("Vault status transition is not allowed")]
+    InvalidStatusTransition,
+
+    #[msg("Vault cannot close while shares are outstanding")]
+    SharesOutstanding,
+
     #[msg("Arithmetic underflow")]
     Underflow,
 
@@ -54,6 +80,149 @@ pub enum ErrorCode {
     #[msg("Invalid treasury account")]
     InvalidTreasury,
 
+    #[msg("Withdrawal exceeds unwithdrawn performance fees")]
+    InsufficientFees,
+
+    #[msg("Fee recipient has not been registered")]
+    FeeRecipientNotSet,
+
+    #[msg("Invalid fee recipient account")]
+    InvalidFeeRecipient,
+
+    #[msg("Depositor is not registered for this vault")]
+    UnauthorizedDepositor,
+
+    // NAV Pricing Errors
+    #[msg("NAV pricing is not enabled for this vault")]
+    NavPricingNotEnabled,
+
+    #[msg("Net asset value must be greater than zero")]
+    InvalidNav,
+
+    #[msg("NAV change bound must be between 1 and 10000 bps")]
+    InvalidNavChangeBound,
+
+    #[msg("NAV update exceeds the allowed change per update")]
+    NavChangeExceedsBound,
+
+    // Emergency Pause Errors
+    #[msg("Instruction is paused")]
+    InstructionPaused,
+
+    #[msg("Invalid pause flags")]
+    InvalidPauseFlags,
+
+    // Compliance Errors
+    #[msg("Investor is not on the vault allowlist")]
+    InvestorNotAllowlisted,
+
+    #[msg("Investor allowlist entry has expired")]
+    AllowlistEntryExpired,
+
+    #[msg("Allowlist expiry must be in the future")]
+    InvalidAllowlistExpiry,
+
+    #[msg("Shareholder is frozen")]
+    ShareholderFrozen,
+
+    #[msg("Shareholder is not frozen")]
+    ShareholderNotFrozen,
+
+    #[msg("Compliance reason code must be non-zero")]
+    InvalidReasonCode,
+
+    #[msg("Share mint has no vault_signer permanent delegate; forced transfers are unsupported")]
+    ForcedTransferUnsupported,
+
+    // Investment Limits Errors
+    #[msg("Mint amount is below the vault minimum")]
+    BelowMinimumMint,
+
+    #[msg("Holder would exceed the per-holder share limit")]
+    HolderShareLimitExceeded,
+
+    #[msg("Vault has reached its maximum number of holders")]
+    HolderCountLimitReached,
+
+    #[msg("Minimum mint amount cannot exceed the per-holder share limit")]
+    InvalidInvestmentLimits,
+
+    // Subscription Window Errors
+    #[msg("Subscription window has not opened yet")]
+    SubscriptionNotStarted,
+
+    #[msg("Subscription window has closed")]
+    SubscriptionEnded,
+
+    #[msg("Subscription end must be in the future and after the start")]
+    InvalidSubscriptionWindow,
+
+    #[msg("Subscription window is still open")]
+    SubscriptionStillOpen,
+
+    // Soft Cap Errors
+    #[msg("Soft cap needs a subscription end and cannot exceed total shares")]
+    InvalidSoftCap,
+
+    #[msg("Soft cap has not been reached")]
+    SoftCapNotReached,
+
+    #[msg("Only the holder can refund a share mint without a vault_signer permanent delegate")]
+    RefundRequiresHolder,
+
+    // Principal Deployment Errors
+    #[msg("Invalid principal operator account")]
+    InvalidPrincipalOperator,
+
+    #[msg("Cannot return more principal than is deployed")]
+    ReturnExceedsDeployed,
+
+    #[msg("Not enough liquid principal: the rest is deployed off-chain")]
+    PrincipalDeployed,
+
+    // Operator Debt Errors
+    #[msg("Operator, installment and interval must be set and the grace period non-negative")]
+    InvalidDebtTerms,
+
+    #[msg("The vault's operator debt account must be supplied")]
+    OperatorDebtRequired,
+
+    #[msg("Operator is in default")]
+    OperatorInDefault,
+
+    #[msg("Operator is not behind its repayment schedule")]
+    DebtNotOverdue,
+
+    // Impairment Errors
+    #[msg("Impairment must rise and stay at or below 10000 bps")]
+    InvalidImpairment,
+
+    #[msg("NAV-priced vaults record losses through update_nav")]
+    WriteDownRequiresFixedPricing,
+
+    // Emergency Exit Errors
+    #[msg("Emergency exit delay must be between 1 and 90 days")]
+    InvalidEmergencyExitDelay,
+
+    #[msg("Emergency exit opens only after the delay following a redemption halt, write-down or operator default")]
+    EmergencyExitNotOpen,
+
+    // Redemption Queue Errors
+    #[msg("Redemption notice period must be between 0 and 180 days")]
+    InvalidRedemptionNoticePeriod,
+
+    #[msg("Redemption request is not queued")]
+    RedemptionNotQueued,
+
+    #[msg("Redemption request is still within its notice period")]
+    RedemptionNotUnlocked,
+
+    #[msg("Cancel queued redemptions first")]
+    RedemptionQueued,
+
+    #[msg("Redeem through request_redemption while a notice period is set or requests are queued")]
+    RedemptionQueueRequired,
+
     // Governance Layer Errors
     #[msg("Unauthorized: caller is not the vault authority")]
     Unauthorized,
@@ -63,4 +232,70 @@ pub enum ErrorCode {
 
     #[msg("Invalid authority: cannot set to zero address")]
     InvalidAuthority,
+
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+
+    // Multisig Errors
+    #[msg("Multisig needs 1-10 unique signers and 1 <= threshold <= signers")]
+    InvalidMultisigConfig,
+
+    #[msg("Signer is not a member of the multisig")]
+    NotMultisigSigner,
+
+    #[msg("Multisig transaction does not belong to this multisig")]
+    InvalidMultisigTransaction,
+
+    #[msg("Signer has already approved this transaction")]
+    AlreadyApproved,
+
+    #[msg("Multisig approval threshold not met")]
+    ThresholdNotMet,
+
+    #[msg("Multisig transaction has already been executed")]
+    TransactionAlreadyExecuted,
+
+    #[msg("Multisig was reconfigured after this transaction was created")]
+    StaleMultisigTransaction,
+
+    #[msg("An account this multisig action needs is missing or does not match")]
+    MultisigAccountMissing,
+
+    // Timelock Errors
+    #[msg("Fee increases must go through a timelocked proposal")]
+    FeeIncreaseRequiresTimelock,
+
+    #[msg("Governance delay is outside the allowed range")]
+    InvalidGovernanceDelay,
+
+    #[msg("Proposal does not belong to this vault")]
+    InvalidProposal,
+
+    #[msg("Proposal is not queued")]
+    ProposalNotQueued,
+
+    #[msg("Proposal timelock has not elapsed")]
+    TimelockNotElapsed,
+
+    // Shareholder Voting Errors
+    #[msg("Another shareholder proposal is still open")]
+    ShareholderProposalActive,
+
+    #[msg("Proposer holds too few shares to open a proposal")]
+    ProposalThresholdNotMet,
+
+    #[msg("Shareholder proposal does not belong to this vault")]
+    InvalidShareholderProposal,
+
+    #[msg("Shareholder proposal is not open")]
+    ShareholderProposalNotOpen,
+
+    #[msg("Voting period has ended")]
+    VotingClosed,
+
+    #[msg("Voting period has not ended")]
+    VotingNotEnded,
+
+    #[msg("Voter held no shares when the proposal was created")]
+    NoVotingPower,
 }
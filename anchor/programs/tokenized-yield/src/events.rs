@@ -0,0 +1,659 @@
+use anchor_lang::prelude::*;
+
+use crate::{GovernanceAction, MultisigAction, ShareholderAction, ShareholderProposalStatus, VaultStatus};
+
+// =========================================================================
+// VAULT LIFECYCLE EVENTS
+// =========================================================================
+
+/// Emitted once when a vault and its PDAs are created
+#[event]
+pub struct VaultInitialized {
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    pub vault_id: u64,
+    pub name: String,
+    pub vault_share_mint: Pubkey,
+    pub payment_mint: Pubkey,
+    pub principal_vault: Pubkey,
+    pub revenue_vault: Pubkey,
+    pub treasury: Pubkey,
+    pub total_shares: u64,
+    pub price_per_share: u64,
+    pub performance_fee_bps: u16,
+    pub transfer_hook_enabled: bool,
+    pub subscription_start: i64,
+    pub subscription_end: i64,
+    pub soft_cap: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an investor buys shares
+#[event]
+pub struct SharesMinted {
+    pub vault: Pubkey,
+    pub investor: Pubkey,
+    /// Shares minted in this call
+    pub amount: u64,
+    /// Principal paid into the principal vault
+    pub payment_amount: u64,
+    /// Pending rewards settled to the investor before minting
+    pub rewards_paid: u64,
+    /// Investor's share quantity after the mint
+    pub investor_quantity: u64,
+    /// Vault minted supply after the mint
+    pub minted_shares: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when revenue is pushed into the vault and split with the treasury
+#[event]
+pub struct RevenueDeposited {
+    pub vault: Pubkey,
+    pub depositor: Pubkey,
+    /// Gross revenue paid by the depositor
+    pub amount: u64,
+    /// Portion credited to the treasury (net of Token-2022 transfer fees)
+    pub performance_fee: u64,
+    /// Portion credited to shareholders (net of Token-2022 transfer fees)
+    pub distributable_amount: u64,
+    /// Accumulator value after this deposit
+    pub acc_reward_per_share: u128,
+    /// Remainder carried after this deposit
+    pub reward_remainder: u128,
+    /// Lifetime fees after this deposit
+    pub total_fees_collected: u64,
+    pub minted_shares: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a shareholder claims pending rewards
+#[event]
+pub struct Harvested {
+    pub vault: Pubkey,
+    pub investor: Pubkey,
+    pub amount: u64,
+    /// Reward debt checkpoint after the claim
+    pub reward_debt: u128,
+    pub acc_reward_per_share: u128,
+    /// Operator is behind its debt repayment schedule
+    pub operator_overdue: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when shares are burned for principal
+#[event]
+pub struct SharesRedeemed {
+    pub vault: Pubkey,
+    pub investor: Pubkey,
+    /// Shares burned in this call
+    pub amount: u64,
+    /// Principal returned from the principal vault
+    pub principal: u64,
+    /// Pending rewards settled to the investor before burning
+    pub rewards_paid: u64,
+    /// Investor's share quantity after the redemption
+    pub investor_quantity: u64,
+    /// Vault minted supply after the redemption
+    pub minted_shares: u64,
+    /// Operator is behind its debt repayment schedule
+    pub operator_overdue: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when shares and their reward entitlement move between holders
+#[event]
+pub struct SharesTransferred {
+    pub vault: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    /// Sender's share quantity after the transfer
+    pub from_quantity: u64,
+    /// Recipient's share quantity after the transfer
+    pub to_quantity: u64,
+    pub timestamp: i64,
+}
+
+// =========================================================================
+// GOVERNANCE EVENTS
+// =========================================================================
+
+/// Emitted on every lifecycle transition made with `set_vault_status`
+#[event]
+pub struct VaultStatusChanged {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub old_status: VaultStatus,
+    pub new_status: VaultStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeUpdated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub old_fee_bps: u16,
+    pub new_fee_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryUpdated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub old_treasury: Pubkey,
+    pub new_treasury: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceDelayUpdated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub old_delay: i64,
+    pub new_delay: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a timelocked governance change is queued
+#[event]
+pub struct GovernanceChangeQueued {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: GovernanceAction,
+    /// Earliest execution time
+    pub eta: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceChangeExecuted {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub executor: Pubkey,
+    pub action: GovernanceAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceChangeCancelled {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ShareholderProposalCreated {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: ShareholderAction,
+    /// Total voting power (`minted_shares` at creation)
+    pub snapshot_supply: u64,
+    pub voting_ends_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ShareholderVoteCast {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub support: bool,
+    pub weight: u64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a shareholder proposal is executed, defeated or expires
+#[event]
+pub struct ShareholderProposalFinalized {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub executor: Pubkey,
+    pub action: ShareholderAction,
+    pub status: ShareholderProposalStatus,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeRecipientUpdated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub old_fee_recipient: Pubkey,
+    pub new_fee_recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a revenue depositor is registered or removed
+#[event]
+pub struct RevenueDepositorUpdated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub depositor: Pubkey,
+    pub approved: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OpenRevenueDepositsUpdated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub open: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when performance fees leave the treasury
+#[event]
+pub struct FeesWithdrawn {
+    pub vault: Pubkey,
+    /// Authority for `withdraw_fees`, cranker for `sweep_fees`
+    pub caller: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    /// Lifetime withdrawals after this call
+    pub total_fees_withdrawn: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority switches a vault to NAV pricing or changes its oracle/bound
+#[event]
+pub struct NavPricingConfigured {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub nav_oracle: Pubkey,
+    pub max_nav_change_bps: u16,
+    /// NAV after configuration (seeded from fixed pricing on first enable)
+    pub net_asset_value: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a new valuation is posted
+#[event]
+pub struct NavUpdated {
+    pub vault: Pubkey,
+    /// Authority or NAV oracle that posted the valuation
+    pub updater: Pubkey,
+    pub old_nav: u64,
+    pub new_nav: u64,
+    pub minted_shares: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardianUpdated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub old_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted by `pause` and `unpause`
+#[event]
+pub struct PauseUpdated {
+    pub vault: Pubkey,
+    /// Guardian or authority for `pause`, authority for `unpause`
+    pub caller: Pubkey,
+    /// PAUSE_* flags changed by this call
+    pub flags: u8,
+    pub paused: bool,
+    /// Full pause bitmask after this call
+    pub paused_instructions: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ComplianceAuthorityUpdated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub old_compliance_authority: Pubkey,
+    pub new_compliance_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintAllowlistRequiredUpdated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub required: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when an investor allowlist entry is written or removed
+#[event]
+pub struct InvestorAllowlistUpdated {
+    pub vault: Pubkey,
+    /// Compliance authority or vault authority
+    pub officer: Pubkey,
+    pub investor: Pubkey,
+    pub allowed: bool,
+    pub expires_at: i64,
+    pub jurisdiction: [u8; 2],
+    pub timestamp: i64,
+}
+
+/// Emitted when compliance freezes or unfreezes a holder
+#[event]
+pub struct ShareholderFreezeUpdated {
+    pub vault: Pubkey,
+    /// Compliance authority or vault authority
+    pub officer: Pubkey,
+    pub holder: Pubkey,
+    pub share_account: Pubkey,
+    pub frozen: bool,
+    /// Reason code of the freeze being applied or lifted
+    pub reason: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted with every `ForcedTransferRecord`
+#[event]
+pub struct SharesForceTransferred {
+    pub vault: Pubkey,
+    pub record: Pubkey,
+    pub record_id: u64,
+    pub officer: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub reason: u16,
+    pub reference: [u8; 32],
+    pub from_quantity: u64,
+    pub to_quantity: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority changes the investment limits
+#[event]
+pub struct InvestmentLimitsUpdated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub min_mint_amount: u64,
+    pub max_shares_per_holder: u64,
+    pub max_holder_count: u32,
+    pub holder_count: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority pushes back the end of the subscription window
+#[event]
+pub struct SubscriptionExtended {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub old_subscription_end: i64,
+    pub new_subscription_end: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a closed subscription is settled against the soft cap
+#[event]
+pub struct SubscriptionFinalized {
+    pub vault: Pubkey,
+    pub cranker: Pubkey,
+    pub minted_shares: u64,
+    pub soft_cap: u64,
+    pub new_status: VaultStatus,
+    pub timestamp: i64,
+}
+
+/// Emitted when a holder's position is unwound after a failed raise
+#[event]
+pub struct SharesRefunded {
+    pub vault: Pubkey,
+    pub holder: Pubkey,
+    pub cranker: Pubkey,
+    /// Shares burned, the holder's whole position
+    pub amount: u64,
+    /// Principal returned from the principal vault
+    pub principal: u64,
+    /// Vault minted supply after the refund
+    pub minted_shares: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a timelocked change registers a new principal operator account
+#[event]
+pub struct PrincipalOperatorUpdated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub old_operator: Pubkey,
+    pub new_operator: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when principal is sent to the operator to be deployed off-chain
+#[event]
+pub struct PrincipalReleased {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub operator: Pubkey,
+    pub amount: u64,
+    /// Principal held off-chain after the release
+    pub principal_deployed: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when deployed principal comes back into the principal vault
+#[event]
+pub struct PrincipalReturned {
+    pub vault: Pubkey,
+    pub payer: Pubkey,
+    /// Amount credited to the principal vault
+    pub amount: u64,
+    /// Principal held off-chain after the return
+    pub principal_deployed: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority opens the operator debt ledger
+#[event]
+pub struct OperatorDebtInitialized {
+    pub vault: Pubkey,
+    pub operator_debt: Pubkey,
+    pub authority: Pubkey,
+    pub operator: Pubkey,
+    pub interest_rate_bps: u16,
+    pub installment_amount: u64,
+    pub payment_interval: i64,
+    pub first_due_at: i64,
+    pub grace_period: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an operator payment through `deposit_revenue` settles interest
+#[event]
+pub struct OperatorInterestPaid {
+    pub vault: Pubkey,
+    pub operator: Pubkey,
+    /// Part of the deposit applied to accrued interest
+    pub amount: u64,
+    pub accrued_interest: u64,
+    pub interest_paid: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority declares the operator in default
+#[event]
+pub struct OperatorDefaultMarked {
+    pub vault: Pubkey,
+    pub operator_debt: Pubkey,
+    pub authority: Pubkey,
+    pub principal_deployed: u64,
+    pub accrued_interest: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when principal is written down; `loss` is the par value removed by this call
+#[event]
+pub struct PrincipalWrittenDown {
+    pub vault: Pubkey,
+    pub updater: Pubkey,
+    pub old_impairment_bps: u16,
+    pub new_impairment_bps: u16,
+    pub loss: u64,
+    pub principal_written_down: u64,
+    pub minted_shares: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the emergency exit delay changes
+#[event]
+pub struct EmergencyExitDelayUpdated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub old_delay: i64,
+    pub new_delay: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a holder leaves through `emergency_withdraw`
+#[event]
+pub struct EmergencyWithdrawn {
+    pub vault: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub principal_paid: u64,
+    /// The holder's own accrued rewards
+    pub revenue_paid: u64,
+    /// Accrued rewards the revenue vault could not cover
+    pub rewards_forfeited: u64,
+    pub minted_shares: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the redemption notice period changes
+#[event]
+pub struct RedemptionNoticePeriodUpdated {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub old_notice_period: i64,
+    pub new_notice_period: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a holder queues shares for redemption
+#[event]
+pub struct RedemptionRequested {
+    pub vault: Pubkey,
+    pub request_id: u64,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub unlock_at: i64,
+    pub queued_shares: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the queue head is filled, or skipped because it was cancelled or its holder frozen
+#[event]
+pub struct RedemptionProcessed {
+    pub vault: Pubkey,
+    pub request_id: u64,
+    pub holder: Pubkey,
+    pub cranker: Pubkey,
+    pub amount: u64,
+    pub filled: bool,
+    pub principal_paid: u64,
+    pub minted_shares: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a holder withdraws a queued redemption and gets the shares back
+#[event]
+pub struct RedemptionCancelled {
+    pub vault: Pubkey,
+    pub request_id: u64,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub queued_shares: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a multisig is created or reconfigured
+#[event]
+pub struct MultisigConfigured {
+    pub vault: Pubkey,
+    pub multisig: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub config_version: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionCreated {
+    pub vault: Pubkey,
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub transaction_id: u64,
+    pub proposer: Pubkey,
+    pub action: MultisigAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionApproved {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub transaction_id: u64,
+    pub approver: Pubkey,
+    /// Approvals collected after this one
+    pub approvals: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionExecuted {
+    pub vault: Pubkey,
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub transaction_id: u64,
+    pub executor: Pubkey,
+    pub action: MultisigAction,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority nominates a successor
+#[event]
+pub struct AuthorityProposed {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PendingAuthorityCancelled {
+    pub vault: Pubkey,
+    pub authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the proposed authority accepts
+#[event]
+pub struct AuthorityTransferred {
+    pub vault: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityRevoked {
+    pub vault: Pubkey,
+    pub old_authority: Pubkey,
+    pub timestamp: i64,
+}
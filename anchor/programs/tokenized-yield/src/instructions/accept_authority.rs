@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::{Vault, error::ErrorCode, events::AuthorityTransferred};
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// The proposed authority, proving it controls the key
+    #[account(
+        constraint = new_authority.key() == vault.pending_authority @ ErrorCode::Unauthorized
+    )]
+    pub new_authority: Signer<'info>,
+}
+
+pub fn process_accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    require!(
+        vault.pending_authority != Pubkey::default(),
+        ErrorCode::NoPendingAuthority
+    );
+
+    // Complete the handover
+    let old_authority = vault.authority;
+    vault.authority = vault.pending_authority;
+    vault.pending_authority = Pubkey::default();
+
+    emit!(AuthorityTransferred {
+        vault: vault.key(),
+        old_authority,
+        new_authority: vault.authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::{RevenueDepositor, Vault, error::ErrorCode, events::RevenueDepositorUpdated};
+
+#[derive(Accounts)]
+#[instruction(depositor: Pubkey)]
+pub struct AddRevenueDepositor<'info> {
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"revenue_depositor", vault.key().as_ref(), depositor.as_ref()],
+        bump,
+        space = 8 + RevenueDepositor::INIT_SPACE
+    )]
+    pub depositor_approval: Account<'info, RevenueDepositor>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers a wallet (e.g. a farm operator or settlement bot) as a revenue depositor
+pub fn process_add_revenue_depositor(ctx: Context<AddRevenueDepositor>, depositor: Pubkey) -> Result<()> {
+    apply_add_revenue_depositor(
+        &ctx.accounts.vault,
+        ctx.accounts.authority.key(),
+        &mut ctx.accounts.depositor_approval,
+        depositor,
+        ctx.bumps.depositor_approval,
+    )
+}
+
+/// Shared with multisig execution
+pub(crate) fn apply_add_revenue_depositor(
+    vault: &Account<Vault>,
+    authority: Pubkey,
+    approval: &mut RevenueDepositor,
+    depositor: Pubkey,
+    bump: u8,
+) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    approval.vault = vault.key();
+    approval.depositor = depositor;
+    approval.added_at = now;
+    approval.bump = bump;
+
+    emit!(RevenueDepositorUpdated {
+        vault: vault.key(),
+        authority,
+        depositor,
+        approved: true,
+        timestamp: now,
+    });
+
+    Ok(())
+}
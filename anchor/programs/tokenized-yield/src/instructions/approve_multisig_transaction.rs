@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::{Multisig, MultisigTransaction, error::ErrorCode, events::MultisigTransactionApproved};
+
+#[derive(Accounts)]
+pub struct ApproveMultisigTransaction<'info> {
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        constraint = transaction.multisig == multisig.key() @ ErrorCode::InvalidMultisigTransaction
+    )]
+    pub transaction: Account<'info, MultisigTransaction>,
+
+    pub approver: Signer<'info>,
+}
+
+pub fn process_approve_multisig_transaction(ctx: Context<ApproveMultisigTransaction>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let transaction = &mut ctx.accounts.transaction;
+
+    require!(!transaction.executed, ErrorCode::TransactionAlreadyExecuted);
+    require!(
+        transaction.config_version == multisig.config_version,
+        ErrorCode::StaleMultisigTransaction
+    );
+
+    let index = multisig
+        .signer_index(&ctx.accounts.approver.key())
+        .ok_or(ErrorCode::NotMultisigSigner)?;
+    let bit = 1u16 << index;
+    require!(transaction.approvals & bit == 0, ErrorCode::AlreadyApproved);
+
+    transaction.approvals |= bit;
+
+    // Start the governance delay the moment the threshold is first met
+    let now = Clock::get()?.unix_timestamp;
+    if transaction.approved_at == 0 && transaction.approval_count() >= multisig.threshold as u32 {
+        transaction.approved_at = now;
+    }
+
+    emit!(MultisigTransactionApproved {
+        multisig: multisig.key(),
+        transaction: transaction.key(),
+        transaction_id: transaction.transaction_id,
+        approver: ctx.accounts.approver.key(),
+        approvals: transaction.approval_count() as u8,
+        threshold: multisig.threshold,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::{GovernanceProposal, ProposalStatus, Vault, error::ErrorCode, events::GovernanceChangeCancelled};
+
+#[derive(Accounts)]
+pub struct CancelGovernanceChange<'info> {
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", vault.key().as_ref(), proposal.proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.vault == vault.key() @ ErrorCode::InvalidProposal
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+}
+
+pub fn process_cancel_governance_change(ctx: Context<CancelGovernanceChange>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    apply_cancel_governance_change(&ctx.accounts.vault, &mut ctx.accounts.proposal, authority)
+}
+
+/// Shared with multisig execution
+pub(crate) fn apply_cancel_governance_change(
+    vault: &Account<Vault>,
+    proposal: &mut Account<GovernanceProposal>,
+    authority: Pubkey,
+) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+    require!(proposal.status == ProposalStatus::Queued, ErrorCode::ProposalNotQueued);
+
+    proposal.status = ProposalStatus::Cancelled;
+
+    emit!(GovernanceChangeCancelled {
+        vault: vault.key(),
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
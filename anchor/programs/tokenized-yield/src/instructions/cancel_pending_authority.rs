@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::{Vault, error::ErrorCode, events::PendingAuthorityCancelled};
+
+#[derive(Accounts)]
+pub struct CancelPendingAuthority<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn process_cancel_pending_authority(ctx: Context<CancelPendingAuthority>) -> Result<()> {
+    apply_cancel_pending_authority(&mut ctx.accounts.vault, ctx.accounts.authority.key())
+}
+
+/// Shared with multisig execution
+pub(crate) fn apply_cancel_pending_authority(vault: &mut Account<Vault>, authority: Pubkey) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    require!(
+        vault.pending_authority != Pubkey::default(),
+        ErrorCode::NoPendingAuthority
+    );
+
+    let cancelled_authority = vault.pending_authority;
+    vault.pending_authority = Pubkey::default();
+
+    emit!(PendingAuthorityCancelled {
+        vault: vault.key(),
+        authority,
+        cancelled_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
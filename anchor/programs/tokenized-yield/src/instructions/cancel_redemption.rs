@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, MintTo, TokenAccount, TokenInterface, mint_to};
+use crate::{
+    RedemptionRequest, RedemptionStatus, UserStake, Vault, error::ErrorCode, events::RedemptionCancelled,
+};
+
+#[derive(Accounts)]
+pub struct CancelRedemption<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: PDA signer: share mint authority
+    #[account(
+        seeds = [b"vault_signer", vault.key().as_ref()],
+        bump = vault.signer_bump
+    )]
+    pub vault_signer: UncheckedAccount<'info>,
+
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"redemption_request", vault.key().as_ref(), request.request_id.to_le_bytes().as_ref()],
+        bump = request.bump,
+        constraint = request.vault == vault.key() @ ErrorCode::RedemptionNotQueued,
+        constraint = request.holder == holder.key() @ ErrorCode::Unauthorized
+    )]
+    pub request: Account<'info, RedemptionRequest>,
+
+    #[account(
+        mut,
+        seeds = [b"shareholder", vault.key().as_ref(), holder.key().as_ref()],
+        bump = shareholder.bump,
+        constraint = shareholder.vault == vault.key() @ ErrorCode::InvalidShareholder
+    )]
+    pub shareholder: Account<'info, UserStake>,
+
+    #[account(
+        mut,
+        constraint = holder_share_account.mint == vault.vault_share_mint @ ErrorCode::InvalidShareMint,
+        constraint = holder_share_account.owner == holder.key() @ ErrorCode::InvalidTokenAccountOwner
+    )]
+    pub holder_share_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_share_mint", vault.key().as_ref()],
+        bump,
+        mint::token_program = share_token_program
+    )]
+    pub vault_share_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        constraint = share_token_program.key() == vault.share_token_program @ ErrorCode::InvalidShareTokenProgram
+    )]
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Takes a queued request out of the queue and re-mints its shares to the
+/// holder. Works while paused, so holders can unlock their shares before an
+/// emergency exit. Rewards need no settling: the shares never left `quantity`.
+pub fn process_cancel_redemption(ctx: Context<CancelRedemption>) -> Result<()> {
+    require!(ctx.accounts.request.status == RedemptionStatus::Queued, ErrorCode::RedemptionNotQueued);
+    require!(!ctx.accounts.shareholder.frozen, ErrorCode::ShareholderFrozen);
+
+    apply_cancel_redemption(
+        &mut ctx.accounts.vault,
+        &mut ctx.accounts.shareholder,
+        &mut ctx.accounts.request,
+        &ctx.accounts.holder_share_account,
+        &ctx.accounts.vault_share_mint,
+        &ctx.accounts.share_token_program,
+        &ctx.accounts.vault_signer,
+    )
+}
+
+/// Shared with `process_redemptions`, which cancels the requests of frozen holders
+pub(crate) fn apply_cancel_redemption<'info>(
+    vault: &mut Account<'info, Vault>,
+    shareholder: &mut Account<'info, UserStake>,
+    request: &mut Account<'info, RedemptionRequest>,
+    holder_share_account: &InterfaceAccount<'info, TokenAccount>,
+    vault_share_mint: &InterfaceAccount<'info, Mint>,
+    share_token_program: &Interface<'info, TokenInterface>,
+    vault_signer: &UncheckedAccount<'info>,
+) -> Result<()> {
+    let amount = request.amount;
+
+    // Unlock the shares (Effects)
+    shareholder.queued_quantity = shareholder.queued_quantity
+        .checked_sub(amount)
+        .ok_or(ErrorCode::Underflow)?;
+    vault.queued_shares = vault.queued_shares
+        .checked_sub(amount)
+        .ok_or(ErrorCode::Underflow)?;
+    request.status = RedemptionStatus::Cancelled;
+
+    // Re-mint the share tokens (Interactions)
+    let vault_key = vault.key();
+    let seeds = &[b"vault_signer".as_ref(), vault_key.as_ref(), &[vault.signer_bump]];
+    let signer = &[&seeds[..]];
+
+    mint_to(
+        CpiContext::new_with_signer(
+            share_token_program.to_account_info(),
+            MintTo {
+                mint: vault_share_mint.to_account_info(),
+                to: holder_share_account.to_account_info(),
+                authority: vault_signer.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    emit!(RedemptionCancelled {
+        vault: vault_key,
+        request_id: request.request_id,
+        holder: request.holder,
+        amount,
+        queued_shares: vault.queued_shares,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::{ShareholderProposal, ShareholderProposalStatus, ShareholderVote, UserStake, Vault, error::ErrorCode};
+use crate::events::ShareholderVoteCast;
+
+#[derive(Accounts)]
+pub struct CastShareholderVote<'info> {
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [b"shareholder_proposal", vault.key().as_ref(), proposal.proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.vault == vault.key() @ ErrorCode::InvalidShareholderProposal
+    )]
+    pub proposal: Account<'info, ShareholderProposal>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [b"shareholder", vault.key().as_ref(), voter.key().as_ref()],
+        bump = voter_stake.bump,
+        constraint = voter_stake.vault == vault.key() @ ErrorCode::InvalidShareholder
+    )]
+    pub voter_stake: Account<'info, UserStake>,
+
+    /// One vote per holder per proposal
+    #[account(
+        init,
+        payer = voter,
+        seeds = [b"shareholder_vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+        space = 8 + ShareholderVote::INIT_SPACE
+    )]
+    pub vote: Account<'info, ShareholderVote>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Votes with the holder's full snapshotted weight
+pub fn process_cast_shareholder_vote(ctx: Context<CastShareholderVote>, support: bool) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let vote = &mut ctx.accounts.vote;
+
+    require!(
+        proposal.status == ShareholderProposalStatus::Voting,
+        ErrorCode::ShareholderProposalNotOpen
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < proposal.voting_ends_at, ErrorCode::VotingClosed);
+
+    let weight = ctx.accounts.voter_stake.voting_power(proposal.key());
+    require!(weight > 0, ErrorCode::NoVotingPower);
+
+    if support {
+        proposal.votes_for = proposal.votes_for
+            .checked_add(weight)
+            .ok_or(ErrorCode::Overflow)?;
+    } else {
+        proposal.votes_against = proposal.votes_against
+            .checked_add(weight)
+            .ok_or(ErrorCode::Overflow)?;
+    }
+
+    vote.proposal = proposal.key();
+    vote.voter = ctx.accounts.voter.key();
+    vote.support = support;
+    vote.weight = weight;
+    vote.bump = ctx.bumps.vote;
+
+    emit!(ShareholderVoteCast {
+        vault: ctx.accounts.vault.key(),
+        proposal: proposal.key(),
+        voter: vote.voter,
+        support,
+        weight,
+        votes_for: proposal.votes_for,
+        votes_against: proposal.votes_against,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::{NavHistory, Vault, error::ErrorCode, events::NavPricingConfigured};
+use crate::states::vault::MAX_NAV_CHANGE_BPS;
+
+#[derive(Accounts)]
+pub struct ConfigureNavPricing<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"nav_history", vault.key().as_ref()],
+        bump,
+        space = 8 + NavHistory::INIT_SPACE
+    )]
+    pub nav_history: Account<'info, NavHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_configure_nav_pricing(
+    ctx: Context<ConfigureNavPricing>,
+    nav_oracle: Pubkey,
+    max_nav_change_bps: u16,
+) -> Result<()> {
+    apply_nav_pricing(
+        &mut ctx.accounts.vault,
+        ctx.accounts.authority.key(),
+        &mut ctx.accounts.nav_history,
+        ctx.bumps.nav_history,
+        nav_oracle,
+        max_nav_change_bps,
+    )
+}
+
+/// Shared with multisig execution
+pub(crate) fn apply_nav_pricing(
+    vault: &mut Account<Vault>,
+    authority: Pubkey,
+    nav_history: &mut NavHistory,
+    nav_history_bump: u8,
+    nav_oracle: Pubkey,
+    max_nav_change_bps: u16,
+) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    require!(
+        max_nav_change_bps > 0 && max_nav_change_bps <= MAX_NAV_CHANGE_BPS,
+        ErrorCode::InvalidNavChangeBound
+    );
+
+    if nav_history.vault == Pubkey::default() {
+        nav_history.vault = vault.key();
+        nav_history.bump = nav_history_bump;
+    }
+
+    // Switching from fixed pricing seeds NAV with the principal backing minted shares,
+    // net of any write-down, so the first NAV-priced mint or redeem happens at the old fixed price.
+    let now = Clock::get()?.unix_timestamp;
+    if !vault.nav_pricing_enabled {
+        vault.net_asset_value = vault
+            .fixed_price_value(vault.minted_shares, false)
+            .ok_or(ErrorCode::MathOverflow)?;
+        vault.nav_pricing_enabled = true;
+        vault.nav_updated_at = now;
+    }
+
+    vault.nav_oracle = nav_oracle;
+    vault.max_nav_change_bps = max_nav_change_bps;
+
+    emit!(NavPricingConfigured {
+        vault: vault.key(),
+        authority,
+        nav_oracle,
+        max_nav_change_bps,
+        net_asset_value: vault.net_asset_value,
+        timestamp: now,
+    });
+
+    Ok(())
+}
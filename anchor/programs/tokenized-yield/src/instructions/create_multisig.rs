@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::{Multisig, Vault, error::ErrorCode};
+use crate::events::{AuthorityTransferred, MultisigConfigured};
+use crate::states::multisig::MAX_MULTISIG_SIGNERS;
+
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Re-running on an existing multisig reconfigures it and invalidates
+    /// transactions created under the previous signer set
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"multisig", vault.key().as_ref()],
+        bump,
+        space = 8 + Multisig::INIT_SPACE
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Configures an M-of-N signer set and hands `vault.authority` to it.
+/// The multisig PDA is program-derived, so no acceptance step is needed.
+pub fn process_create_multisig(
+    ctx: Context<CreateMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let multisig = &mut ctx.accounts.multisig;
+
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    require!(
+        !signers.is_empty() && signers.len() <= MAX_MULTISIG_SIGNERS,
+        ErrorCode::InvalidMultisigConfig
+    );
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        ErrorCode::InvalidMultisigConfig
+    );
+    for (i, signer) in signers.iter().enumerate() {
+        require!(*signer != Pubkey::default(), ErrorCode::InvalidMultisigConfig);
+        require!(!signers[..i].contains(signer), ErrorCode::InvalidMultisigConfig);
+    }
+
+    if multisig.vault == Pubkey::default() {
+        multisig.vault = vault.key();
+        multisig.bump = ctx.bumps.multisig;
+    } else {
+        multisig.config_version = multisig.config_version
+            .checked_add(1)
+            .ok_or(ErrorCode::Overflow)?;
+    }
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+
+    let old_authority = vault.authority;
+    vault.authority = multisig.key();
+    vault.pending_authority = Pubkey::default();
+
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    emit!(MultisigConfigured {
+        vault: vault.key(),
+        multisig: multisig.key(),
+        signers: multisig.signers.clone(),
+        threshold,
+        config_version: multisig.config_version,
+        timestamp,
+    });
+
+    emit!(AuthorityTransferred {
+        vault: vault.key(),
+        old_authority,
+        new_authority: vault.authority,
+        timestamp,
+    });
+
+    Ok(())
+}
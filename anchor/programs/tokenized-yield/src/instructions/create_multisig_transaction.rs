@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use crate::{Multisig, MultisigAction, MultisigTransaction, Vault, error::ErrorCode};
+use crate::events::MultisigTransactionCreated;
+use crate::states::vault::{MAX_NAV_CHANGE_BPS, MAX_PERFORMANCE_FEE_BPS, PAUSE_ALL};
+use super::propose_governance_change::validate_governance_action;
+
+#[derive(Accounts)]
+pub struct CreateMultisigTransaction<'info> {
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [b"multisig", vault.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// Multisig member proposing the action; counts as the first approval
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [b"multisig_tx", multisig.key().as_ref(), multisig.transaction_count.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + MultisigTransaction::INIT_SPACE
+    )]
+    pub transaction: Account<'info, MultisigTransaction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_create_multisig_transaction(
+    ctx: Context<CreateMultisigTransaction>,
+    action: MultisigAction,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let multisig = &mut ctx.accounts.multisig;
+    let transaction = &mut ctx.accounts.transaction;
+
+    require!(vault.authority == multisig.key(), ErrorCode::Unauthorized);
+
+    let index = multisig
+        .signer_index(&ctx.accounts.proposer.key())
+        .ok_or(ErrorCode::NotMultisigSigner)?;
+
+    // Reject actions that could never execute
+    match action {
+        MultisigAction::Governance { action } => validate_governance_action(&action)?,
+        MultisigAction::LowerPerformanceFee { new_fee_bps } => {
+            require!(
+                new_fee_bps <= MAX_PERFORMANCE_FEE_BPS,
+                ErrorCode::PerformanceFeeExceedsMax
+            );
+        }
+        MultisigAction::WithdrawFees { amount } | MultisigAction::ReleasePrincipal { amount } => {
+            require!(amount > 0, ErrorCode::InvalidPaymentAmount);
+        }
+        MultisigAction::InitializeOperatorDebt { operator, installment_amount, payment_interval, grace_period, .. } => {
+            require!(
+                operator != Pubkey::default()
+                    && installment_amount > 0
+                    && payment_interval > 0
+                    && grace_period >= 0,
+                ErrorCode::InvalidDebtTerms
+            );
+        }
+        MultisigAction::ConfigureNavPricing { max_nav_change_bps, .. } => {
+            require!(
+                max_nav_change_bps > 0 && max_nav_change_bps <= MAX_NAV_CHANGE_BPS,
+                ErrorCode::InvalidNavChangeBound
+            );
+        }
+        MultisigAction::Unpause { flags } => {
+            require!(flags != 0 && flags & !PAUSE_ALL == 0, ErrorCode::InvalidPauseFlags);
+        }
+        MultisigAction::ProposeAuthority { new_authority } => {
+            require!(new_authority != Pubkey::default(), ErrorCode::InvalidAuthority);
+        }
+        _ => {}
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+
+    transaction.multisig = multisig.key();
+    transaction.transaction_id = multisig.transaction_count;
+    transaction.proposer = ctx.accounts.proposer.key();
+    transaction.action = action;
+    transaction.approvals = 1 << index;
+    transaction.config_version = multisig.config_version;
+    transaction.approved_at = if multisig.threshold == 1 { now } else { 0 };
+    transaction.executed = false;
+    transaction.bump = ctx.bumps.transaction;
+
+    multisig.transaction_count = multisig.transaction_count
+        .checked_add(1)
+        .ok_or(ErrorCode::Overflow)?;
+
+    emit!(MultisigTransactionCreated {
+        vault: vault.key(),
+        multisig: multisig.key(),
+        transaction: transaction.key(),
+        transaction_id: transaction.transaction_id,
+        proposer: transaction.proposer,
+        action,
+        timestamp: now,
+    });
+
+    Ok(())
+}
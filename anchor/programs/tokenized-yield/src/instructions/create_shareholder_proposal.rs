@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::{ShareholderAction, ShareholderProposal, ShareholderProposalStatus, UserStake, Vault, error::ErrorCode};
+use crate::events::ShareholderProposalCreated;
+use crate::states::shareholder_proposal::{SHAREHOLDER_PROPOSAL_THRESHOLD_BPS, SHAREHOLDER_VOTING_PERIOD};
+use crate::states::vault::{FEE_BPS_DENOMINATOR, MAX_PERFORMANCE_FEE_BPS};
+
+#[derive(Accounts)]
+pub struct CreateShareholderProposal<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [b"shareholder", vault.key().as_ref(), proposer.key().as_ref()],
+        bump = proposer_stake.bump,
+        constraint = proposer_stake.vault == vault.key() @ ErrorCode::InvalidShareholder
+    )]
+    pub proposer_stake: Account<'info, UserStake>,
+
+    #[account(
+        init,
+        payer = proposer,
+        seeds = [b"shareholder_proposal", vault.key().as_ref(), vault.shareholder_proposal_count.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + ShareholderProposal::INIT_SPACE
+    )]
+    pub proposal: Account<'info, ShareholderProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens a share-weighted vote. Voting power is each holder's `UserStake.quantity`
+/// at this moment; works whether or not the vault still has an authority.
+pub fn process_create_shareholder_proposal(
+    ctx: Context<CreateShareholderProposal>,
+    action: ShareholderAction,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(
+        vault.active_shareholder_proposal == Pubkey::default(),
+        ErrorCode::ShareholderProposalActive
+    );
+    require!(vault.minted_shares > 0, ErrorCode::NoSharesMinted);
+
+    // Proposer must hold at least SHAREHOLDER_PROPOSAL_THRESHOLD_BPS of supply
+    let threshold = (vault.minted_shares as u128)
+        .checked_mul(SHAREHOLDER_PROPOSAL_THRESHOLD_BPS as u128)
+        .ok_or(ErrorCode::Overflow)?
+        .checked_div(FEE_BPS_DENOMINATOR as u128)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let held = ctx.accounts.proposer_stake.quantity;
+    require!(held > 0 && held as u128 >= threshold, ErrorCode::ProposalThresholdNotMet);
+
+    // Reject actions that could never execute
+    match action {
+        ShareholderAction::UpdatePerformanceFee { new_fee_bps } => {
+            require!(
+                new_fee_bps <= MAX_PERFORMANCE_FEE_BPS,
+                ErrorCode::PerformanceFeeExceedsMax
+            );
+        }
+        ShareholderAction::UpdateTreasury { new_treasury } => {
+            require!(new_treasury != Pubkey::default(), ErrorCode::InvalidTreasury);
+        }
+        ShareholderAction::ReplaceAuthority { new_authority } => {
+            require!(new_authority != Pubkey::default(), ErrorCode::InvalidAuthority);
+        }
+        ShareholderAction::WindDown => {}
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let voting_ends_at = now
+        .checked_add(SHAREHOLDER_VOTING_PERIOD)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    proposal.vault = vault.key();
+    proposal.proposal_id = vault.shareholder_proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    proposal.status = ShareholderProposalStatus::Voting;
+    proposal.snapshot_supply = vault.minted_shares;
+    proposal.votes_for = 0;
+    proposal.votes_against = 0;
+    proposal.created_at = now;
+    proposal.voting_ends_at = voting_ends_at;
+    proposal.bump = ctx.bumps.proposal;
+
+    // From here on every quantity change snapshots the pre-change balance
+    vault.active_shareholder_proposal = proposal.key();
+    vault.shareholder_proposal_count = vault.shareholder_proposal_count
+        .checked_add(1)
+        .ok_or(ErrorCode::Overflow)?;
+
+    emit!(ShareholderProposalCreated {
+        vault: vault.key(),
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        proposer: proposal.proposer,
+        action,
+        snapshot_supply: proposal.snapshot_supply,
+        voting_ends_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Transfer, transfer};
-use crate::{Vault, error::ErrorCode, constants::PRECISION};
-use crate::states::vault::FEE_BPS_DENOMINATOR;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use crate::{OperatorDebt, RevenueDepositor, Vault, error::ErrorCode, constants::PRECISION};
+use crate::events::{OperatorInterestPaid, RevenueDeposited};
+use crate::transfer_fee::net_amount_received;
+use crate::states::vault::{FEE_BPS_DENOMINATOR, PAUSE_DEPOSIT_REVENUE};
 
 #[derive(Accounts)]
 pub struct DepositRevenue<'info> {
@@ -16,14 +18,14 @@ pub struct DepositRevenue<'info> {
         constraint = payer_ata.mint == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
         constraint = payer_ata.owner == payer.key() @ ErrorCode::InvalidTokenAccountOwner
     )]
-    pub payer_ata: Account<'info, TokenAccount>,
+    pub payer_ata: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         constraint = revenue_vault.key() == vault.revenue_vault @ ErrorCode::InvalidPaymentVault,
         constraint = revenue_vault.owner == vault_signer.key() @ ErrorCode::InvalidPaymentVault
     )]
-    pub revenue_vault: Account<'info, TokenAccount>,
+    pub revenue_vault: InterfaceAccount<'info, TokenAccount>,
 
     /// Treasury account that receives performance fees
     #[account(
@@ -31,7 +33,7 @@ pub struct DepositRevenue<'info> {
         constraint = treasury.key() == vault.treasury @ ErrorCode::InvalidTreasury,
         constraint = treasury.owner == vault_signer.key() @ ErrorCode::InvalidTreasury
     )]
-    pub treasury: Account<'info, TokenAccount>,
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: PDA Signer for payment vault check
     #[account(
@@ -40,14 +42,46 @@ pub struct DepositRevenue<'info> {
     )]
     pub vault_signer: UncheckedAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(
+        constraint = payment_mint.key() == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        mint::token_program = token_program
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Registry entry for `payer`, required unless the vault accepts open deposits
+    #[account(
+        seeds = [b"revenue_depositor", vault.key().as_ref(), payer.key().as_ref()],
+        bump = depositor_approval.bump
+    )]
+    pub depositor_approval: Option<Account<'info, RevenueDepositor>>,
+
+    /// Required once the vault has an operator debt ledger; deposits by the
+    /// operator settle accrued interest
+    #[account(
+        mut,
+        seeds = [b"operator_debt", vault.key().as_ref()],
+        bump = operator_debt.bump
+    )]
+    pub operator_debt: Option<Account<'info, OperatorDebt>>,
 }
 
 pub fn process_deposit_revenue(ctx: Context<DepositRevenue>, amount: u64) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
 
+    require!(vault.status.allows_revenue(), ErrorCode::InvalidVaultStatus);
+    require!(!vault.is_paused(PAUSE_DEPOSIT_REVENUE), ErrorCode::InstructionPaused);
+    require!(
+        vault.open_revenue_deposits || ctx.accounts.depositor_approval.is_some(),
+        ErrorCode::UnauthorizedDepositor
+    );
     require!(vault.minted_shares > 0, ErrorCode::NoSharesMinted);
     require!(amount > 0, ErrorCode::InvalidRevenueAmount);
+    require!(
+        !vault.has_operator_debt || ctx.accounts.operator_debt.is_some(),
+        ErrorCode::OperatorDebtRequired
+    );
 
     // ============================================================
     // PERFORMANCE FEE CALCULATION
@@ -66,21 +100,32 @@ pub fn process_deposit_revenue(ctx: Context<DepositRevenue>, amount: u64) -> Res
         .checked_sub(performance_fee)
         .ok_or(ErrorCode::Underflow)?;
 
+    // Token-2022 transfer fees are withheld in the destination accounts,
+    // so only what actually lands in the treasury / revenue vault is booked.
+    let payment_mint_info = ctx.accounts.payment_mint.to_account_info();
+    let performance_fee_received = net_amount_received(&payment_mint_info, performance_fee)?;
+    let distributable_received = net_amount_received(&payment_mint_info, distributable_amount)?;
+
     // ============================================================
     // TRANSFER PERFORMANCE FEE TO TREASURY (if fee > 0)
     // ============================================================
     if performance_fee > 0 {
-        let cpi_accounts_treasury = Transfer {
+        let cpi_accounts_treasury = TransferChecked {
             from: ctx.accounts.payer_ata.to_account_info(),
+            mint: ctx.accounts.payment_mint.to_account_info(),
             to: ctx.accounts.treasury.to_account_info(),
             authority: ctx.accounts.payer.to_account_info(),
         };
         let cpi_program_treasury = ctx.accounts.token_program.to_account_info();
-        transfer(CpiContext::new(cpi_program_treasury, cpi_accounts_treasury), performance_fee)?;
+        transfer_checked(
+            CpiContext::new(cpi_program_treasury, cpi_accounts_treasury),
+            performance_fee,
+            ctx.accounts.payment_mint.decimals,
+        )?;
 
         // Update total fees collected (for Protocol Revenue Invariant)
         vault.total_fees_collected = vault.total_fees_collected
-            .checked_add(performance_fee)
+            .checked_add(performance_fee_received)
             .ok_or(ErrorCode::Overflow)?;
     }
 
@@ -88,25 +133,66 @@ pub fn process_deposit_revenue(ctx: Context<DepositRevenue>, amount: u64) -> Res
     // TRANSFER DISTRIBUTABLE AMOUNT TO REVENUE VAULT
     // ============================================================
     if distributable_amount > 0 {
-        let cpi_accounts = Transfer {
+        let cpi_accounts = TransferChecked {
             from: ctx.accounts.payer_ata.to_account_info(),
+            mint: ctx.accounts.payment_mint.to_account_info(),
             to: ctx.accounts.revenue_vault.to_account_info(),
             authority: ctx.accounts.payer.to_account_info(),
         };
         let cpi_program = ctx.accounts.token_program.to_account_info();
-        transfer(CpiContext::new(cpi_program, cpi_accounts), distributable_amount)?;
+        transfer_checked(
+            CpiContext::new(cpi_program, cpi_accounts),
+            distributable_amount,
+            ctx.accounts.payment_mint.decimals,
+        )?;
     }
 
     // ============================================================
     // REWARD DISTRIBUTION MATH (UNCHANGED)
-    // Uses distributable_amount ONLY for accumulator update
+    // Uses the distributable amount received ONLY for accumulator update
     // ============================================================
 
     // Skip accumulator update if no distributable amount
-    if distributable_amount == 0 {
-        return Ok(());
+    if distributable_received > 0 {
+        update_reward_accumulator(vault, distributable_received)?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let payer_key = ctx.accounts.payer.key();
+    if let Some(debt) = ctx.accounts.operator_debt.as_mut().filter(|debt| debt.operator == payer_key) {
+        debt.accrue_interest(vault.principal_deployed, now)?;
+        let accrued_before = debt.accrued_interest;
+        // Only the share that reaches holders settles interest; the performance fee
+        // is the manager's and transfer fees withheld on the way never arrive
+        debt.record_interest_payment(distributable_received)?;
+
+        emit!(OperatorInterestPaid {
+            vault: vault.key(),
+            operator: debt.operator,
+            amount: accrued_before - debt.accrued_interest,
+            accrued_interest: debt.accrued_interest,
+            interest_paid: debt.interest_paid,
+            timestamp: now,
+        });
     }
 
+    emit!(RevenueDeposited {
+        vault: vault.key(),
+        depositor: ctx.accounts.payer.key(),
+        amount,
+        performance_fee: performance_fee_received,
+        distributable_amount: distributable_received,
+        acc_reward_per_share: vault.acc_reward_per_share,
+        reward_remainder: vault.reward_remainder,
+        total_fees_collected: vault.total_fees_collected,
+        minted_shares: vault.minted_shares,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+fn update_reward_accumulator(vault: &mut Vault, distributable_amount: u64) -> Result<()> {
     // Multiplication safety bound
     require!(
         (distributable_amount as u128) <= u128::MAX / PRECISION,
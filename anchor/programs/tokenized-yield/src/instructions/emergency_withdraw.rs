@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Burn, Mint, TokenAccount, TokenInterface, TransferChecked, burn, transfer_checked,
+};
+use crate::{OperatorDebt, UserStake, Vault, VaultStatus, error::ErrorCode, events::EmergencyWithdrawn};
+
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: PDA Signer
+    #[account(
+        seeds = [b"vault_signer", vault.key().as_ref()],
+        bump = vault.signer_bump
+    )]
+    pub vault_signer: UncheckedAccount<'info>,
+
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"shareholder", vault.key().as_ref(), holder.key().as_ref()],
+        bump = shareholder.bump,
+        constraint = shareholder.vault == vault.key() @ ErrorCode::InvalidShareholder
+    )]
+    pub shareholder: Account<'info, UserStake>,
+
+    #[account(
+        mut,
+        constraint = principal_vault.key() == vault.principal_vault @ ErrorCode::InvalidPaymentVault,
+        constraint = principal_vault.owner == vault_signer.key() @ ErrorCode::InvalidPaymentVault
+    )]
+    pub principal_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = revenue_vault.key() == vault.revenue_vault @ ErrorCode::InvalidPaymentVault,
+        constraint = revenue_vault.owner == vault_signer.key() @ ErrorCode::InvalidPaymentVault
+    )]
+    pub revenue_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = holder_share_account.mint == vault.vault_share_mint @ ErrorCode::InvalidShareMint,
+        constraint = holder_share_account.owner == holder.key() @ ErrorCode::InvalidTokenAccountOwner
+    )]
+    pub holder_share_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_share_mint", vault.key().as_ref()],
+        bump,
+        mint::token_program = share_token_program
+    )]
+    pub vault_share_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = holder_payment_account.mint == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        constraint = holder_payment_account.owner == holder.key() @ ErrorCode::InvalidTokenAccountOwner
+    )]
+    pub holder_payment_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = payment_mint.key() == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        mint::token_program = token_program
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(
+        constraint = share_token_program.key() == vault.share_token_program @ ErrorCode::InvalidShareTokenProgram
+    )]
+    pub share_token_program: Interface<'info, TokenInterface>,
+
+    /// Required once the vault has an operator debt ledger; a default opens the exit
+    #[account(
+        seeds = [b"operator_debt", vault.key().as_ref()],
+        bump = operator_debt.bump
+    )]
+    pub operator_debt: Option<Account<'info, OperatorDebt>>,
+}
+
+/// Last-resort exit once redemptions have been halted, the vault written down
+/// or the operator declared in default for `emergency_exit_delay`. Burns the
+/// holder's whole position and pays `quantity / minted_shares` of whatever sits
+/// in the principal vault, ignoring pause flags and `price_per_share`, plus the
+/// rewards the holder has accrued so far.
+///
+/// Revenue owed to other holders is never touched. The holder forfeits any
+/// claim on principal still deployed with the operator, which stays with the
+/// remaining holders.
+pub fn process_emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let shareholder = &mut ctx.accounts.shareholder;
+
+    require!(
+        !matches!(vault.status, VaultStatus::Refunding | VaultStatus::Closed),
+        ErrorCode::InvalidVaultStatus
+    );
+    require!(!shareholder.frozen, ErrorCode::ShareholderFrozen);
+
+    let amount = shareholder.quantity;
+    require!(amount > 0, ErrorCode::InsufficientShares);
+    require!(shareholder.queued_quantity == 0, ErrorCode::RedemptionQueued);
+
+    // 1. The exit must have been triggered and the delay served
+    let defaulted_at = match ctx.accounts.operator_debt.as_deref() {
+        Some(debt) => debt.defaulted.then_some(debt.defaulted_at),
+        None => {
+            require!(!vault.has_operator_debt, ErrorCode::OperatorDebtRequired);
+            None
+        }
+    };
+    let now = Clock::get()?.unix_timestamp;
+    let opens_at = vault
+        .emergency_exit_opens_at(defaulted_at)
+        .ok_or(ErrorCode::EmergencyExitNotOpen)?;
+    require!(now >= opens_at, ErrorCode::EmergencyExitNotOpen);
+
+    // 2. Pro-rata slice of the principal, rounded down
+    let minted_shares = vault.minted_shares;
+    let pro_rata = |balance: u64| -> Result<u64> {
+        let share = (balance as u128)
+            .checked_mul(amount as u128)
+            .ok_or(ErrorCode::Overflow)?
+            .checked_div(minted_shares as u128)
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(u64::try_from(share).map_err(|_| ErrorCode::Overflow)?)
+    };
+    let principal_paid = pro_rata(ctx.accounts.principal_vault.amount)?;
+    let nav_released = pro_rata(vault.net_asset_value)?;
+
+    // 3. Settle the holder's own rewards, capped by what the revenue vault holds
+    shareholder.checkpoint_rewards(vault.acc_reward_per_share)?;
+    let revenue_paid = shareholder.pending_rewards.min(ctx.accounts.revenue_vault.amount);
+    let rewards_forfeited = shareholder.pending_rewards - revenue_paid;
+
+    // 4. Drop the position (Effects)
+    let new_minted_shares = minted_shares
+        .checked_sub(amount)
+        .ok_or(ErrorCode::Underflow)?;
+
+    vault.track_holder(amount, 0)?;
+    shareholder.snapshot_voting_power(vault.active_shareholder_proposal);
+    shareholder.pending_rewards = 0;
+    shareholder.rebase_quantity(0, vault.acc_reward_per_share)?;
+    vault.minted_shares = new_minted_shares;
+    if vault.nav_pricing_enabled {
+        vault.net_asset_value = vault
+            .net_asset_value
+            .checked_sub(nav_released)
+            .ok_or(ErrorCode::Underflow)?;
+    }
+
+    // 5. Burn shares, pay out (Interactions)
+    let vault_key = vault.key();
+    let seeds = &[b"vault_signer".as_ref(), vault_key.as_ref(), &[vault.signer_bump]];
+    let signer = &[&seeds[..]];
+
+    burn(
+        CpiContext::new(
+            ctx.accounts.share_token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.vault_share_mint.to_account_info(),
+                from: ctx.accounts.holder_share_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    for (from, paid) in [
+        (&ctx.accounts.principal_vault, principal_paid),
+        (&ctx.accounts.revenue_vault, revenue_paid),
+    ] {
+        if paid == 0 {
+            continue;
+        }
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: from.to_account_info(),
+                    mint: ctx.accounts.payment_mint.to_account_info(),
+                    to: ctx.accounts.holder_payment_account.to_account_info(),
+                    authority: ctx.accounts.vault_signer.to_account_info(),
+                },
+                signer,
+            ),
+            paid,
+            ctx.accounts.payment_mint.decimals,
+        )?;
+    }
+
+    emit!(EmergencyWithdrawn {
+        vault: vault_key,
+        holder: ctx.accounts.holder.key(),
+        amount,
+        principal_paid,
+        revenue_paid,
+        rewards_forfeited,
+        minted_shares: new_minted_shares,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::{GovernanceAction, GovernanceProposal, ProposalStatus, Vault, error::ErrorCode};
+use crate::events::{
+    EmergencyExitDelayUpdated, FeeUpdated, GovernanceChangeExecuted, GovernanceDelayUpdated,
+    PrincipalOperatorUpdated, RedemptionNoticePeriodUpdated, TreasuryUpdated,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteGovernanceChange<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", vault.key().as_ref(), proposal.proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.vault == vault.key() @ ErrorCode::InvalidProposal
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    /// Anyone may execute a proposal once its timelock has elapsed
+    pub executor: Signer<'info>,
+
+    /// New treasury account, required for `UpdateTreasury` proposals
+    pub new_treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: PDA signer for treasury ownership verification
+    #[account(
+        seeds = [b"vault_signer", vault.key().as_ref()],
+        bump = vault.signer_bump
+    )]
+    pub vault_signer: UncheckedAccount<'info>,
+}
+
+/// Applies a governance action to the vault and emits the matching update event.
+/// Callers are responsible for authorization and any timelock.
+pub(crate) fn apply_governance_action<'info>(
+    vault: &mut Account<'info, Vault>,
+    action: &GovernanceAction,
+    caller: Pubkey,
+    new_treasury: Option<&InterfaceAccount<'info, TokenAccount>>,
+    vault_signer: Pubkey,
+) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    match *action {
+        GovernanceAction::UpdatePerformanceFee { new_fee_bps } => {
+            let old_fee_bps = vault.performance_fee_bps;
+            vault.performance_fee_bps = new_fee_bps;
+
+            emit!(FeeUpdated {
+                vault: vault.key(),
+                authority: caller,
+                old_fee_bps,
+                new_fee_bps,
+                timestamp,
+            });
+        }
+        GovernanceAction::UpdateTreasury { new_treasury: expected } => {
+            // The new treasury must preserve capital segregation:
+            // 1. Owned by vault_signer PDA (same authority as revenue/principal vaults)
+            // 2. Using the same payment_mint
+            let treasury = new_treasury.ok_or(ErrorCode::InvalidTreasury)?;
+            require!(treasury.key() == expected, ErrorCode::InvalidTreasury);
+            require!(treasury.mint == vault.payment_mint, ErrorCode::InvalidTreasury);
+            require!(treasury.owner == vault_signer, ErrorCode::InvalidTreasury);
+
+            let old_treasury = vault.treasury;
+            vault.treasury = expected;
+
+            emit!(TreasuryUpdated {
+                vault: vault.key(),
+                authority: caller,
+                old_treasury,
+                new_treasury: expected,
+                timestamp,
+            });
+        }
+        GovernanceAction::UpdateGovernanceDelay { new_delay } => {
+            let old_delay = vault.governance_delay;
+            vault.governance_delay = new_delay;
+
+            emit!(GovernanceDelayUpdated {
+                vault: vault.key(),
+                authority: caller,
+                old_delay,
+                new_delay,
+                timestamp,
+            });
+        }
+        GovernanceAction::UpdatePrincipalOperator { new_operator } => {
+            // The payment mint is checked by `release_principal` on every payout
+            let old_operator = vault.principal_operator;
+            vault.principal_operator = new_operator;
+
+            emit!(PrincipalOperatorUpdated {
+                vault: vault.key(),
+                authority: caller,
+                old_operator,
+                new_operator,
+                timestamp,
+            });
+        }
+        GovernanceAction::UpdateEmergencyExitDelay { new_delay } => {
+            let old_delay = vault.emergency_exit_delay;
+            vault.emergency_exit_delay = new_delay;
+
+            emit!(EmergencyExitDelayUpdated {
+                vault: vault.key(),
+                authority: caller,
+                old_delay,
+                new_delay,
+                timestamp,
+            });
+        }
+        GovernanceAction::UpdateRedemptionNoticePeriod { new_notice_period } => {
+            // Requests already queued keep the unlock time they were given
+            let old_notice_period = vault.redemption_notice_period;
+            vault.redemption_notice_period = new_notice_period;
+
+            emit!(RedemptionNoticePeriodUpdated {
+                vault: vault.key(),
+                authority: caller,
+                old_notice_period,
+                new_notice_period,
+                timestamp,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+pub fn process_execute_governance_change(ctx: Context<ExecuteGovernanceChange>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let proposal = &mut ctx.accounts.proposal;
+
+    // Queued changes die with governance
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+    require!(proposal.status == ProposalStatus::Queued, ErrorCode::ProposalNotQueued);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= proposal.eta, ErrorCode::TimelockNotElapsed);
+
+    // Mark executed before applying (CEI)
+    proposal.status = ProposalStatus::Executed;
+
+    apply_governance_action(
+        vault,
+        &proposal.action,
+        proposal.proposer,
+        ctx.accounts.new_treasury.as_ref(),
+        ctx.accounts.vault_signer.key(),
+    )?;
+
+    emit!(GovernanceChangeExecuted {
+        vault: vault.key(),
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        executor: ctx.accounts.executor.key(),
+        action: proposal.action,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,458 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{Allocate, Assign, CreateAccount, Transfer, allocate, assign, create_account, transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use crate::{
+    GovernanceProposal, Multisig, MultisigAction, MultisigTransaction, NavHistory, OperatorDebt,
+    RevenueDepositor, Vault, error::ErrorCode,
+};
+use crate::events::MultisigTransactionExecuted;
+use super::{
+    DebtTerms, apply_add_revenue_depositor, apply_authority_proposal, apply_cancel_governance_change,
+    apply_cancel_pending_authority, apply_compliance_authority, apply_extend_subscription, apply_fee_recipient,
+    apply_governance_action, apply_guardian, apply_initialize_operator_debt, apply_investment_limits,
+    apply_mark_operator_default, apply_mint_allowlist_required, apply_nav_pricing, apply_open_revenue_deposits,
+    apply_performance_fee_decrease, apply_release_principal, apply_remove_revenue_depositor,
+    apply_revoke_authority, apply_unpause, apply_vault_status, apply_withdraw_fees, apply_write_down,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigTransaction<'info> {
+    #[account(
+        mut,
+        constraint = vault.authority == multisig.key() @ ErrorCode::Unauthorized
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [b"multisig", vault.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        constraint = transaction.multisig == multisig.key() @ ErrorCode::InvalidMultisigTransaction
+    )]
+    pub transaction: Account<'info, MultisigTransaction>,
+
+    /// Anyone may execute once the threshold (and any timelock) is met.
+    /// Pays rent for accounts the action creates and receives it from those it closes.
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// New treasury for `UpdateTreasury`, the fee recipient for `SetFeeRecipient`,
+    /// the destination for `WithdrawFees`, or the operator for `ReleasePrincipal`
+    #[account(mut)]
+    pub token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury for `WithdrawFees`, principal vault for `ReleasePrincipal`
+    #[account(mut)]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub payment_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// CHECK: Program PDA the action creates, updates or closes (`nav_history` for
+    /// `ConfigureNavPricing`, the registry entry for `Add`/`RemoveRevenueDepositor`,
+    /// `operator_debt` for `ReleasePrincipal` and the operator debt actions); its address
+    /// is checked against the seeds
+    #[account(mut)]
+    pub state_account: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Option<Program<'info, System>>,
+
+    /// Proposal to cancel for `CancelGovernanceChange`
+    #[account(mut)]
+    pub proposal: Option<Account<'info, GovernanceProposal>>,
+
+    /// CHECK: PDA signer for treasury ownership verification
+    #[account(
+        seeds = [b"vault_signer", vault.key().as_ref()],
+        bump = vault.signer_bump
+    )]
+    pub vault_signer: UncheckedAccount<'info>,
+}
+
+pub fn process_execute_multisig_transaction(ctx: Context<ExecuteMultisigTransaction>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let multisig = &ctx.accounts.multisig;
+    let transaction = &mut ctx.accounts.transaction;
+
+    require!(!transaction.executed, ErrorCode::TransactionAlreadyExecuted);
+    require!(
+        transaction.config_version == multisig.config_version,
+        ErrorCode::StaleMultisigTransaction
+    );
+    require!(
+        transaction.approval_count() >= multisig.threshold as u32,
+        ErrorCode::ThresholdNotMet
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let action = transaction.action;
+    let caller = multisig.key();
+
+    // Mark executed before applying (CEI)
+    transaction.executed = true;
+
+    match action {
+        MultisigAction::Governance { action } => {
+            let eta = transaction
+                .approved_at
+                .checked_add(vault.governance_delay)
+                .ok_or(ErrorCode::MathOverflow)?;
+            require!(now >= eta, ErrorCode::TimelockNotElapsed);
+
+            apply_governance_action(
+                vault,
+                &action,
+                caller,
+                ctx.accounts.token_account.as_ref(),
+                ctx.accounts.vault_signer.key(),
+            )?;
+        }
+        MultisigAction::CancelGovernanceChange { proposal_id } => {
+            let proposal = ctx.accounts.proposal.as_mut().ok_or(ErrorCode::InvalidProposal)?;
+            require!(
+                proposal.vault == vault.key() && proposal.proposal_id == proposal_id,
+                ErrorCode::InvalidProposal
+            );
+            apply_cancel_governance_change(vault, proposal, caller)?;
+        }
+        MultisigAction::LowerPerformanceFee { new_fee_bps } => {
+            apply_performance_fee_decrease(vault, caller, new_fee_bps)?;
+        }
+        MultisigAction::SetFeeRecipient { fee_recipient } => {
+            let account = ctx.accounts.token_account.as_ref().ok_or(ErrorCode::InvalidFeeRecipient)?;
+            require!(
+                account.key() == fee_recipient && account.mint == vault.payment_mint,
+                ErrorCode::InvalidFeeRecipient
+            );
+            apply_fee_recipient(vault, caller, fee_recipient)?;
+        }
+        MultisigAction::SetGuardian { guardian } => {
+            apply_guardian(vault, caller, guardian)?;
+        }
+        MultisigAction::SetOpenRevenueDeposits { open } => {
+            apply_open_revenue_deposits(vault, caller, open)?;
+        }
+        MultisigAction::SetComplianceAuthority { compliance_authority } => {
+            apply_compliance_authority(vault, caller, compliance_authority)?;
+        }
+        MultisigAction::SetMintAllowlistRequired { required } => {
+            apply_mint_allowlist_required(vault, caller, required)?;
+        }
+        MultisigAction::SetInvestmentLimits { min_mint_amount, max_shares_per_holder, max_holder_count } => {
+            apply_investment_limits(vault, caller, min_mint_amount, max_shares_per_holder, max_holder_count)?;
+        }
+        MultisigAction::ExtendSubscription { subscription_end } => {
+            apply_extend_subscription(vault, caller, subscription_end)?;
+        }
+        MultisigAction::WriteDown { impairment_bps } => {
+            apply_write_down(vault, caller, impairment_bps)?;
+        }
+        MultisigAction::WithdrawFees { amount } => {
+            let treasury = ctx.accounts.vault_token_account.as_ref().ok_or(ErrorCode::InvalidTreasury)?;
+            require!(
+                treasury.key() == vault.treasury && treasury.owner == ctx.accounts.vault_signer.key(),
+                ErrorCode::InvalidTreasury
+            );
+            let destination = ctx.accounts.token_account.as_ref().ok_or(ErrorCode::InvalidPaymentMint)?;
+            require!(destination.mint == vault.payment_mint, ErrorCode::InvalidPaymentMint);
+            let (payment_mint, token_program) =
+                payment_token(vault, &ctx.accounts.payment_mint, &ctx.accounts.token_program)?;
+
+            apply_withdraw_fees(
+                vault,
+                caller,
+                treasury,
+                destination,
+                payment_mint,
+                token_program,
+                &ctx.accounts.vault_signer,
+                amount,
+            )?;
+        }
+        MultisigAction::ReleasePrincipal { amount } => {
+            let principal_vault = ctx.accounts.vault_token_account.as_ref().ok_or(ErrorCode::InvalidPaymentVault)?;
+            require!(
+                principal_vault.key() == vault.principal_vault
+                    && principal_vault.owner == ctx.accounts.vault_signer.key(),
+                ErrorCode::InvalidPaymentVault
+            );
+            let operator = ctx.accounts.token_account.as_ref().ok_or(ErrorCode::InvalidPrincipalOperator)?;
+            require!(
+                operator.key() == vault.principal_operator && operator.mint == vault.payment_mint,
+                ErrorCode::InvalidPrincipalOperator
+            );
+            let (payment_mint, token_program) =
+                payment_token(vault, &ctx.accounts.payment_mint, &ctx.accounts.token_program)?;
+
+            // Every drawdown is recorded against the operator's debt
+            let info = state_account(&ctx.accounts.state_account)?;
+            let vault_key = vault.key();
+            pda_bump(info, &[b"operator_debt", vault_key.as_ref()])?;
+            let mut debt = load_account::<OperatorDebt>(info)?;
+
+            apply_release_principal(
+                vault,
+                caller,
+                principal_vault,
+                operator,
+                &mut debt,
+                payment_mint,
+                token_program,
+                &ctx.accounts.vault_signer,
+                amount,
+            )?;
+            store_account(info, &debt)?;
+        }
+        MultisigAction::InitializeOperatorDebt {
+            operator,
+            interest_rate_bps,
+            installment_amount,
+            payment_interval,
+            first_due_at,
+            grace_period,
+        } => {
+            let info = state_account(&ctx.accounts.state_account)?;
+            let vault_key = vault.key();
+            let seeds: &[&[u8]] = &[b"operator_debt", vault_key.as_ref()];
+            let bump = pda_bump(info, seeds)?;
+            create_pda(
+                info,
+                &ctx.accounts.executor,
+                ctx.accounts.system_program.as_ref(),
+                seeds,
+                bump,
+                8 + OperatorDebt::INIT_SPACE,
+            )?;
+
+            let mut debt = OperatorDebt::try_deserialize_unchecked(&mut &info.try_borrow_data()?[..])?;
+            apply_initialize_operator_debt(
+                vault,
+                caller,
+                &mut debt,
+                info.key(),
+                bump,
+                DebtTerms {
+                    operator,
+                    interest_rate_bps,
+                    installment_amount,
+                    payment_interval,
+                    first_due_at,
+                    grace_period,
+                },
+            )?;
+            store_account(info, &debt)?;
+        }
+        MultisigAction::MarkOperatorDefault => {
+            let info = state_account(&ctx.accounts.state_account)?;
+            let vault_key = vault.key();
+            pda_bump(info, &[b"operator_debt", vault_key.as_ref()])?;
+            let mut debt = load_account::<OperatorDebt>(info)?;
+
+            apply_mark_operator_default(vault, caller, &mut debt, info.key())?;
+            store_account(info, &debt)?;
+        }
+        MultisigAction::ConfigureNavPricing { nav_oracle, max_nav_change_bps } => {
+            let info = state_account(&ctx.accounts.state_account)?;
+            let vault_key = vault.key();
+            let seeds: &[&[u8]] = &[b"nav_history", vault_key.as_ref()];
+            let bump = pda_bump(info, seeds)?;
+
+            // Created on first use, as `configure_nav_pricing` does with `init_if_needed`
+            let mut nav_history = if info.owner == &System::id() {
+                create_pda(
+                    info,
+                    &ctx.accounts.executor,
+                    ctx.accounts.system_program.as_ref(),
+                    seeds,
+                    bump,
+                    8 + NavHistory::INIT_SPACE,
+                )?;
+                NavHistory::try_deserialize_unchecked(&mut &info.try_borrow_data()?[..])?
+            } else {
+                load_account::<NavHistory>(info)?
+            };
+
+            apply_nav_pricing(vault, caller, &mut nav_history, bump, nav_oracle, max_nav_change_bps)?;
+            store_account(info, &nav_history)?;
+        }
+        MultisigAction::AddRevenueDepositor { depositor } => {
+            let info = state_account(&ctx.accounts.state_account)?;
+            let vault_key = vault.key();
+            let seeds: &[&[u8]] = &[b"revenue_depositor", vault_key.as_ref(), depositor.as_ref()];
+            let bump = pda_bump(info, seeds)?;
+            create_pda(
+                info,
+                &ctx.accounts.executor,
+                ctx.accounts.system_program.as_ref(),
+                seeds,
+                bump,
+                8 + RevenueDepositor::INIT_SPACE,
+            )?;
+
+            let mut approval = RevenueDepositor::try_deserialize_unchecked(&mut &info.try_borrow_data()?[..])?;
+            apply_add_revenue_depositor(vault, caller, &mut approval, depositor, bump)?;
+            store_account(info, &approval)?;
+        }
+        MultisigAction::RemoveRevenueDepositor { depositor } => {
+            let info = state_account(&ctx.accounts.state_account)?;
+            let vault_key = vault.key();
+            pda_bump(info, &[b"revenue_depositor", vault_key.as_ref(), depositor.as_ref()])?;
+            load_account::<RevenueDepositor>(info)?;
+
+            apply_remove_revenue_depositor(vault, caller, depositor)?;
+            close_account(info, &ctx.accounts.executor)?;
+        }
+        MultisigAction::Unpause { flags } => {
+            apply_unpause(vault, caller, flags)?;
+        }
+        MultisigAction::SetVaultStatus { status } => {
+            apply_vault_status(vault, caller, status)?;
+        }
+        MultisigAction::ProposeAuthority { new_authority } => {
+            apply_authority_proposal(vault, caller, new_authority)?;
+        }
+        MultisigAction::CancelPendingAuthority => {
+            apply_cancel_pending_authority(vault, caller)?;
+        }
+        MultisigAction::RevokeAuthority => {
+            apply_revoke_authority(vault)?;
+        }
+    }
+
+    emit!(MultisigTransactionExecuted {
+        vault: vault.key(),
+        multisig: caller,
+        transaction: transaction.key(),
+        transaction_id: transaction.transaction_id,
+        executor: ctx.accounts.executor.key(),
+        action,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Payment mint and the token program that owns it, for actions that move vault funds
+fn payment_token<'a, 'info>(
+    vault: &Vault,
+    payment_mint: &'a Option<InterfaceAccount<'info, Mint>>,
+    token_program: &'a Option<Interface<'info, TokenInterface>>,
+) -> Result<(&'a InterfaceAccount<'info, Mint>, &'a Interface<'info, TokenInterface>)> {
+    let payment_mint = payment_mint.as_ref().ok_or(ErrorCode::InvalidPaymentMint)?;
+    let token_program = token_program.as_ref().ok_or(ErrorCode::InvalidPaymentMint)?;
+    require!(
+        payment_mint.key() == vault.payment_mint
+            && *payment_mint.to_account_info().owner == token_program.key(),
+        ErrorCode::InvalidPaymentMint
+    );
+    Ok((payment_mint, token_program))
+}
+
+fn state_account<'a, 'info>(account: &'a Option<UncheckedAccount<'info>>) -> Result<&'a AccountInfo<'info>> {
+    Ok(account.as_ref().ok_or(ErrorCode::MultisigAccountMissing)?)
+}
+
+/// Checks `account` is the program PDA at `seeds` and returns its bump
+fn pda_bump(account: &AccountInfo, seeds: &[&[u8]]) -> Result<u8> {
+    let (address, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(account.key(), address, ErrorCode::MultisigAccountMissing);
+    Ok(bump)
+}
+
+/// Allocates the PDA at `seeds`, funded by the executor, as `init` would. An address
+/// someone pre-funded is topped up and claimed instead, so a stray transfer cannot
+/// make `create_account` fail and block the action.
+fn create_pda<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: Option<&Program<'info, System>>,
+    seeds: &[&[u8]],
+    bump: u8,
+    space: usize,
+) -> Result<()> {
+    let system_program = system_program.ok_or(ErrorCode::MultisigAccountMissing)?.to_account_info();
+    let bump = [bump];
+    let signer_seeds = [seeds, &[&bump[..]]].concat();
+    let signer = &[&signer_seeds[..]];
+    let rent = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                CreateAccount {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let top_up = rent.max(1).saturating_sub(account.lamports());
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer,
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program,
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            signer,
+        ),
+        &crate::ID,
+    )
+}
+
+fn load_account<T: AccountDeserialize + Owner>(account: &AccountInfo) -> Result<T> {
+    require_keys_eq!(*account.owner, T::owner(), ErrorCode::MultisigAccountMissing);
+    T::try_deserialize(&mut &account.try_borrow_data()?[..])
+}
+
+/// Returns a program account's rent to the executor and hands it back to the
+/// system program, as `close` would
+fn close_account<'info>(account: &AccountInfo<'info>, destination: &Signer<'info>) -> Result<()> {
+    let refund = destination
+        .lamports()
+        .checked_add(account.lamports())
+        .ok_or(ErrorCode::Overflow)?;
+    **destination.try_borrow_mut_lamports()? = refund;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.assign(&System::id());
+    account.realloc(0, false)?;
+    Ok(())
+}
+
+fn store_account<T: AccountSerialize>(account: &AccountInfo, value: &T) -> Result<()> {
+    let mut data = account.try_borrow_mut_data()?;
+    value.try_serialize(&mut &mut data[..])
+}
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::{
+    GovernanceAction, ShareholderAction, ShareholderProposal, ShareholderProposalStatus, Vault,
+    VaultStatus, error::ErrorCode,
+};
+use crate::events::{AuthorityTransferred, ShareholderProposalFinalized, VaultStatusChanged};
+use crate::states::shareholder_proposal::SHAREHOLDER_EXECUTION_WINDOW;
+use super::apply_governance_action;
+
+#[derive(Accounts)]
+pub struct ExecuteShareholderProposal<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [b"shareholder_proposal", vault.key().as_ref(), proposal.proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.vault == vault.key() @ ErrorCode::InvalidShareholderProposal
+    )]
+    pub proposal: Account<'info, ShareholderProposal>,
+
+    /// Anyone may finalize a proposal once voting has ended
+    pub executor: Signer<'info>,
+
+    /// New treasury account, required for `UpdateTreasury` proposals
+    pub new_treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: PDA signer for treasury ownership verification
+    #[account(
+        seeds = [b"vault_signer", vault.key().as_ref()],
+        bump = vault.signer_bump
+    )]
+    pub vault_signer: UncheckedAccount<'info>,
+}
+
+/// Tallies a closed vote. A passed proposal is applied; a failed or stale one
+/// is recorded as such. Either way the vault is freed for the next proposal.
+pub fn process_execute_shareholder_proposal(ctx: Context<ExecuteShareholderProposal>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(
+        proposal.status == ShareholderProposalStatus::Voting,
+        ErrorCode::ShareholderProposalNotOpen
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= proposal.voting_ends_at, ErrorCode::VotingNotEnded);
+
+    let passed = proposal.quorum_reached().ok_or(ErrorCode::MathOverflow)?
+        && proposal.approved().ok_or(ErrorCode::MathOverflow)?;
+    let execution_deadline = proposal
+        .voting_ends_at
+        .checked_add(SHAREHOLDER_EXECUTION_WINDOW)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    // Mark final before applying (CEI)
+    proposal.status = if !passed {
+        ShareholderProposalStatus::Defeated
+    } else if now > execution_deadline {
+        ShareholderProposalStatus::Expired
+    } else {
+        ShareholderProposalStatus::Executed
+    };
+    vault.active_shareholder_proposal = Pubkey::default();
+
+    if proposal.status == ShareholderProposalStatus::Executed {
+        apply_shareholder_action(
+            vault,
+            &proposal.action,
+            proposal.key(),
+            ctx.accounts.new_treasury.as_ref(),
+            ctx.accounts.vault_signer.key(),
+        )?;
+    }
+
+    emit!(ShareholderProposalFinalized {
+        vault: vault.key(),
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        executor: ctx.accounts.executor.key(),
+        action: proposal.action,
+        status: proposal.status,
+        votes_for: proposal.votes_for,
+        votes_against: proposal.votes_against,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Applies a passed shareholder action. Holders act independently of
+/// `vault.authority`, so none of these require governance to be enabled.
+fn apply_shareholder_action<'info>(
+    vault: &mut Account<'info, Vault>,
+    action: &ShareholderAction,
+    proposal: Pubkey,
+    new_treasury: Option<&InterfaceAccount<'info, TokenAccount>>,
+    vault_signer: Pubkey,
+) -> Result<()> {
+    match *action {
+        ShareholderAction::UpdatePerformanceFee { new_fee_bps } => {
+            apply_governance_action(
+                vault,
+                &GovernanceAction::UpdatePerformanceFee { new_fee_bps },
+                proposal,
+                new_treasury,
+                vault_signer,
+            )?;
+        }
+        ShareholderAction::UpdateTreasury { new_treasury: expected } => {
+            apply_governance_action(
+                vault,
+                &GovernanceAction::UpdateTreasury { new_treasury: expected },
+                proposal,
+                new_treasury,
+                vault_signer,
+            )?;
+        }
+        ShareholderAction::ReplaceAuthority { new_authority } => {
+            // Holders approved the key directly, no acceptance step.
+            // This is also the only way back from a revoked authority.
+            let old_authority = vault.authority;
+            vault.authority = new_authority;
+            vault.pending_authority = Pubkey::default();
+
+            emit!(AuthorityTransferred {
+                vault: vault.key(),
+                old_authority,
+                new_authority,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+        ShareholderAction::WindDown => {
+            let old_status = vault.status;
+            if old_status.can_transition_to(VaultStatus::WindingDown) {
+                vault.status = VaultStatus::WindingDown;
+            } else if old_status == VaultStatus::Paused
+                && vault.status_before_pause.can_transition_to(VaultStatus::WindingDown)
+            {
+                // Stay paused, but resume into WindingDown
+                vault.status_before_pause = VaultStatus::WindingDown;
+            } else {
+                return err!(ErrorCode::InvalidStatusTransition);
+            }
+
+            emit!(VaultStatusChanged {
+                vault: vault.key(),
+                authority: proposal,
+                old_status,
+                new_status: vault.status,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+        }
+    }
+
+    Ok(())
+}
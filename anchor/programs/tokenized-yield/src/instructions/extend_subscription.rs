@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::{Vault, error::ErrorCode, events::SubscriptionExtended};
+
+#[derive(Accounts)]
+pub struct ExtendSubscription<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Moves `subscription_end` later. A closed window stays closed, and an
+/// open-ended window has no end to move.
+pub fn process_extend_subscription(ctx: Context<ExtendSubscription>, new_subscription_end: i64) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    apply_extend_subscription(&mut ctx.accounts.vault, authority, new_subscription_end)
+}
+
+/// Shared with multisig execution
+pub(crate) fn apply_extend_subscription(
+    vault: &mut Account<Vault>,
+    authority: Pubkey,
+    new_subscription_end: i64,
+) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(!vault.subscription_ended(now), ErrorCode::SubscriptionEnded);
+    require!(
+        vault.subscription_end != 0 && new_subscription_end > vault.subscription_end,
+        ErrorCode::InvalidSubscriptionWindow
+    );
+
+    let old_subscription_end = vault.subscription_end;
+    vault.subscription_end = new_subscription_end;
+
+    emit!(SubscriptionExtended {
+        vault: vault.key(),
+        authority,
+        old_subscription_end,
+        new_subscription_end,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::{Vault, VaultStatus, error::ErrorCode, events::SubscriptionFinalized};
+
+#[derive(Accounts)]
+pub struct FinalizeSubscription<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// Anyone may settle a closed subscription; the outcome is fixed by `minted_shares`
+    pub cranker: Signer<'info>,
+}
+
+/// Moves a fundraising vault whose window has closed to `Active` if the soft
+/// cap was met, or to `Refunding` so holders can take their principal back.
+pub fn process_finalize_subscription(ctx: Context<FinalizeSubscription>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    require!(vault.status == VaultStatus::Fundraising, ErrorCode::InvalidVaultStatus);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(vault.subscription_ended(now), ErrorCode::SubscriptionStillOpen);
+
+    let new_status = if vault.minted_shares >= vault.soft_cap {
+        VaultStatus::Active
+    } else {
+        VaultStatus::Refunding
+    };
+    vault.status = new_status;
+
+    emit!(SubscriptionFinalized {
+        vault: vault.key(),
+        cranker: ctx.accounts.cranker.key(),
+        minted_shares: vault.minted_shares,
+        soft_cap: vault.soft_cap,
+        new_status,
+        timestamp: now,
+    });
+
+    Ok(())
+}
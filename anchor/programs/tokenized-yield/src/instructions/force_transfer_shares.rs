@@ -0,0 +1,240 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::spl_token_2022::extension::permanent_delegate::PermanentDelegate,
+    token_interface::{
+        Burn, FreezeAccount, Mint, MintTo, ThawAccount, TokenAccount, TokenInterface, burn,
+        freeze_account, get_mint_extension_data, mint_to, thaw_account,
+    },
+};
+use crate::{ForcedTransferRecord, UserStake, Vault, error::ErrorCode, events::SharesForceTransferred};
+
+#[derive(Accounts)]
+pub struct ForceTransferShares<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// Compliance authority or vault authority
+    #[account(
+        mut,
+        constraint = vault.is_compliance_officer(&officer.key()) @ ErrorCode::Unauthorized
+    )]
+    pub officer: Signer<'info>,
+
+    /// CHECK: Wallet losing the shares, only used for PDA derivation
+    pub from_holder: UncheckedAccount<'info>,
+
+    /// CHECK: Wallet receiving the shares, only used for PDA/ATA derivation
+    #[account(
+        constraint = to_holder.key() != from_holder.key() @ ErrorCode::InvalidShareholder
+    )]
+    pub to_holder: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"shareholder", vault.key().as_ref(), from_holder.key().as_ref()],
+        bump = from_stake.bump,
+        constraint = from_stake.vault == vault.key() @ ErrorCode::InvalidShareholder
+    )]
+    pub from_stake: Account<'info, UserStake>,
+
+    #[account(
+        init_if_needed,
+        payer = officer,
+        seeds = [b"shareholder", vault.key().as_ref(), to_holder.key().as_ref()],
+        bump,
+        space = 8 + UserStake::INIT_SPACE
+    )]
+    pub to_stake: Account<'info, UserStake>,
+
+    #[account(
+        mut,
+        constraint = from_share_account.mint == vault.vault_share_mint @ ErrorCode::InvalidShareMint,
+        constraint = from_share_account.owner == from_holder.key() @ ErrorCode::InvalidTokenAccountOwner
+    )]
+    pub from_share_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = officer,
+        associated_token::mint = vault_share_mint,
+        associated_token::authority = to_holder,
+        associated_token::token_program = share_token_program,
+    )]
+    pub to_share_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_share_mint", vault.key().as_ref()],
+        bump,
+        mint::token_program = share_token_program
+    )]
+    pub vault_share_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA signer: mint authority, freeze authority and permanent delegate
+    #[account(
+        seeds = [b"vault_signer", vault.key().as_ref()],
+        bump = vault.signer_bump
+    )]
+    pub vault_signer: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = officer,
+        seeds = [b"forced_transfer", vault.key().as_ref(), vault.forced_transfer_count.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + ForcedTransferRecord::INIT_SPACE
+    )]
+    pub record: Account<'info, ForcedTransferRecord>,
+
+    #[account(
+        constraint = share_token_program.key() == vault.share_token_program @ ErrorCode::InvalidShareTokenProgram
+    )]
+    pub share_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves a position under court order or after key loss: burns `amount` from
+/// the source account through the permanent delegate and mints it to the
+/// recipient's ATA. Both stakes are settled at the current accumulator, so
+/// rewards earned so far stay with the side that earned them.
+///
+/// Deliberately ignores vault status, pause flags and the source freeze.
+pub fn process_force_transfer_shares(
+    ctx: Context<ForceTransferShares>,
+    amount: u64,
+    reason: u16,
+    reference: [u8; 32],
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let from_stake = &mut ctx.accounts.from_stake;
+    let to_stake = &mut ctx.accounts.to_stake;
+    let record = &mut ctx.accounts.record;
+
+    require!(amount > 0, ErrorCode::InvalidShareAmount);
+    require!(reason != 0, ErrorCode::InvalidReasonCode);
+    require!(from_stake.unlocked_quantity() >= amount, ErrorCode::InsufficientShares);
+    require!(!to_stake.frozen, ErrorCode::ShareholderFrozen);
+
+    // Only share mints with vault_signer as permanent delegate can be burned from
+    let delegate = get_mint_extension_data::<PermanentDelegate>(&ctx.accounts.vault_share_mint.to_account_info())
+        .map_err(|_| ErrorCode::ForcedTransferUnsupported)?;
+    require!(
+        Option::<Pubkey>::from(delegate.delegate) == Some(ctx.accounts.vault_signer.key()),
+        ErrorCode::ForcedTransferUnsupported
+    );
+
+    if !to_stake.is_initialized {
+        to_stake.initialize(ctx.accounts.to_holder.key(), vault.key(), ctx.bumps.to_stake);
+    }
+
+    // 1. Settle rewards for both sides at the current accumulator
+    from_stake.checkpoint_rewards(vault.acc_reward_per_share)?;
+    to_stake.checkpoint_rewards(vault.acc_reward_per_share)?;
+
+    // 2. Move entitlement (Effects)
+    let new_from_quantity = from_stake.quantity
+        .checked_sub(amount)
+        .ok_or(ErrorCode::Underflow)?;
+    let new_to_quantity = to_stake.quantity
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+
+    vault.track_holder(from_stake.quantity, new_from_quantity)?;
+    vault.track_holder(to_stake.quantity, new_to_quantity)?;
+    from_stake.snapshot_voting_power(vault.active_shareholder_proposal);
+    to_stake.snapshot_voting_power(vault.active_shareholder_proposal);
+    from_stake.rebase_quantity(new_from_quantity, vault.acc_reward_per_share)?;
+    to_stake.rebase_quantity(new_to_quantity, vault.acc_reward_per_share)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    record.vault = vault.key();
+    record.record_id = vault.forced_transfer_count;
+    record.officer = ctx.accounts.officer.key();
+    record.from = ctx.accounts.from_holder.key();
+    record.to = ctx.accounts.to_holder.key();
+    record.amount = amount;
+    record.reason = reason;
+    record.reference = reference;
+    record.timestamp = now;
+    record.bump = ctx.bumps.record;
+
+    vault.forced_transfer_count = vault.forced_transfer_count
+        .checked_add(1)
+        .ok_or(ErrorCode::Overflow)?;
+
+    // 3. Burn from the source, re-mint to the recipient (Interactions)
+    let vault_key = vault.key();
+    let seeds = &[b"vault_signer".as_ref(), vault_key.as_ref(), &[vault.signer_bump]];
+    let signer = &[&seeds[..]];
+    let share_token_program = ctx.accounts.share_token_program.to_account_info();
+    let from_frozen = ctx.accounts.from_share_account.is_frozen();
+
+    if from_frozen {
+        thaw_account(CpiContext::new_with_signer(
+            share_token_program.clone(),
+            ThawAccount {
+                account: ctx.accounts.from_share_account.to_account_info(),
+                mint: ctx.accounts.vault_share_mint.to_account_info(),
+                authority: ctx.accounts.vault_signer.to_account_info(),
+            },
+            signer,
+        ))?;
+    }
+
+    burn(
+        CpiContext::new_with_signer(
+            share_token_program.clone(),
+            Burn {
+                mint: ctx.accounts.vault_share_mint.to_account_info(),
+                from: ctx.accounts.from_share_account.to_account_info(),
+                authority: ctx.accounts.vault_signer.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    if from_frozen {
+        freeze_account(CpiContext::new_with_signer(
+            share_token_program.clone(),
+            FreezeAccount {
+                account: ctx.accounts.from_share_account.to_account_info(),
+                mint: ctx.accounts.vault_share_mint.to_account_info(),
+                authority: ctx.accounts.vault_signer.to_account_info(),
+            },
+            signer,
+        ))?;
+    }
+
+    mint_to(
+        CpiContext::new_with_signer(
+            share_token_program,
+            MintTo {
+                mint: ctx.accounts.vault_share_mint.to_account_info(),
+                to: ctx.accounts.to_share_ata.to_account_info(),
+                authority: ctx.accounts.vault_signer.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    emit!(SharesForceTransferred {
+        vault: vault_key,
+        record: record.key(),
+        record_id: record.record_id,
+        officer: record.officer,
+        from: record.from,
+        to: record.to,
+        amount,
+        reason,
+        reference,
+        from_quantity: new_from_quantity,
+        to_quantity: new_to_quantity,
+        timestamp: now,
+    });
+
+    Ok(())
+}
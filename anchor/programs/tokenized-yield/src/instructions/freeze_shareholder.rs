@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{FreezeAccount, Mint, TokenAccount, TokenInterface, freeze_account};
+use crate::{UserStake, Vault, error::ErrorCode, events::ShareholderFreezeUpdated};
+
+#[derive(Accounts)]
+pub struct FreezeShareholder<'info> {
+    pub vault: Account<'info, Vault>,
+
+    /// Compliance authority or vault authority
+    #[account(
+        constraint = vault.is_compliance_officer(&officer.key()) @ ErrorCode::Unauthorized
+    )]
+    pub officer: Signer<'info>,
+
+    /// CHECK: Wallet being frozen, only used for PDA/ATA derivation
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"shareholder", vault.key().as_ref(), holder.key().as_ref()],
+        bump = shareholder.bump,
+        constraint = shareholder.vault == vault.key() @ ErrorCode::InvalidShareholder
+    )]
+    pub shareholder: Account<'info, UserStake>,
+
+    #[account(
+        mut,
+        associated_token::mint = vault_share_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = share_token_program,
+    )]
+    pub holder_share_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"vault_share_mint", vault.key().as_ref()],
+        bump,
+        mint::token_program = share_token_program
+    )]
+    pub vault_share_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA signer, freeze authority of the share mint
+    #[account(
+        seeds = [b"vault_signer", vault.key().as_ref()],
+        bump = vault.signer_bump
+    )]
+    pub vault_signer: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = share_token_program.key() == vault.share_token_program @ ErrorCode::InvalidShareTokenProgram
+    )]
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Freezes a holder's share ATA and `UserStake` (sanctions hit, dispute, ...).
+/// `reason` is a non-zero compliance code kept on the stake until unfrozen.
+pub fn process_freeze_shareholder(ctx: Context<FreezeShareholder>, reason: u16) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let shareholder = &mut ctx.accounts.shareholder;
+
+    require!(reason != 0, ErrorCode::InvalidReasonCode);
+    require!(!shareholder.frozen, ErrorCode::ShareholderFrozen);
+
+    // Effects
+    shareholder.frozen = true;
+    shareholder.freeze_reason = reason;
+
+    // Interactions
+    let vault_key = vault.key();
+    let seeds = &[b"vault_signer".as_ref(), vault_key.as_ref(), &[vault.signer_bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = FreezeAccount {
+        account: ctx.accounts.holder_share_ata.to_account_info(),
+        mint: ctx.accounts.vault_share_mint.to_account_info(),
+        authority: ctx.accounts.vault_signer.to_account_info(),
+    };
+    freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.share_token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    ))?;
+
+    emit!(ShareholderFreezeUpdated {
+        vault: vault_key,
+        officer: ctx.accounts.officer.key(),
+        holder: ctx.accounts.holder.key(),
+        share_account: ctx.accounts.holder_share_ata.key(),
+        frozen: true,
+        reason,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer, transfer};
-use crate::{UserStake, Vault, error::ErrorCode, constants::PRECISION};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use crate::{OperatorDebt, UserStake, Vault, error::ErrorCode, constants::PRECISION, events::Harvested};
+use crate::states::vault::PAUSE_HARVEST;
 
 #[derive(Accounts)]
 pub struct Harvest<'info> {
@@ -29,16 +30,29 @@ pub struct Harvest<'info> {
         constraint = revenue_vault.key() == vault.revenue_vault @ ErrorCode::InvalidPaymentVault,
         constraint = revenue_vault.owner == vault_signer.key() @ ErrorCode::InvalidPaymentVault
     )]
-    pub revenue_vault: Account<'info, TokenAccount>,
+    pub revenue_vault: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         constraint = user_ata.mint == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
         constraint = user_ata.owner == payer.key() @ ErrorCode::InvalidTokenAccountOwner
     )]
-    pub user_ata: Account<'info, TokenAccount>,
+    pub user_ata: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(
+        constraint = payment_mint.key() == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        mint::token_program = token_program
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Required once the vault has an operator debt ledger
+    #[account(
+        seeds = [b"operator_debt", vault.key().as_ref()],
+        bump = operator_debt.bump
+    )]
+    pub operator_debt: Option<Account<'info, OperatorDebt>>,
 }
 
 pub fn process_harvest(ctx: Context<Harvest>) -> Result<()> {
@@ -49,6 +63,13 @@ pub fn process_harvest(ctx: Context<Harvest>) -> Result<()> {
     let token_program = &ctx.accounts.token_program;
     let vault_signer = &ctx.accounts.vault_signer;
 
+    require!(vault.status.allows_harvest(), ErrorCode::InvalidVaultStatus);
+    require!(!vault.is_paused(PAUSE_HARVEST), ErrorCode::InstructionPaused);
+    require!(!shareholder.frozen, ErrorCode::ShareholderFrozen);
+
+    let now = Clock::get()?.unix_timestamp;
+    let operator_overdue = vault.operator_overdue(ctx.accounts.operator_debt.as_deref(), now)?;
+
     // Compute pending reward
     let accumulated = (shareholder.quantity as u128)
         .checked_mul(vault.acc_reward_per_share)
@@ -60,9 +81,14 @@ pub fn process_harvest(ctx: Context<Harvest>) -> Result<()> {
         .checked_sub(shareholder.reward_debt)
         .ok_or(ErrorCode::Underflow)?;
 
-    if pending > 0 {
-        let pending_u64 = u64::try_from(pending).map_err(|_| ErrorCode::Overflow)?;
+    // Include rewards settled at earlier checkpoints (share transfers)
+    let pending_u64 = u64::try_from(pending)
+        .map_err(|_| ErrorCode::Overflow)?
+        .checked_add(shareholder.pending_rewards)
+        .ok_or(ErrorCode::Overflow)?;
+    shareholder.pending_rewards = 0;
 
+    if pending_u64 > 0 {
         // Solvency check
         require!(
             revenue_vault.amount >= pending_u64,
@@ -77,17 +103,28 @@ pub fn process_harvest(ctx: Context<Harvest>) -> Result<()> {
         let seeds = &[b"vault_signer".as_ref(), vault_key.as_ref(), &[vault.signer_bump]];
         let signer = &[&seeds[..]];
 
-        let cpi_accounts = Transfer {
+        let cpi_accounts = TransferChecked {
             from: revenue_vault.to_account_info(),
+            mint: ctx.accounts.payment_mint.to_account_info(),
             to: user_ata.to_account_info(),
             authority: vault_signer.to_account_info(),
         };
         let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
-        token::transfer(cpi_ctx, pending_u64)?;
+        transfer_checked(cpi_ctx, pending_u64, ctx.accounts.payment_mint.decimals)?;
     } else {
         // Even if 0 pending, update debt to current accumulator
         shareholder.reward_debt = accumulated;
     }
 
+    emit!(Harvested {
+        vault: vault.key(),
+        investor: ctx.accounts.payer.key(),
+        amount: pending_u64,
+        reward_debt: shareholder.reward_debt,
+        acc_reward_per_share: vault.acc_reward_per_share,
+        operator_overdue,
+        timestamp: now,
+    });
+
     Ok(())
 }
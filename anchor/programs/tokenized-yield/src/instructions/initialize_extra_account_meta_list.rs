@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::{Vault, error::ErrorCode};
+use super::transfer_hook::extra_account_metas;
+
+/// Number of extra accounts the transfer hook resolves (vault + two stakes)
+const EXTRA_ACCOUNT_METAS_LEN: usize = 3;
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = vault.transfer_hook_enabled @ ErrorCode::TransferHookNotEnabled
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        seeds = [b"vault_share_mint", vault.key().as_ref()],
+        bump
+    )]
+    pub vault_share_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: ExtraAccountMetaList PDA, written below
+    #[account(
+        init,
+        seeds = [b"extra-account-metas", vault_share_mint.key().as_ref()],
+        bump,
+        space = ExtraAccountMetaList::size_of(EXTRA_ACCOUNT_METAS_LEN)?,
+        payer = payer
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers the extra accounts Token-2022 passes to the transfer hook.
+/// Must run once after `initialize_vault_with_transfer_hook`, before any share transfer.
+pub fn process_initialize_extra_account_meta_list(
+    ctx: Context<InitializeExtraAccountMetaList>,
+) -> Result<()> {
+    let metas = extra_account_metas(&ctx.accounts.vault.key())?;
+
+    ExtraAccountMetaList::init::<ExecuteInstruction>(
+        &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+        &metas,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use crate::{OperatorDebt, Vault, error::ErrorCode, events::OperatorDebtInitialized};
+
+#[derive(Accounts)]
+pub struct InitializeOperatorDebt<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"operator_debt", vault.key().as_ref()],
+        bump,
+        space = 8 + OperatorDebt::INIT_SPACE
+    )]
+    pub operator_debt: Account<'info, OperatorDebt>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens the vault's debt ledger. It must exist before any principal is
+/// released, and its terms cannot be changed afterwards.
+pub fn process_initialize_operator_debt(
+    ctx: Context<InitializeOperatorDebt>,
+    operator: Pubkey,
+    interest_rate_bps: u16,
+    installment_amount: u64,
+    payment_interval: i64,
+    first_due_at: i64,
+    grace_period: i64,
+) -> Result<()> {
+    let operator_debt = ctx.accounts.operator_debt.key();
+    apply_initialize_operator_debt(
+        &mut ctx.accounts.vault,
+        ctx.accounts.authority.key(),
+        &mut ctx.accounts.operator_debt,
+        operator_debt,
+        ctx.bumps.operator_debt,
+        DebtTerms {
+            operator,
+            interest_rate_bps,
+            installment_amount,
+            payment_interval,
+            first_due_at,
+            grace_period,
+        },
+    )
+}
+
+/// Repayment terms fixed when the ledger is opened
+pub(crate) struct DebtTerms {
+    pub operator: Pubkey,
+    pub interest_rate_bps: u16,
+    pub installment_amount: u64,
+    pub payment_interval: i64,
+    pub first_due_at: i64,
+    pub grace_period: i64,
+}
+
+/// Shared with multisig execution
+pub(crate) fn apply_initialize_operator_debt(
+    vault: &mut Account<Vault>,
+    authority: Pubkey,
+    debt: &mut OperatorDebt,
+    operator_debt: Pubkey,
+    bump: u8,
+    terms: DebtTerms,
+) -> Result<()> {
+    let DebtTerms {
+        operator,
+        interest_rate_bps,
+        installment_amount,
+        payment_interval,
+        first_due_at,
+        grace_period,
+    } = terms;
+
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+    require!(operator != Pubkey::default(), ErrorCode::InvalidDebtTerms);
+    require!(
+        installment_amount > 0 && payment_interval > 0 && grace_period >= 0,
+        ErrorCode::InvalidDebtTerms
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    debt.vault = vault.key();
+    debt.operator = operator;
+    debt.interest_rate_bps = interest_rate_bps;
+    debt.installment_amount = installment_amount;
+    debt.payment_interval = payment_interval;
+    debt.first_due_at = first_due_at;
+    debt.grace_period = grace_period;
+    debt.total_drawn = 0;
+    debt.principal_repaid = 0;
+    debt.accrued_interest = 0;
+    debt.interest_paid = 0;
+    debt.interest_remainder = 0;
+    debt.interest_updated_at = now;
+    debt.defaulted = false;
+    debt.defaulted_at = 0;
+    debt.bump = bump;
+
+    vault.has_operator_debt = true;
+
+    emit!(OperatorDebtInitialized {
+        vault: vault.key(),
+        operator_debt,
+        authority,
+        operator,
+        interest_rate_bps,
+        installment_amount,
+        payment_interval,
+        first_due_at,
+        grace_period,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::{UserStake, Vault};
+
+#[derive(Accounts)]
+pub struct InitializeShareholder<'info> {
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Wallet the stake is opened for
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"shareholder", vault.key().as_ref(), holder.key().as_ref()],
+        bump,
+        space = 8 + UserStake::INIT_SPACE
+    )]
+    pub shareholder: Account<'info, UserStake>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens an empty `UserStake` so the holder can receive shares through the
+/// transfer hook, which cannot create accounts itself.
+pub fn process_initialize_shareholder(ctx: Context<InitializeShareholder>) -> Result<()> {
+    ctx.accounts.shareholder.initialize(
+        ctx.accounts.holder.key(),
+        ctx.accounts.vault.key(),
+        ctx.bumps.shareholder,
+    );
+
+    Ok(())
+}
@@ -1,21 +1,25 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{Mint, Token, TokenAccount},
+    token::Token,
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 pub use crate::states::Vault;
-use crate::states::vault::MAX_PERFORMANCE_FEE_BPS;
+use crate::states::VaultStatus;
+use crate::states::vault::{DEFAULT_EMERGENCY_EXIT_DELAY, DEFAULT_GOVERNANCE_DELAY, MAX_PERFORMANCE_FEE_BPS};
 use crate::error::ErrorCode;
+use crate::events::VaultInitialized;
 
 #[derive(Accounts)]
+#[instruction(vault_id: u64)]
 pub struct InitializeVault<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
     #[account(
         init,
-        seeds = [b"vault", owner.key().as_ref()],
+        seeds = [b"vault", owner.key().as_ref(), vault_id.to_le_bytes().as_ref()],
         bump,
         payer = owner,
         space = 8 + Vault::INIT_SPACE,
@@ -28,10 +32,11 @@ pub struct InitializeVault<'info> {
         mint::decimals = 6,
         mint::authority = vault_signer.key(),
         mint::freeze_authority = vault_signer.key(),
+        mint::token_program = share_token_program,
         seeds = [b"vault_share_mint", vault.key().as_ref()],
         bump
     )]
-    pub vault_share_mint: Account<'info, Mint>,
+    pub vault_share_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         init_if_needed,
@@ -47,80 +52,247 @@ pub struct InitializeVault<'info> {
         init,
         token::mint = payment_mint,
         token::authority = vault_signer,
+        token::token_program = token_program,
         seeds = [b"principal-vault", vault.key().as_ref()],
         bump,
         payer = owner
     )]
-    pub principal_vault: Account<'info, TokenAccount>,
+    pub principal_vault: InterfaceAccount<'info, TokenAccount>,
 
-    pub payment_mint: Account<'info, Mint>,
+    #[account(mint::token_program = token_program)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
 
+    /// Holds distributable revenue, paid out in the payment mint
     #[account(
         init,
-        token::mint = vault_share_mint,
+        token::mint = payment_mint,
         token::authority = vault_signer,
+        token::token_program = token_program,
         seeds = [b"revenue-vault", vault.key().as_ref()],
         bump,
         payer = owner
     )]
-    pub revenue_vault: Account<'info, TokenAccount>,
+    pub revenue_vault: InterfaceAccount<'info, TokenAccount>,
 
     /// Treasury PDA token account that receives performance fees
     #[account(
         init,
         token::mint = payment_mint,
         token::authority = vault_signer,
+        token::token_program = token_program,
         seeds = [b"treasury", vault.key().as_ref()],
         bump,
         payer = owner
     )]
-    pub treasury: Account<'info, TokenAccount>,
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub share_token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn process_initialize_vault(
     ctx: Context<InitializeVault>,
+    vault_id: u64,
     name: String,
     total_shares: u64,
     price_per_share: u64,
     performance_fee_bps: u16,
+    subscription_start: i64,
+    subscription_end: i64,
+    soft_cap: u64,
+) -> Result<()> {
+    let keys = VaultKeys {
+        owner: ctx.accounts.owner.key(),
+        vault_share_mint: ctx.accounts.vault_share_mint.key(),
+        share_token_program: ctx.accounts.share_token_program.key(),
+        payment_mint: ctx.accounts.payment_mint.key(),
+        principal_vault: ctx.accounts.principal_vault.key(),
+        revenue_vault: ctx.accounts.revenue_vault.key(),
+        treasury: ctx.accounts.treasury.key(),
+    };
+    let params = VaultParams {
+        vault_id,
+        name,
+        total_shares,
+        price_per_share,
+        performance_fee_bps,
+        subscription_start,
+        subscription_end,
+        soft_cap,
+        transfer_hook_enabled: false,
+    };
+
+    initialize_vault_state(
+        &mut ctx.accounts.vault,
+        keys,
+        params,
+        ctx.bumps.vault,
+        ctx.bumps.vault_signer,
+    )
+}
+
+/// Accounts a vault is bound to at creation, shared by every initialization path
+pub(crate) struct VaultKeys {
+    pub owner: Pubkey,
+    pub vault_share_mint: Pubkey,
+    pub share_token_program: Pubkey,
+    pub payment_mint: Pubkey,
+    pub principal_vault: Pubkey,
+    pub revenue_vault: Pubkey,
+    pub treasury: Pubkey,
+}
+
+/// Caller-supplied vault parameters, shared by every initialization path
+pub(crate) struct VaultParams {
+    pub vault_id: u64,
+    pub name: String,
+    pub total_shares: u64,
+    pub price_per_share: u64,
+    pub performance_fee_bps: u16,
+    pub subscription_start: i64,
+    pub subscription_end: i64,
+    pub soft_cap: u64,
+    pub transfer_hook_enabled: bool,
+}
+
+pub(crate) fn initialize_vault_state(
+    vault: &mut Account<Vault>,
+    keys: VaultKeys,
+    params: VaultParams,
+    bump: u8,
+    signer_bump: u8,
 ) -> Result<()> {
     // Validate fee boundedness (max 20%)
     require!(
-        performance_fee_bps <= MAX_PERFORMANCE_FEE_BPS,
+        params.performance_fee_bps <= MAX_PERFORMANCE_FEE_BPS,
         ErrorCode::PerformanceFeeExceedsMax
     );
 
-    let vault = &mut ctx.accounts.vault;
-    vault.owner = *ctx.accounts.owner.key;
-    vault.name = name;
+    // An open-ended window needs no end; otherwise it must close in the future, after it opens
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        params.subscription_end == 0
+            || params.subscription_end > params.subscription_start.max(now),
+        ErrorCode::InvalidSubscriptionWindow
+    );
+    // A soft cap is judged when the window closes, so it needs an end and must be reachable
+    require!(
+        params.soft_cap == 0
+            || (params.subscription_end != 0 && params.soft_cap <= params.total_shares),
+        ErrorCode::InvalidSoftCap
+    );
 
-    vault.vault_share_mint = ctx.accounts.vault_share_mint.key();
-    vault.payment_mint = ctx.accounts.payment_mint.key();
-    vault.principal_vault = ctx.accounts.principal_vault.key();
-    vault.revenue_vault = ctx.accounts.revenue_vault.key();
+    vault.owner = keys.owner;
+    vault.vault_id = params.vault_id;
+    vault.name = params.name;
 
-    vault.total_shares = total_shares;
+    vault.vault_share_mint = keys.vault_share_mint;
+    vault.share_token_program = keys.share_token_program;
+    vault.transfer_hook_enabled = params.transfer_hook_enabled;
+    vault.status = VaultStatus::Fundraising;
+    vault.status_before_pause = VaultStatus::Fundraising;
+    vault.payment_mint = keys.payment_mint;
+    vault.principal_vault = keys.principal_vault;
+    vault.revenue_vault = keys.revenue_vault;
+
+    vault.total_shares = params.total_shares;
     vault.minted_shares = 0;
-    vault.price_per_share = price_per_share;
+    vault.price_per_share = params.price_per_share;
 
     vault.acc_reward_per_share = 0;
     vault.reward_remainder = 0;
 
     // Performance Fee Layer initialization
-    vault.performance_fee_bps = performance_fee_bps;
-    vault.treasury = ctx.accounts.treasury.key();
+    vault.performance_fee_bps = params.performance_fee_bps;
+    vault.treasury = keys.treasury;
     vault.total_fees_collected = 0;
+    vault.total_fees_withdrawn = 0;
+    vault.fee_recipient = Pubkey::default();
+
+    // Revenue Access Layer initialization - deposits stay open until the authority restricts them
+    vault.open_revenue_deposits = true;
+
+    // NAV Pricing Layer initialization - vaults start on fixed pricing
+    vault.nav_pricing_enabled = false;
+    vault.net_asset_value = 0;
+    vault.nav_oracle = Pubkey::default();
+    vault.max_nav_change_bps = 0;
+    vault.nav_updated_at = 0;
 
     // Governance Layer initialization - owner becomes initial authority
-    vault.authority = *ctx.accounts.owner.key;
+    vault.authority = keys.owner;
+    vault.pending_authority = Pubkey::default();
+    vault.governance_delay = DEFAULT_GOVERNANCE_DELAY;
+    vault.proposal_count = 0;
+    vault.shareholder_proposal_count = 0;
+    vault.active_shareholder_proposal = Pubkey::default();
+
+    // Emergency Pause Layer initialization - no guardian, nothing paused
+    vault.guardian = Pubkey::default();
+    vault.paused_instructions = 0;
+
+    // Compliance Layer initialization - unrestricted minting, authority handles compliance
+    vault.compliance_authority = Pubkey::default();
+    vault.mint_allowlist_required = false;
+    vault.forced_transfer_count = 0;
+
+    // Investment Limits Layer initialization - no limits, no holders yet
+    vault.min_mint_amount = 0;
+    vault.max_shares_per_holder = 0;
+    vault.max_holder_count = 0;
+    vault.holder_count = 0;
+
+    // Subscription Window Layer initialization
+    vault.subscription_start = params.subscription_start;
+    vault.subscription_end = params.subscription_end;
+    vault.soft_cap = params.soft_cap;
+
+    // Principal Deployment Layer initialization - all principal held on-chain
+    vault.principal_operator = Pubkey::default();
+    vault.principal_deployed = 0;
+    vault.has_operator_debt = false;
+
+    // Impairment Layer initialization - shares start at full par value
+    vault.impairment_bps = 0;
+    vault.principal_written_down = 0;
+    vault.written_down_at = 0;
+
+    // Emergency Exit Layer initialization - exit clock idle until redemptions halt
+    vault.emergency_exit_delay = DEFAULT_EMERGENCY_EXIT_DELAY;
+    vault.redemptions_halted_at = 0;
+
+    // Redemption Queue Layer initialization - empty queue, no notice period
+    vault.redemption_notice_period = 0;
+    vault.redemption_request_count = 0;
+    vault.next_redemption_to_fill = 0;
+    vault.queued_shares = 0;
+
+    vault.bump = bump;
+    vault.signer_bump = signer_bump;
 
-    vault.bump = ctx.bumps.vault;
-    vault.signer_bump = ctx.bumps.vault_signer;
+    emit!(VaultInitialized {
+        vault: vault.key(),
+        owner: vault.owner,
+        vault_id: vault.vault_id,
+        name: vault.name.clone(),
+        vault_share_mint: vault.vault_share_mint,
+        payment_mint: vault.payment_mint,
+        principal_vault: vault.principal_vault,
+        revenue_vault: vault.revenue_vault,
+        treasury: vault.treasury,
+        total_shares: vault.total_shares,
+        price_per_share: vault.price_per_share,
+        performance_fee_bps: vault.performance_fee_bps,
+        transfer_hook_enabled: vault.transfer_hook_enabled,
+        subscription_start: vault.subscription_start,
+        subscription_end: vault.subscription_end,
+        soft_cap: vault.soft_cap,
+        timestamp: now,
+    });
 
     Ok(())
 }
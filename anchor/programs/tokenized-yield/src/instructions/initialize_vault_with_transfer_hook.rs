@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::states::Vault;
+use super::initialize_vault::{initialize_vault_state, VaultKeys, VaultParams};
+
+/// Same as `InitializeVault`, but the share mint is created under Token-2022
+/// with a transfer-hook extension pointing back at this program, and
+/// `vault_signer` as permanent delegate so `force_transfer_shares` can burn.
+#[derive(Accounts)]
+#[instruction(vault_id: u64)]
+pub struct InitializeVaultWithTransferHook<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [b"vault", owner.key().as_ref(), vault_id.to_le_bytes().as_ref()],
+        bump,
+        payer = owner,
+        space = 8 + Vault::INIT_SPACE,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        init,
+        payer = owner,
+        mint::decimals = 6,
+        mint::authority = vault_signer.key(),
+        mint::freeze_authority = vault_signer.key(),
+        mint::token_program = share_token_program,
+        extensions::transfer_hook::authority = vault_signer,
+        extensions::transfer_hook::program_id = crate::ID,
+        extensions::permanent_delegate::delegate = vault_signer,
+        seeds = [b"vault_share_mint", vault.key().as_ref()],
+        bump
+    )]
+    pub vault_share_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        seeds = [b"vault_signer", vault.key().as_ref()],
+        bump,
+        payer = owner,
+        space = 8,
+    )]
+    /// CHECK: PDA signer
+    pub vault_signer: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        token::mint = payment_mint,
+        token::authority = vault_signer,
+        token::token_program = token_program,
+        seeds = [b"principal-vault", vault.key().as_ref()],
+        bump,
+        payer = owner
+    )]
+    pub principal_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mint::token_program = token_program)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    /// Holds distributable revenue, paid out in the payment mint
+    #[account(
+        init,
+        token::mint = payment_mint,
+        token::authority = vault_signer,
+        token::token_program = token_program,
+        seeds = [b"revenue-vault", vault.key().as_ref()],
+        bump,
+        payer = owner
+    )]
+    pub revenue_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Treasury PDA token account that receives performance fees
+    #[account(
+        init,
+        token::mint = payment_mint,
+        token::authority = vault_signer,
+        token::token_program = token_program,
+        seeds = [b"treasury", vault.key().as_ref()],
+        bump,
+        payer = owner
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub share_token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process_initialize_vault_with_transfer_hook(
+    ctx: Context<InitializeVaultWithTransferHook>,
+    vault_id: u64,
+    name: String,
+    total_shares: u64,
+    price_per_share: u64,
+    performance_fee_bps: u16,
+    subscription_start: i64,
+    subscription_end: i64,
+    soft_cap: u64,
+) -> Result<()> {
+    let keys = VaultKeys {
+        owner: ctx.accounts.owner.key(),
+        vault_share_mint: ctx.accounts.vault_share_mint.key(),
+        share_token_program: ctx.accounts.share_token_program.key(),
+        payment_mint: ctx.accounts.payment_mint.key(),
+        principal_vault: ctx.accounts.principal_vault.key(),
+        revenue_vault: ctx.accounts.revenue_vault.key(),
+        treasury: ctx.accounts.treasury.key(),
+    };
+    let params = VaultParams {
+        vault_id,
+        name,
+        total_shares,
+        price_per_share,
+        performance_fee_bps,
+        subscription_start,
+        subscription_end,
+        soft_cap,
+        transfer_hook_enabled: true,
+    };
+
+    initialize_vault_state(
+        &mut ctx.accounts.vault,
+        keys,
+        params,
+        ctx.bumps.vault,
+        ctx.bumps.vault_signer,
+    )
+}
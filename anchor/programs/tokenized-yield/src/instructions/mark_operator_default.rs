@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::{OperatorDebt, Vault, error::ErrorCode, events::OperatorDefaultMarked};
+use crate::states::vault::{PAUSE_MINT, PAUSE_REDEEM};
+
+#[derive(Accounts)]
+pub struct MarkOperatorDefault<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_debt", vault.key().as_ref()],
+        bump = operator_debt.bump
+    )]
+    pub operator_debt: Account<'info, OperatorDebt>,
+}
+
+/// Declares the operator in default once it has missed the schedule.
+/// Halts new subscriptions, redemptions and further drawdowns, so no holder
+/// can redeem at par ahead of the others before the loss is sized. The loss
+/// itself is recognised separately with `write_down`, after which the
+/// authority can reopen redemptions at the impaired price.
+pub fn process_mark_operator_default(ctx: Context<MarkOperatorDefault>) -> Result<()> {
+    let operator_debt = ctx.accounts.operator_debt.key();
+    apply_mark_operator_default(
+        &mut ctx.accounts.vault,
+        ctx.accounts.authority.key(),
+        &mut ctx.accounts.operator_debt,
+        operator_debt,
+    )
+}
+
+/// Shared with multisig execution
+pub(crate) fn apply_mark_operator_default(
+    vault: &mut Account<Vault>,
+    authority: Pubkey,
+    debt: &mut OperatorDebt,
+    operator_debt: Pubkey,
+) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+    require!(!debt.defaulted, ErrorCode::OperatorInDefault);
+
+    let now = Clock::get()?.unix_timestamp;
+    debt.accrue_interest(vault.principal_deployed, now)?;
+    require!(debt.is_overdue(vault.principal_deployed, now), ErrorCode::DebtNotOverdue);
+
+    debt.defaulted = true;
+    debt.defaulted_at = now;
+    vault.paused_instructions |= PAUSE_MINT | PAUSE_REDEEM;
+    vault.sync_redemption_halt(now);
+
+    emit!(OperatorDefaultMarked {
+        vault: vault.key(),
+        operator_debt,
+        authority,
+        principal_deployed: vault.principal_deployed,
+        accrued_interest: debt.accrued_interest,
+        timestamp: now,
+    });
+
+    Ok(())
+}
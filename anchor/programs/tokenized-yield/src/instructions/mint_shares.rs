@@ -1,11 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{self, Mint, MintTo, Token, TokenAccount, Transfer, transfer},
+    token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked, transfer_checked},
 };
 
 pub use crate::UserStake;
-use crate::{error::ErrorCode, Vault};
+use crate::{InvestorAllowlist, error::ErrorCode, events::SharesMinted, transfer_fee::gross_amount_for_net, Vault};
+use crate::states::vault::PAUSE_MINT;
 
 #[derive(Accounts)]
 pub struct MintShares<'info> {
@@ -28,36 +29,38 @@ pub struct MintShares<'info> {
         constraint = payer_ata.mint == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
         constraint = payer_ata.owner == payer.key() @ ErrorCode::InvalidTokenAccountOwner
     )]
-    pub payer_ata: Account<'info, TokenAccount>,
+    pub payer_ata: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         constraint = principal_vault.key() == vault.principal_vault @ ErrorCode::InvalidPaymentVault,
         constraint = principal_vault.owner == vault_signer.key() @ ErrorCode::InvalidPaymentVault
     )]
-    pub principal_vault: Account<'info, TokenAccount>,
+    pub principal_vault: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         constraint = revenue_vault.key() == vault.revenue_vault @ ErrorCode::InvalidPaymentVault,
         constraint = revenue_vault.owner == vault_signer.key() @ ErrorCode::InvalidPaymentVault
     )]
-    pub revenue_vault: Account<'info, TokenAccount>,
+    pub revenue_vault: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         seeds = [b"vault_share_mint", vault.key().as_ref()],
-        bump
+        bump,
+        mint::token_program = share_token_program
     )]
-    pub vault_share_mint: Account<'info, Mint>,
+    pub vault_share_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         init_if_needed,
         payer = payer,
         associated_token::mint = vault_share_mint,
         associated_token::authority = payer,
+        associated_token::token_program = share_token_program,
     )]
-    pub investor_share_ata: Account<'info, TokenAccount>,
+    pub investor_share_ata: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         init_if_needed,
@@ -69,14 +72,47 @@ pub struct MintShares<'info> {
     pub shareholder: Account<'info, UserStake>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+
+    #[account(
+        constraint = payment_mint.key() == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        mint::token_program = token_program
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(
+        constraint = share_token_program.key() == vault.share_token_program @ ErrorCode::InvalidShareTokenProgram
+    )]
+    pub share_token_program: Interface<'info, TokenInterface>,
+
+    /// Buyer's verification, required while `vault.mint_allowlist_required` is set
+    #[account(
+        seeds = [b"investor_allowlist", vault.key().as_ref(), payer.key().as_ref()],
+        bump = investor_allowlist.bump
+    )]
+    pub investor_allowlist: Option<Account<'info, InvestorAllowlist>>,
 }
 
 pub fn process_mint_shares(ctx: Context<MintShares>, amount: u64) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
     let shareholder = &mut ctx.accounts.shareholder;
 
+    require!(vault.status.allows_minting(), ErrorCode::InvalidVaultStatus);
+    require!(!vault.is_paused(PAUSE_MINT), ErrorCode::InstructionPaused);
     require!(amount > 0, ErrorCode::InvalidShareAmount);
+    require!(amount >= vault.min_mint_amount, ErrorCode::BelowMinimumMint);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= vault.subscription_start, ErrorCode::SubscriptionNotStarted);
+    require!(!vault.subscription_ended(now), ErrorCode::SubscriptionEnded);
+
+    if vault.mint_allowlist_required {
+        let entry = ctx.accounts.investor_allowlist.as_ref().ok_or(ErrorCode::InvestorNotAllowlisted)?;
+        require!(
+            entry.is_valid_at(now),
+            ErrorCode::AllowlistEntryExpired
+        );
+    }
 
     // 1. Calculate new vault state
     let new_minted = vault
@@ -90,19 +126,14 @@ pub fn process_mint_shares(ctx: Context<MintShares>, amount: u64) -> Result<()>
         ErrorCode::ExceedsTotalSupply
     );
 
-    // Calculate expected payment
-    let expected_payment = amount
-        .checked_mul(vault.price_per_share)
+    // Calculate expected payment (fixed price, or NAV per share in NAV mode)
+    let expected_payment = vault
+        .mint_cost(amount)
         .ok_or(ErrorCode::MathOverflow)?;
 
     // 2. Calculate new shareholder state
-    if shareholder.is_initialized == false {
-        shareholder.is_initialized = true;
-        shareholder.owner = *ctx.accounts.payer.key;
-        shareholder.vault = vault.key();
-        shareholder.quantity = 0u64;
-        shareholder.reward_debt = 0u128;
-        shareholder.bump = ctx.bumps.shareholder;
+    if !shareholder.is_initialized {
+        shareholder.initialize(ctx.accounts.payer.key(), vault.key(), ctx.bumps.shareholder);
     }
 
     // Compute pending reward for existing shares (if any)
@@ -125,7 +156,15 @@ pub fn process_mint_shares(ctx: Context<MintShares>, amount: u64) -> Result<()>
         .ok_or(ErrorCode::Overflow)?;
 
     // 3. Mutate State (Effects)
+    vault.track_holder(shareholder.quantity, new_quantity)?;
+    shareholder.snapshot_voting_power(vault.active_shareholder_proposal);
     vault.minted_shares = new_minted;
+    if vault.nav_pricing_enabled {
+        vault.net_asset_value = vault
+            .net_asset_value
+            .checked_add(expected_payment)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
     shareholder.quantity = new_quantity;
     
     // Update reward debt based on new quantity
@@ -135,34 +174,52 @@ pub fn process_mint_shares(ctx: Context<MintShares>, amount: u64) -> Result<()>
         .checked_div(crate::constants::PRECISION)
         .ok_or(ErrorCode::MathOverflow)?;
 
+    // Include rewards settled at earlier checkpoints (share transfers)
+    let pending_u64 = u64::try_from(pending)
+        .map_err(|_| ErrorCode::Overflow)?
+        .checked_add(shareholder.pending_rewards)
+        .ok_or(ErrorCode::Overflow)?;
+    shareholder.pending_rewards = 0;
+
     // 4. Perform CPIs (Interactions)
     
     // Transfer pending rewards if any
-    if pending > 0 {
-        let pending_u64 = u64::try_from(pending).map_err(|_| ErrorCode::Overflow)?;
-        
+    if pending_u64 > 0 {
         let vault_key = vault.key();
         let seeds = &[b"vault_signer".as_ref(), vault_key.as_ref(), &[vault.signer_bump]];
         let signer = &[&seeds[..]];
 
         // We transfer from revenue_vault to payer_ata (user's wallet)
-        let cpi_accounts_reward = Transfer {
+        let cpi_accounts_reward = TransferChecked {
             from: ctx.accounts.revenue_vault.to_account_info(),
+            mint: ctx.accounts.payment_mint.to_account_info(),
             to: ctx.accounts.payer_ata.to_account_info(),
             authority: ctx.accounts.vault_signer.to_account_info(),
         };
         let cpi_program_token = ctx.accounts.token_program.to_account_info();
-        transfer(CpiContext::new_with_signer(cpi_program_token, cpi_accounts_reward, signer), pending_u64)?;
+        transfer_checked(
+            CpiContext::new_with_signer(cpi_program_token, cpi_accounts_reward, signer),
+            pending_u64,
+            ctx.accounts.payment_mint.decimals,
+        )?;
     }
     
-    // Transfer payment to principal vault
-    let cpi_accounts_transfer = Transfer {
+    // Transfer payment to principal vault.
+    // The payer covers any Token-2022 transfer fee, so the principal vault is
+    // credited exactly the share cost.
+    let gross_payment = gross_amount_for_net(&ctx.accounts.payment_mint.to_account_info(), expected_payment)?;
+    let cpi_accounts_transfer = TransferChecked {
         from: ctx.accounts.payer_ata.to_account_info(),
+        mint: ctx.accounts.payment_mint.to_account_info(),
         to: ctx.accounts.principal_vault.to_account_info(),
         authority: ctx.accounts.payer.to_account_info(),
     };
     let cpi_program_token = ctx.accounts.token_program.to_account_info();
-    transfer(CpiContext::new(cpi_program_token, cpi_accounts_transfer), expected_payment)?;
+    transfer_checked(
+        CpiContext::new(cpi_program_token, cpi_accounts_transfer),
+        gross_payment,
+        ctx.accounts.payment_mint.decimals,
+    )?;
 
     // Mint shares to user
     let vault_key = vault.key();
@@ -174,14 +231,25 @@ pub fn process_mint_shares(ctx: Context<MintShares>, amount: u64) -> Result<()>
         to: ctx.accounts.investor_share_ata.to_account_info(),
         authority: ctx.accounts.vault_signer.to_account_info(),
     };
-    token::mint_to(
+    token_interface::mint_to(
         CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.share_token_program.to_account_info(),
             cpi_accounts_mint,
             signer,
         ),
         amount,
     )?;
 
+    emit!(SharesMinted {
+        vault: vault_key,
+        investor: ctx.accounts.payer.key(),
+        amount,
+        payment_amount: expected_payment,
+        rewards_paid: pending_u64,
+        investor_quantity: new_quantity,
+        minted_shares: new_minted,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
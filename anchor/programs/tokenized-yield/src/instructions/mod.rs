@@ -13,15 +13,165 @@ pub use harvest::*;
 pub mod redeem_shares;
 pub use redeem_shares::*;
 
+pub mod transfer_shares;
+pub use transfer_shares::*;
+
+// Token-2022 Transfer Hook Instructions
+pub mod initialize_vault_with_transfer_hook;
+pub use initialize_vault_with_transfer_hook::*;
+
+pub mod initialize_extra_account_meta_list;
+pub use initialize_extra_account_meta_list::*;
+
+pub mod initialize_shareholder;
+pub use initialize_shareholder::*;
+
+pub mod transfer_hook;
+pub use transfer_hook::*;
+
 // Governance Instructions
+pub mod set_vault_status;
+pub use set_vault_status::*;
+
 pub mod update_performance_fee;
 pub use update_performance_fee::*;
 
-pub mod update_treasury;
-pub use update_treasury::*;
+pub mod propose_governance_change;
+pub use propose_governance_change::*;
+
+pub mod execute_governance_change;
+pub use execute_governance_change::*;
+
+pub mod cancel_governance_change;
+pub use cancel_governance_change::*;
+
+pub mod set_fee_recipient;
+pub use set_fee_recipient::*;
+
+pub mod set_open_revenue_deposits;
+pub use set_open_revenue_deposits::*;
+
+pub mod add_revenue_depositor;
+pub use add_revenue_depositor::*;
+
+pub mod remove_revenue_depositor;
+pub use remove_revenue_depositor::*;
+
+pub mod withdraw_fees;
+pub use withdraw_fees::*;
+
+pub mod sweep_fees;
+pub use sweep_fees::*;
+
+pub mod configure_nav_pricing;
+pub use configure_nav_pricing::*;
+
+pub mod update_nav;
+pub use update_nav::*;
+
+// Emergency Pause Instructions
+pub mod set_guardian;
+pub use set_guardian::*;
+
+pub mod pause;
+pub use pause::*;
+
+pub mod unpause;
+pub use unpause::*;
+
+// Compliance Instructions
+pub mod set_compliance_authority;
+pub use set_compliance_authority::*;
+
+pub mod set_mint_allowlist_required;
+pub use set_mint_allowlist_required::*;
+
+pub mod set_investor_allowlist;
+pub use set_investor_allowlist::*;
+
+pub mod remove_investor_allowlist;
+pub use remove_investor_allowlist::*;
+
+pub mod freeze_shareholder;
+pub use freeze_shareholder::*;
+
+pub mod unfreeze_shareholder;
+pub use unfreeze_shareholder::*;
+
+pub mod force_transfer_shares;
+pub use force_transfer_shares::*;
+
+// Investment Limits Instructions
+pub mod set_investment_limits;
+pub use set_investment_limits::*;
+
+// Subscription Window Instructions
+pub mod extend_subscription;
+pub use extend_subscription::*;
+pub mod finalize_subscription;
+pub use finalize_subscription::*;
+pub mod refund;
+pub use refund::*;
+
+// Principal Deployment Instructions
+pub mod release_principal;
+pub use release_principal::*;
+pub mod return_principal;
+pub use return_principal::*;
+
+// Operator Debt Instructions
+pub mod initialize_operator_debt;
+pub use initialize_operator_debt::*;
+pub mod mark_operator_default;
+pub use mark_operator_default::*;
+
+// Impairment Instructions
+pub mod write_down;
+pub use write_down::*;
+
+// Emergency Exit Instructions
+pub mod emergency_withdraw;
+pub use emergency_withdraw::*;
+
+// Redemption Queue Instructions
+pub mod request_redemption;
+pub use request_redemption::*;
+pub mod process_redemptions;
+pub use process_redemptions::*;
+pub mod cancel_redemption;
+pub use cancel_redemption::*;
+
+// Multisig Instructions
+pub mod create_multisig;
+pub use create_multisig::*;
+
+pub mod create_multisig_transaction;
+pub use create_multisig_transaction::*;
+
+pub mod approve_multisig_transaction;
+pub use approve_multisig_transaction::*;
+
+pub mod execute_multisig_transaction;
+pub use execute_multisig_transaction::*;
+
+// Shareholder Voting Instructions
+pub mod create_shareholder_proposal;
+pub use create_shareholder_proposal::*;
+
+pub mod cast_shareholder_vote;
+pub use cast_shareholder_vote::*;
+
+pub mod execute_shareholder_proposal;
+pub use execute_shareholder_proposal::*;
+
+pub mod propose_authority;
+pub use propose_authority::*;
+
+pub mod accept_authority;
+pub use accept_authority::*;
 
-pub mod transfer_authority;
-pub use transfer_authority::*;
+pub mod cancel_pending_authority;
+pub use cancel_pending_authority::*;
 
 pub mod revoke_authority;
 pub use revoke_authority::*;
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::{Vault, error::ErrorCode, events::PauseUpdated};
+use crate::states::vault::PAUSE_ALL;
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// Guardian or authority
+    #[account(
+        constraint = vault.is_pauser(&pauser.key()) @ ErrorCode::Unauthorized
+    )]
+    pub pauser: Signer<'info>,
+}
+
+/// Halts the instructions in `flags` (`PAUSE_*` bitmask, `PAUSE_ALL` for everything).
+/// Deliberately not gated on governance: the guardian can still pause after
+/// `revoke_authority`.
+pub fn process_pause(ctx: Context<Pause>, flags: u8) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    require!(flags != 0 && flags & !PAUSE_ALL == 0, ErrorCode::InvalidPauseFlags);
+
+    let now = Clock::get()?.unix_timestamp;
+    vault.paused_instructions |= flags;
+    vault.sync_redemption_halt(now);
+
+    emit!(PauseUpdated {
+        vault: vault.key(),
+        caller: ctx.accounts.pauser.key(),
+        flags,
+        paused: true,
+        paused_instructions: vault.paused_instructions,
+        timestamp: now,
+    });
+
+    Ok(())
+}
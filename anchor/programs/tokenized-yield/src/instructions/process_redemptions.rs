@@ -0,0 +1,237 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    FreezeAccount, Mint, ThawAccount, TokenAccount, TokenInterface, TransferChecked, freeze_account, thaw_account,
+    transfer_checked,
+};
+use crate::{
+    RedemptionRequest, RedemptionStatus, UserStake, Vault, error::ErrorCode, events::RedemptionProcessed,
+};
+use crate::states::vault::PAUSE_REDEEM;
+use super::apply_cancel_redemption;
+
+#[derive(Accounts)]
+pub struct ProcessRedemptions<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: PDA Signer
+    #[account(
+        seeds = [b"vault_signer", vault.key().as_ref()],
+        bump = vault.signer_bump
+    )]
+    pub vault_signer: UncheckedAccount<'info>,
+
+    /// Anyone may crank the queue; principal can only reach the requester's own account
+    pub cranker: Signer<'info>,
+
+    /// Head of the queue
+    #[account(
+        mut,
+        seeds = [b"redemption_request", vault.key().as_ref(), vault.next_redemption_to_fill.to_le_bytes().as_ref()],
+        bump = request.bump
+    )]
+    pub request: Account<'info, RedemptionRequest>,
+
+    #[account(
+        mut,
+        seeds = [b"shareholder", vault.key().as_ref(), request.holder.as_ref()],
+        bump = shareholder.bump,
+        constraint = shareholder.vault == vault.key() @ ErrorCode::InvalidShareholder
+    )]
+    pub shareholder: Account<'info, UserStake>,
+
+    #[account(
+        mut,
+        constraint = principal_vault.key() == vault.principal_vault @ ErrorCode::InvalidPaymentVault,
+        constraint = principal_vault.owner == vault_signer.key() @ ErrorCode::InvalidPaymentVault
+    )]
+    pub principal_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = holder_payment_account.mint == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        constraint = holder_payment_account.owner == request.holder @ ErrorCode::InvalidTokenAccountOwner
+    )]
+    pub holder_payment_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = payment_mint.key() == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        mint::token_program = token_program
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Re-mint target when the head's holder is frozen
+    #[account(
+        mut,
+        constraint = holder_share_account.mint == vault.vault_share_mint @ ErrorCode::InvalidShareMint,
+        constraint = holder_share_account.owner == request.holder @ ErrorCode::InvalidTokenAccountOwner
+    )]
+    pub holder_share_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_share_mint", vault.key().as_ref()],
+        bump
+    )]
+    pub vault_share_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = share_token_program.key() == vault.share_token_program @ ErrorCode::InvalidShareTokenProgram
+    )]
+    pub share_token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+/// Settles the request at the head of the queue and moves the head on.
+///
+/// A queued request is filled once its notice period is over and the liquid
+/// principal covers it, priced at fill time. Rewards earned while queued are
+/// checkpointed into `pending_rewards` for `harvest`. Cancelled requests are
+/// skipped. A frozen holder's request is cancelled and its shares re-minted, so
+/// the holder keeps the position (and can emergency-exit) without stalling the
+/// requests behind it.
+pub fn process_process_redemptions(ctx: Context<ProcessRedemptions>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let shareholder = &mut ctx.accounts.shareholder;
+    let request = &mut ctx.accounts.request;
+
+    let now = Clock::get()?.unix_timestamp;
+    if request.status == RedemptionStatus::Queued && shareholder.frozen {
+        let holder_share_account = ctx.accounts.holder_share_account.as_ref().ok_or(ErrorCode::InvalidTokenAccountOwner)?;
+        let vault_share_mint = ctx.accounts.vault_share_mint.as_ref().ok_or(ErrorCode::InvalidShareMint)?;
+        let share_token_program = ctx.accounts.share_token_program.as_ref().ok_or(ErrorCode::InvalidShareTokenProgram)?;
+
+        let vault_key = vault.key();
+        let seeds = &[b"vault_signer".as_ref(), vault_key.as_ref(), &[vault.signer_bump]];
+        let signer = &[&seeds[..]];
+
+        // `freeze_shareholder` usually froze this account too; thaw it around the re-mint
+        let refreeze = holder_share_account.is_frozen();
+        if refreeze {
+            thaw_account(CpiContext::new_with_signer(
+                share_token_program.to_account_info(),
+                ThawAccount {
+                    account: holder_share_account.to_account_info(),
+                    mint: vault_share_mint.to_account_info(),
+                    authority: ctx.accounts.vault_signer.to_account_info(),
+                },
+                signer,
+            ))?;
+        }
+
+        apply_cancel_redemption(
+            vault,
+            shareholder,
+            request,
+            holder_share_account,
+            vault_share_mint,
+            share_token_program,
+            &ctx.accounts.vault_signer,
+        )?;
+
+        if refreeze {
+            freeze_account(CpiContext::new_with_signer(
+                share_token_program.to_account_info(),
+                FreezeAccount {
+                    account: holder_share_account.to_account_info(),
+                    mint: vault_share_mint.to_account_info(),
+                    authority: ctx.accounts.vault_signer.to_account_info(),
+                },
+                signer,
+            ))?;
+        }
+    }
+
+    let fill = request.status == RedemptionStatus::Queued;
+    let mut principal = 0;
+
+    if fill {
+        require!(vault.status.allows_exits(), ErrorCode::InvalidVaultStatus);
+        require!(!vault.is_paused(PAUSE_REDEEM), ErrorCode::InstructionPaused);
+        require!(now >= request.unlock_at, ErrorCode::RedemptionNotUnlocked);
+
+        principal = vault
+            .redemption_value(request.amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        // FIFO: the head waits for liquidity rather than being skipped
+        if ctx.accounts.principal_vault.amount < principal {
+            return if vault.principal_deployed > 0 {
+                err!(ErrorCode::PrincipalDeployed)
+            } else {
+                err!(ErrorCode::InsufficientVaultBalance)
+            };
+        }
+
+        // Settle rewards and release the queued shares (Effects)
+        let amount = request.amount;
+        let new_quantity = shareholder.quantity
+            .checked_sub(amount)
+            .ok_or(ErrorCode::Underflow)?;
+
+        shareholder.checkpoint_rewards(vault.acc_reward_per_share)?;
+        vault.track_holder(shareholder.quantity, new_quantity)?;
+        shareholder.snapshot_voting_power(vault.active_shareholder_proposal);
+        shareholder.rebase_quantity(new_quantity, vault.acc_reward_per_share)?;
+        shareholder.queued_quantity = shareholder.queued_quantity
+            .checked_sub(amount)
+            .ok_or(ErrorCode::Underflow)?;
+
+        vault.minted_shares = vault.minted_shares
+            .checked_sub(amount)
+            .ok_or(ErrorCode::Underflow)?;
+        vault.queued_shares = vault.queued_shares
+            .checked_sub(amount)
+            .ok_or(ErrorCode::Underflow)?;
+        if vault.nav_pricing_enabled {
+            vault.net_asset_value = vault
+                .net_asset_value
+                .checked_sub(principal)
+                .ok_or(ErrorCode::Underflow)?;
+        }
+
+        request.status = RedemptionStatus::Filled;
+        request.principal_paid = principal;
+    }
+
+    vault.next_redemption_to_fill = vault.next_redemption_to_fill
+        .checked_add(1)
+        .ok_or(ErrorCode::Overflow)?;
+
+    // Pay the principal (Interactions); the shares were burned when queued
+    if principal > 0 {
+        let vault_key = vault.key();
+        let seeds = &[b"vault_signer".as_ref(), vault_key.as_ref(), &[vault.signer_bump]];
+        let signer = &[&seeds[..]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.principal_vault.to_account_info(),
+                    mint: ctx.accounts.payment_mint.to_account_info(),
+                    to: ctx.accounts.holder_payment_account.to_account_info(),
+                    authority: ctx.accounts.vault_signer.to_account_info(),
+                },
+                signer,
+            ),
+            principal,
+            ctx.accounts.payment_mint.decimals,
+        )?;
+    }
+
+    emit!(RedemptionProcessed {
+        vault: vault.key(),
+        request_id: request.request_id,
+        holder: request.holder,
+        cranker: ctx.accounts.cranker.key(),
+        amount: request.amount,
+        filled: fill,
+        principal_paid: principal,
+        minted_shares: vault.minted_shares,
+        timestamp: now,
+    });
+
+    Ok(())
+}
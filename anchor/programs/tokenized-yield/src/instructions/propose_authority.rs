@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::{Vault, error::ErrorCode, events::AuthorityProposed};
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// First step of an authority handover. Nothing changes until the proposed
+/// key signs `accept_authority`; proposing again replaces the pending key.
+pub fn process_propose_authority(
+    ctx: Context<ProposeAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    apply_authority_proposal(&mut ctx.accounts.vault, authority, new_authority)
+}
+
+/// Shared with multisig execution
+pub(crate) fn apply_authority_proposal(vault: &mut Account<Vault>, authority: Pubkey, new_authority: Pubkey) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    // New authority cannot be zero address (use revoke_authority for that)
+    require!(
+        new_authority != Pubkey::default(),
+        ErrorCode::InvalidAuthority
+    );
+
+    vault.pending_authority = new_authority;
+
+    emit!(AuthorityProposed {
+        vault: vault.key(),
+        authority,
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
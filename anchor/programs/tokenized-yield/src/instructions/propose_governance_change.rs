@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use crate::{GovernanceAction, GovernanceProposal, ProposalStatus, Vault, error::ErrorCode, events::GovernanceChangeQueued};
+use crate::states::vault::{
+    MAX_EMERGENCY_EXIT_DELAY, MAX_GOVERNANCE_DELAY, MAX_PERFORMANCE_FEE_BPS, MAX_REDEMPTION_NOTICE_PERIOD,
+    MIN_EMERGENCY_EXIT_DELAY, MIN_GOVERNANCE_DELAY,
+};
+
+#[derive(Accounts)]
+pub struct ProposeGovernanceChange<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"proposal", vault.key().as_ref(), vault.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + GovernanceProposal::INIT_SPACE
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Checks the parameters of `action` against the same bounds execution enforces,
+/// so an invalid change is rejected before anyone waits out the timelock.
+pub(crate) fn validate_governance_action(action: &GovernanceAction) -> Result<()> {
+    match *action {
+        GovernanceAction::UpdatePerformanceFee { new_fee_bps } => {
+            require!(
+                new_fee_bps <= MAX_PERFORMANCE_FEE_BPS,
+                ErrorCode::PerformanceFeeExceedsMax
+            );
+        }
+        GovernanceAction::UpdateTreasury { new_treasury } => {
+            require!(new_treasury != Pubkey::default(), ErrorCode::InvalidTreasury);
+        }
+        GovernanceAction::UpdateGovernanceDelay { new_delay } => {
+            require!(
+                (MIN_GOVERNANCE_DELAY..=MAX_GOVERNANCE_DELAY).contains(&new_delay),
+                ErrorCode::InvalidGovernanceDelay
+            );
+        }
+        GovernanceAction::UpdatePrincipalOperator { new_operator } => {
+            require!(new_operator != Pubkey::default(), ErrorCode::InvalidPrincipalOperator);
+        }
+        GovernanceAction::UpdateEmergencyExitDelay { new_delay } => {
+            require!(
+                (MIN_EMERGENCY_EXIT_DELAY..=MAX_EMERGENCY_EXIT_DELAY).contains(&new_delay),
+                ErrorCode::InvalidEmergencyExitDelay
+            );
+        }
+        GovernanceAction::UpdateRedemptionNoticePeriod { new_notice_period } => {
+            require!(
+                (0..=MAX_REDEMPTION_NOTICE_PERIOD).contains(&new_notice_period),
+                ErrorCode::InvalidRedemptionNoticePeriod
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Queues a parameter change that can execute once `vault.governance_delay` has passed
+pub fn process_propose_governance_change(
+    ctx: Context<ProposeGovernanceChange>,
+    action: GovernanceAction,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let proposal = &mut ctx.accounts.proposal;
+
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    validate_governance_action(&action)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let eta = now
+        .checked_add(vault.governance_delay)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    proposal.vault = vault.key();
+    proposal.proposal_id = vault.proposal_count;
+    proposal.proposer = ctx.accounts.authority.key();
+    proposal.action = action;
+    proposal.status = ProposalStatus::Queued;
+    proposal.created_at = now;
+    proposal.eta = eta;
+    proposal.bump = ctx.bumps.proposal;
+
+    vault.proposal_count = vault.proposal_count
+        .checked_add(1)
+        .ok_or(ErrorCode::Overflow)?;
+
+    emit!(GovernanceChangeQueued {
+        vault: vault.key(),
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        proposer: proposal.proposer,
+        action,
+        eta,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer, Burn, transfer, burn};
-use crate::{UserStake, Vault, error::ErrorCode, constants::PRECISION};
+use anchor_spl::token_interface::{
+    Burn, Mint, TokenAccount, TokenInterface, TransferChecked, burn, transfer_checked,
+};
+use crate::{OperatorDebt, UserStake, Vault, error::ErrorCode, constants::PRECISION, events::SharesRedeemed};
+use crate::states::vault::PAUSE_REDEEM;
 
 #[derive(Accounts)]
 pub struct RedeemShares<'info> {
@@ -31,37 +34,55 @@ pub struct RedeemShares<'info> {
         constraint = principal_vault.key() == vault.principal_vault @ ErrorCode::InvalidPaymentVault,
         constraint = principal_vault.owner == vault_signer.key() @ ErrorCode::InvalidPaymentVault
     )]
-    pub principal_vault: Account<'info, TokenAccount>,
+    pub principal_vault: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         constraint = revenue_vault.key() == vault.revenue_vault @ ErrorCode::InvalidPaymentVault,
         constraint = revenue_vault.owner == vault_signer.key() @ ErrorCode::InvalidPaymentVault
     )]
-    pub revenue_vault: Account<'info, TokenAccount>,
+    pub revenue_vault: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         constraint = investor_share_ata.mint == vault.vault_share_mint @ ErrorCode::InvalidPaymentMint,
         constraint = investor_share_ata.owner == payer.key() @ ErrorCode::InvalidTokenAccountOwner
     )]
-    pub investor_share_ata: Account<'info, TokenAccount>,
+    pub investor_share_ata: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         seeds = [b"vault_share_mint", vault.key().as_ref()],
-        bump
+        bump,
+        mint::token_program = share_token_program
     )]
-    pub vault_share_mint: Account<'info, Mint>,
+    pub vault_share_mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         mut,
         constraint = payer_ata.mint == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
         constraint = payer_ata.owner == payer.key() @ ErrorCode::InvalidTokenAccountOwner
     )]
-    pub payer_ata: Account<'info, TokenAccount>,
+    pub payer_ata: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(
+        constraint = payment_mint.key() == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        mint::token_program = token_program
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(
+        constraint = share_token_program.key() == vault.share_token_program @ ErrorCode::InvalidShareTokenProgram
+    )]
+    pub share_token_program: Interface<'info, TokenInterface>,
+
+    /// Required once the vault has an operator debt ledger
+    #[account(
+        seeds = [b"operator_debt", vault.key().as_ref()],
+        bump = operator_debt.bump
+    )]
+    pub operator_debt: Option<Account<'info, OperatorDebt>>,
 }
 
 pub fn process_redeem_shares(ctx: Context<RedeemShares>, amount: u64) -> Result<()> {
@@ -72,8 +93,16 @@ pub fn process_redeem_shares(ctx: Context<RedeemShares>, amount: u64) -> Result<
     let token_program = &ctx.accounts.token_program;
     let vault_signer = &ctx.accounts.vault_signer;
 
+    require!(vault.status.allows_exits(), ErrorCode::InvalidVaultStatus);
+    require!(!vault.is_paused(PAUSE_REDEEM), ErrorCode::InstructionPaused);
+    // Instant exits would jump ahead of queued requests and skip the notice period
+    require!(!vault.requires_redemption_queue(), ErrorCode::RedemptionQueueRequired);
+    require!(!shareholder.frozen, ErrorCode::ShareholderFrozen);
     require!(amount > 0, ErrorCode::InvalidShareAmount);
-    require!(shareholder.quantity >= amount, ErrorCode::InsufficientShares);
+    require!(shareholder.unlocked_quantity() >= amount, ErrorCode::InsufficientShares);
+
+    let now = Clock::get()?.unix_timestamp;
+    let operator_overdue = vault.operator_overdue(ctx.accounts.operator_debt.as_deref(), now)?;
 
     // STEP A: REWARD SYNC
     // Compute pending reward for EXISTING quantity
@@ -91,9 +120,14 @@ pub fn process_redeem_shares(ctx: Context<RedeemShares>, amount: u64) -> Result<
     let seeds = &[b"vault_signer".as_ref(), vault_key.as_ref(), &[vault.signer_bump]];
     let signer = &[&seeds[..]];
 
-    if pending > 0 {
-        let pending_u64 = u64::try_from(pending).map_err(|_| ErrorCode::Overflow)?;
+    // Include rewards settled at earlier checkpoints (share transfers)
+    let pending_u64 = u64::try_from(pending)
+        .map_err(|_| ErrorCode::Overflow)?
+        .checked_add(shareholder.pending_rewards)
+        .ok_or(ErrorCode::Overflow)?;
+    shareholder.pending_rewards = 0;
 
+    if pending_u64 > 0 {
         require!(
             revenue_vault.amount >= pending_u64,
             ErrorCode::InsufficientVaultBalance
@@ -102,26 +136,33 @@ pub fn process_redeem_shares(ctx: Context<RedeemShares>, amount: u64) -> Result<
         // Update checkpoint before transfer
         shareholder.reward_debt = accumulated;
 
-        let cpi_accounts_reward = Transfer {
+        let cpi_accounts_reward = TransferChecked {
             from: revenue_vault.to_account_info(),
+            mint: ctx.accounts.payment_mint.to_account_info(),
             to: ctx.accounts.payer_ata.to_account_info(), // Send reward to payer
             authority: vault_signer.to_account_info(),
         };
         let cpi_ctx_reward = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts_reward, signer);
-        transfer(cpi_ctx_reward, pending_u64)?;
+        transfer_checked(cpi_ctx_reward, pending_u64, ctx.accounts.payment_mint.decimals)?;
     } else {
         shareholder.reward_debt = accumulated;
     }
 
     // STEP B: PRINCIPAL CALCULATION
-    let principal = amount
-        .checked_mul(vault.price_per_share)
+    // Fixed price, or NAV per share in NAV mode
+    let principal = vault
+        .redemption_value(amount)
         .ok_or(ErrorCode::MathOverflow)?;
 
-    require!(
-        principal_vault.amount >= principal,
-        ErrorCode::InsufficientVaultBalance
-    );
+    // Only the liquid part of the principal can be paid out; name the cause
+    // when the shortfall is capital the operator still holds
+    if principal_vault.amount < principal {
+        return if vault.principal_deployed > 0 {
+            err!(ErrorCode::PrincipalDeployed)
+        } else {
+            err!(ErrorCode::InsufficientVaultBalance)
+        };
+    }
 
     // STEP C: STATE UPDATE (CEI)
     let new_quantity = shareholder.quantity
@@ -132,8 +173,16 @@ pub fn process_redeem_shares(ctx: Context<RedeemShares>, amount: u64) -> Result<
         .checked_sub(amount)
         .ok_or(ErrorCode::Underflow)?;
 
+    vault.track_holder(shareholder.quantity, new_quantity)?;
+    shareholder.snapshot_voting_power(vault.active_shareholder_proposal);
     shareholder.quantity = new_quantity;
     vault.minted_shares = new_minted_shares;
+    if vault.nav_pricing_enabled {
+        vault.net_asset_value = vault
+            .net_asset_value
+            .checked_sub(principal)
+            .ok_or(ErrorCode::Underflow)?;
+    }
 
     // Recompute reward debt for NEW quantity
     // Debt = new_quantity * acc_reward_per_share / PRECISION
@@ -151,17 +200,30 @@ pub fn process_redeem_shares(ctx: Context<RedeemShares>, amount: u64) -> Result<
         from: ctx.accounts.investor_share_ata.to_account_info(),
         authority: ctx.accounts.payer.to_account_info(),
     };
-    let cpi_ctx_burn = CpiContext::new(token_program.to_account_info(), cpi_accounts_burn);
+    let cpi_ctx_burn = CpiContext::new(ctx.accounts.share_token_program.to_account_info(), cpi_accounts_burn);
     burn(cpi_ctx_burn, amount)?;
 
     // 2. Transfer principal
-    let cpi_accounts_principal = Transfer {
+    let cpi_accounts_principal = TransferChecked {
         from: principal_vault.to_account_info(),
+        mint: ctx.accounts.payment_mint.to_account_info(),
         to: ctx.accounts.payer_ata.to_account_info(),
         authority: vault_signer.to_account_info(),
     };
     let cpi_ctx_principal = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts_principal, signer);
-    transfer(cpi_ctx_principal, principal)?;
+    transfer_checked(cpi_ctx_principal, principal, ctx.accounts.payment_mint.decimals)?;
+
+    emit!(SharesRedeemed {
+        vault: vault_key,
+        investor: ctx.accounts.payer.key(),
+        amount,
+        principal,
+        rewards_paid: pending_u64,
+        investor_quantity: new_quantity,
+        minted_shares: new_minted_shares,
+        operator_overdue,
+        timestamp: now,
+    });
 
     Ok(())
 }
@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::extension::permanent_delegate::PermanentDelegate,
+    token_interface::{
+        Burn, Mint, TokenAccount, TokenInterface, TransferChecked, burn, get_mint_extension_data,
+        transfer_checked,
+    },
+};
+use crate::{UserStake, Vault, VaultStatus, error::ErrorCode, events::SharesRefunded};
+
+#[derive(Accounts)]
+pub struct Refund<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: PDA signer: principal vault owner and, on hooked mints, permanent delegate
+    #[account(
+        seeds = [b"vault_signer", vault.key().as_ref()],
+        bump = vault.signer_bump
+    )]
+    pub vault_signer: UncheckedAccount<'info>,
+
+    /// Anyone may crank a refund; principal can only reach the holder's own account
+    pub cranker: Signer<'info>,
+
+    /// CHECK: Wallet being refunded, only used for PDA derivation and owner checks
+    pub holder: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"shareholder", vault.key().as_ref(), holder.key().as_ref()],
+        bump = shareholder.bump,
+        constraint = shareholder.vault == vault.key() @ ErrorCode::InvalidShareholder
+    )]
+    pub shareholder: Account<'info, UserStake>,
+
+    #[account(
+        mut,
+        constraint = principal_vault.key() == vault.principal_vault @ ErrorCode::InvalidPaymentVault,
+        constraint = principal_vault.owner == vault_signer.key() @ ErrorCode::InvalidPaymentVault
+    )]
+    pub principal_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = holder_share_account.mint == vault.vault_share_mint @ ErrorCode::InvalidShareMint,
+        constraint = holder_share_account.owner == holder.key() @ ErrorCode::InvalidTokenAccountOwner
+    )]
+    pub holder_share_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_share_mint", vault.key().as_ref()],
+        bump,
+        mint::token_program = share_token_program
+    )]
+    pub vault_share_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = holder_payment_account.mint == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        constraint = holder_payment_account.owner == holder.key() @ ErrorCode::InvalidTokenAccountOwner
+    )]
+    pub holder_payment_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = payment_mint.key() == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        mint::token_program = token_program
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(
+        constraint = share_token_program.key() == vault.share_token_program @ ErrorCode::InvalidShareTokenProgram
+    )]
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Unwinds a holder's whole position after a failed raise: burns the shares
+/// and returns their principal. Rewards accrued so far are checkpointed into
+/// `pending_rewards` and stay claimable through `harvest`.
+///
+/// Third parties can only crank refunds on share mints with `vault_signer`
+/// as permanent delegate; otherwise the holder must sign.
+pub fn process_refund(ctx: Context<Refund>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let shareholder = &mut ctx.accounts.shareholder;
+
+    require!(vault.status == VaultStatus::Refunding, ErrorCode::InvalidVaultStatus);
+    require!(!shareholder.frozen, ErrorCode::ShareholderFrozen);
+
+    let amount = shareholder.quantity;
+    require!(amount > 0, ErrorCode::InsufficientShares);
+
+    let holder_signed = ctx.accounts.cranker.key() == ctx.accounts.holder.key();
+    if !holder_signed {
+        let delegate = get_mint_extension_data::<PermanentDelegate>(&ctx.accounts.vault_share_mint.to_account_info())
+            .map_err(|_| ErrorCode::RefundRequiresHolder)?;
+        require!(
+            Option::<Pubkey>::from(delegate.delegate) == Some(ctx.accounts.vault_signer.key()),
+            ErrorCode::RefundRequiresHolder
+        );
+    }
+
+    // 1. Principal at the price the holder bought in (NAV per share in NAV mode)
+    let principal = vault
+        .redemption_value(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(
+        ctx.accounts.principal_vault.amount >= principal,
+        ErrorCode::InsufficientVaultBalance
+    );
+
+    // 2. Settle rewards and clear the position (Effects)
+    let new_minted_shares = vault.minted_shares
+        .checked_sub(amount)
+        .ok_or(ErrorCode::Underflow)?;
+
+    shareholder.checkpoint_rewards(vault.acc_reward_per_share)?;
+    vault.track_holder(amount, 0)?;
+    shareholder.snapshot_voting_power(vault.active_shareholder_proposal);
+    shareholder.rebase_quantity(0, vault.acc_reward_per_share)?;
+    vault.minted_shares = new_minted_shares;
+    if vault.nav_pricing_enabled {
+        vault.net_asset_value = vault
+            .net_asset_value
+            .checked_sub(principal)
+            .ok_or(ErrorCode::Underflow)?;
+    }
+
+    // 3. Burn shares, return principal (Interactions)
+    let vault_key = vault.key();
+    let seeds = &[b"vault_signer".as_ref(), vault_key.as_ref(), &[vault.signer_bump]];
+    let signer = &[&seeds[..]];
+
+    let burn_authority = if holder_signed {
+        ctx.accounts.cranker.to_account_info()
+    } else {
+        ctx.accounts.vault_signer.to_account_info()
+    };
+    burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.share_token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.vault_share_mint.to_account_info(),
+                from: ctx.accounts.holder_share_account.to_account_info(),
+                authority: burn_authority,
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.principal_vault.to_account_info(),
+                mint: ctx.accounts.payment_mint.to_account_info(),
+                to: ctx.accounts.holder_payment_account.to_account_info(),
+                authority: ctx.accounts.vault_signer.to_account_info(),
+            },
+            signer,
+        ),
+        principal,
+        ctx.accounts.payment_mint.decimals,
+    )?;
+
+    emit!(SharesRefunded {
+        vault: vault_key,
+        holder: ctx.accounts.holder.key(),
+        cranker: ctx.accounts.cranker.key(),
+        amount,
+        principal,
+        minted_shares: new_minted_shares,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
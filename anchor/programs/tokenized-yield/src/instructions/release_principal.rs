@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use crate::{OperatorDebt, Vault, VaultStatus, error::ErrorCode, events::PrincipalReleased};
+
+#[derive(Accounts)]
+pub struct ReleasePrincipal<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// CHECK: PDA Signer
+    #[account(
+        seeds = [b"vault_signer", vault.key().as_ref()],
+        bump = vault.signer_bump
+    )]
+    pub vault_signer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = principal_vault.key() == vault.principal_vault @ ErrorCode::InvalidPaymentVault,
+        constraint = principal_vault.owner == vault_signer.key() @ ErrorCode::InvalidPaymentVault
+    )]
+    pub principal_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Registered through a timelocked `UpdatePrincipalOperator` proposal
+    #[account(
+        mut,
+        constraint = operator.key() == vault.principal_operator @ ErrorCode::InvalidPrincipalOperator,
+        constraint = operator.mint == vault.payment_mint @ ErrorCode::InvalidPrincipalOperator
+    )]
+    pub operator: InterfaceAccount<'info, TokenAccount>,
+
+    /// Every drawdown is recorded against the operator's debt
+    #[account(
+        mut,
+        seeds = [b"operator_debt", vault.key().as_ref()],
+        bump = operator_debt.bump
+    )]
+    pub operator_debt: Account<'info, OperatorDebt>,
+
+    #[account(
+        constraint = payment_mint.key() == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        mint::token_program = token_program
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Sends principal to the operator to buy and run the underlying asset.
+/// Only possible once the raise is settled, so soft-cap refunds are never short.
+pub fn process_release_principal(ctx: Context<ReleasePrincipal>, amount: u64) -> Result<()> {
+    apply_release_principal(
+        &mut ctx.accounts.vault,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.principal_vault,
+        &ctx.accounts.operator,
+        &mut ctx.accounts.operator_debt,
+        &ctx.accounts.payment_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault_signer,
+        amount,
+    )
+}
+
+/// Shared with multisig execution; accounts must already be validated
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn apply_release_principal<'info>(
+    vault: &mut Account<'info, Vault>,
+    authority: Pubkey,
+    principal_vault: &InterfaceAccount<'info, TokenAccount>,
+    operator: &InterfaceAccount<'info, TokenAccount>,
+    debt: &mut OperatorDebt,
+    payment_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    vault_signer: &UncheckedAccount<'info>,
+    amount: u64,
+) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+    require!(vault.status == VaultStatus::Active, ErrorCode::InvalidVaultStatus);
+    require!(!debt.defaulted, ErrorCode::OperatorInDefault);
+    require!(amount > 0, ErrorCode::InvalidPaymentAmount);
+    require!(
+        principal_vault.amount >= amount,
+        ErrorCode::InsufficientVaultBalance
+    );
+
+    // Book the deployment BEFORE transfer (CEI pattern)
+    let now = Clock::get()?.unix_timestamp;
+    debt.accrue_interest(vault.principal_deployed, now)?;
+    debt.total_drawn = debt.total_drawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+    vault.principal_deployed = vault.principal_deployed
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+
+    let vault_key = vault.key();
+    let seeds = &[b"vault_signer".as_ref(), vault_key.as_ref(), &[vault.signer_bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: principal_vault.to_account_info(),
+        mint: payment_mint.to_account_info(),
+        to: operator.to_account_info(),
+        authority: vault_signer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+    transfer_checked(cpi_ctx, amount, payment_mint.decimals)?;
+
+    emit!(PrincipalReleased {
+        vault: vault_key,
+        authority,
+        operator: operator.key(),
+        amount,
+        principal_deployed: vault.principal_deployed,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::{InvestorAllowlist, Vault, error::ErrorCode, events::InvestorAllowlistUpdated};
+
+#[derive(Accounts)]
+pub struct RemoveInvestorAllowlist<'info> {
+    pub vault: Account<'info, Vault>,
+
+    /// Compliance authority or vault authority
+    #[account(
+        mut,
+        constraint = vault.is_compliance_officer(&officer.key()) @ ErrorCode::Unauthorized
+    )]
+    pub officer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = officer,
+        seeds = [b"investor_allowlist", vault.key().as_ref(), investor_allowlist.investor.as_ref()],
+        bump = investor_allowlist.bump
+    )]
+    pub investor_allowlist: Account<'info, InvestorAllowlist>,
+}
+
+/// Removes an investor's verification. Shares already held are unaffected;
+/// only further minting is blocked.
+pub fn process_remove_investor_allowlist(ctx: Context<RemoveInvestorAllowlist>) -> Result<()> {
+    let entry = &ctx.accounts.investor_allowlist;
+
+    emit!(InvestorAllowlistUpdated {
+        vault: ctx.accounts.vault.key(),
+        officer: ctx.accounts.officer.key(),
+        investor: entry.investor,
+        allowed: false,
+        expires_at: entry.expires_at,
+        jurisdiction: entry.jurisdiction,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
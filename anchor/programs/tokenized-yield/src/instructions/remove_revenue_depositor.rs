@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::{RevenueDepositor, Vault, error::ErrorCode, events::RevenueDepositorUpdated};
+
+#[derive(Accounts)]
+pub struct RemoveRevenueDepositor<'info> {
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"revenue_depositor", vault.key().as_ref(), depositor_approval.depositor.as_ref()],
+        bump = depositor_approval.bump
+    )]
+    pub depositor_approval: Account<'info, RevenueDepositor>,
+}
+
+/// Removes a wallet from the depositor registry, refunding the entry's rent to the authority
+pub fn process_remove_revenue_depositor(ctx: Context<RemoveRevenueDepositor>) -> Result<()> {
+    apply_remove_revenue_depositor(
+        &ctx.accounts.vault,
+        ctx.accounts.authority.key(),
+        ctx.accounts.depositor_approval.depositor,
+    )
+}
+
+/// Shared with multisig execution; the caller closes the registry entry
+pub(crate) fn apply_remove_revenue_depositor(vault: &Account<Vault>, authority: Pubkey, depositor: Pubkey) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    emit!(RevenueDepositorUpdated {
+        vault: vault.key(),
+        authority,
+        depositor,
+        approved: false,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
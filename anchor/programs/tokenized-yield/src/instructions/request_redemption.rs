@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Burn, Mint, TokenAccount, TokenInterface, burn};
+use crate::{
+    RedemptionRequest, RedemptionStatus, UserStake, Vault, VaultStatus, error::ErrorCode,
+    events::RedemptionRequested,
+};
+use crate::states::vault::PAUSE_REDEEM;
+
+#[derive(Accounts)]
+pub struct RequestRedemption<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"shareholder", vault.key().as_ref(), holder.key().as_ref()],
+        bump = shareholder.bump,
+        constraint = shareholder.vault == vault.key() @ ErrorCode::InvalidShareholder
+    )]
+    pub shareholder: Account<'info, UserStake>,
+
+    #[account(
+        mut,
+        constraint = holder_share_account.mint == vault.vault_share_mint @ ErrorCode::InvalidShareMint,
+        constraint = holder_share_account.owner == holder.key() @ ErrorCode::InvalidTokenAccountOwner
+    )]
+    pub holder_share_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_share_mint", vault.key().as_ref()],
+        bump,
+        mint::token_program = share_token_program
+    )]
+    pub vault_share_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = holder,
+        seeds = [b"redemption_request", vault.key().as_ref(), vault.redemption_request_count.to_le_bytes().as_ref()],
+        bump,
+        space = 8 + RedemptionRequest::INIT_SPACE
+    )]
+    pub request: Account<'info, RedemptionRequest>,
+
+    #[account(
+        constraint = share_token_program.key() == vault.share_token_program @ ErrorCode::InvalidShareTokenProgram
+    )]
+    pub share_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Queues `amount` shares behind every earlier request. The share tokens are
+/// burned now so they cannot move, but the shares stay in `quantity` and
+/// `minted_shares` and keep earning until `process_redemptions` fills them.
+pub fn process_request_redemption(ctx: Context<RequestRedemption>, amount: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let shareholder = &mut ctx.accounts.shareholder;
+    let request = &mut ctx.accounts.request;
+
+    // The queue serves deployed capital; raises that may still refund use redeem_shares
+    require!(
+        matches!(vault.status, VaultStatus::Active | VaultStatus::WindingDown),
+        ErrorCode::InvalidVaultStatus
+    );
+    require!(!vault.is_paused(PAUSE_REDEEM), ErrorCode::InstructionPaused);
+    require!(!shareholder.frozen, ErrorCode::ShareholderFrozen);
+    require!(amount > 0, ErrorCode::InvalidShareAmount);
+    require!(shareholder.unlocked_quantity() >= amount, ErrorCode::InsufficientShares);
+
+    let now = Clock::get()?.unix_timestamp;
+    let unlock_at = now
+        .checked_add(vault.redemption_notice_period)
+        .ok_or(ErrorCode::Overflow)?;
+
+    // Lock the shares in the ledger (Effects)
+    shareholder.queued_quantity = shareholder.queued_quantity
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+    vault.queued_shares = vault.queued_shares
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+
+    let request_id = vault.redemption_request_count;
+    request.vault = vault.key();
+    request.request_id = request_id;
+    request.holder = ctx.accounts.holder.key();
+    request.amount = amount;
+    request.status = RedemptionStatus::Queued;
+    request.requested_at = now;
+    request.unlock_at = unlock_at;
+    request.principal_paid = 0;
+    request.bump = ctx.bumps.request;
+
+    vault.redemption_request_count = request_id
+        .checked_add(1)
+        .ok_or(ErrorCode::Overflow)?;
+
+    // Burn the share tokens (Interactions)
+    burn(
+        CpiContext::new(
+            ctx.accounts.share_token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.vault_share_mint.to_account_info(),
+                from: ctx.accounts.holder_share_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    emit!(RedemptionRequested {
+        vault: vault.key(),
+        request_id,
+        holder: ctx.accounts.holder.key(),
+        amount,
+        unlock_at,
+        queued_shares: vault.queued_shares,
+        timestamp: now,
+    });
+
+    Ok(())
+}
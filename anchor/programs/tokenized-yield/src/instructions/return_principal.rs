@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use crate::{OperatorDebt, Vault, error::ErrorCode, events::PrincipalReturned, transfer_fee::gross_amount_for_net};
+
+#[derive(Accounts)]
+pub struct ReturnPrincipal<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// Usually the operator, but anyone may pay principal back
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = payer_ata.mint == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        constraint = payer_ata.owner == payer.key() @ ErrorCode::InvalidTokenAccountOwner
+    )]
+    pub payer_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = principal_vault.key() == vault.principal_vault @ ErrorCode::InvalidPaymentVault
+    )]
+    pub principal_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = payment_mint.key() == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        mint::token_program = token_program
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"operator_debt", vault.key().as_ref()],
+        bump = operator_debt.bump
+    )]
+    pub operator_debt: Account<'info, OperatorDebt>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Credits `amount` of deployed principal back to the principal vault.
+/// Operator profits are revenue and go through `deposit_revenue` instead.
+pub fn process_return_principal(ctx: Context<ReturnPrincipal>, amount: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let debt = &mut ctx.accounts.operator_debt;
+
+    require!(amount > 0, ErrorCode::InvalidPaymentAmount);
+    require!(amount <= vault.principal_deployed, ErrorCode::ReturnExceedsDeployed);
+
+    let now = Clock::get()?.unix_timestamp;
+    debt.accrue_interest(vault.principal_deployed, now)?;
+    debt.principal_repaid = debt.principal_repaid
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+    vault.principal_deployed = vault.principal_deployed
+        .checked_sub(amount)
+        .ok_or(ErrorCode::Underflow)?;
+
+    // The payer covers any Token-2022 transfer fee, so the principal vault is
+    // credited exactly `amount`.
+    let gross_amount = gross_amount_for_net(&ctx.accounts.payment_mint.to_account_info(), amount)?;
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.payer_ata.to_account_info(),
+        mint: ctx.accounts.payment_mint.to_account_info(),
+        to: ctx.accounts.principal_vault.to_account_info(),
+        authority: ctx.accounts.payer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    transfer_checked(cpi_ctx, gross_amount, ctx.accounts.payment_mint.decimals)?;
+
+    emit!(PrincipalReturned {
+        vault: vault.key(),
+        payer: ctx.accounts.payer.key(),
+        amount,
+        principal_deployed: vault.principal_deployed,
+        timestamp: now,
+    });
+
+    Ok(())
+}
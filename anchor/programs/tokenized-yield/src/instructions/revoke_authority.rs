@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{Vault, error::ErrorCode};
+use crate::{Vault, error::ErrorCode, events::AuthorityRevoked};
 
 #[derive(Accounts)]
 pub struct RevokeAuthority<'info> {
@@ -16,8 +16,11 @@ pub struct RevokeAuthority<'info> {
 /// After calling this, all governance operations will fail permanently.
 /// The protocol parameters become immutable.
 pub fn process_revoke_authority(ctx: Context<RevokeAuthority>) -> Result<()> {
-    let vault = &mut ctx.accounts.vault;
+    apply_revoke_authority(&mut ctx.accounts.vault)
+}
 
+/// Shared with multisig execution
+pub(crate) fn apply_revoke_authority(vault: &mut Account<Vault>) -> Result<()> {
     // Check if governance is already disabled
     require!(
         !vault.is_governance_disabled(),
@@ -26,7 +29,15 @@ pub fn process_revoke_authority(ctx: Context<RevokeAuthority>) -> Result<()> {
 
     // Set authority to default (zero) - IRREVERSIBLE
     // This permanently disables all governance operations
+    let old_authority = vault.authority;
     vault.authority = Pubkey::default();
+    vault.pending_authority = Pubkey::default();
+
+    emit!(AuthorityRevoked {
+        vault: vault.key(),
+        old_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
     Ok(())
 }
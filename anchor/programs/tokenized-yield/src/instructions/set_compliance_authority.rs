@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::{Vault, error::ErrorCode, events::ComplianceAuthorityUpdated};
+
+#[derive(Accounts)]
+pub struct SetComplianceAuthority<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Sets the compliance role that manages investor records. Pubkey::default() removes it.
+pub fn process_set_compliance_authority(ctx: Context<SetComplianceAuthority>, new_compliance_authority: Pubkey) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    apply_compliance_authority(&mut ctx.accounts.vault, authority, new_compliance_authority)
+}
+
+/// Shared with multisig execution
+pub(crate) fn apply_compliance_authority(vault: &mut Account<Vault>, authority: Pubkey, new_compliance_authority: Pubkey) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    let old_compliance_authority = vault.compliance_authority;
+    vault.compliance_authority = new_compliance_authority;
+
+    emit!(ComplianceAuthorityUpdated {
+        vault: vault.key(),
+        authority,
+        old_compliance_authority,
+        new_compliance_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
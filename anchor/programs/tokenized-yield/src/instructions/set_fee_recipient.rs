@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::{Vault, error::ErrorCode, events::FeeRecipientUpdated};
+
+#[derive(Accounts)]
+pub struct SetFeeRecipient<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Token account that will receive permissionless fee sweeps
+    #[account(
+        constraint = fee_recipient.mint == vault.payment_mint @ ErrorCode::InvalidFeeRecipient
+    )]
+    pub fee_recipient: InterfaceAccount<'info, TokenAccount>,
+}
+
+pub fn process_set_fee_recipient(ctx: Context<SetFeeRecipient>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let fee_recipient = ctx.accounts.fee_recipient.key();
+    apply_fee_recipient(&mut ctx.accounts.vault, authority, fee_recipient)
+}
+
+/// Callers validate that `fee_recipient` is a payment-mint token account.
+/// Shared with multisig execution.
+pub(crate) fn apply_fee_recipient(vault: &mut Account<Vault>, authority: Pubkey, fee_recipient: Pubkey) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    let old_fee_recipient = vault.fee_recipient;
+    vault.fee_recipient = fee_recipient;
+
+    emit!(FeeRecipientUpdated {
+        vault: vault.key(),
+        authority,
+        old_fee_recipient,
+        new_fee_recipient: vault.fee_recipient,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
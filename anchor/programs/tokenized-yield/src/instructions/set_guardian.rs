@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::{Vault, error::ErrorCode, events::GuardianUpdated};
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Sets the guardian that can pause instructions. Pubkey::default() removes it.
+pub fn process_set_guardian(ctx: Context<SetGuardian>, new_guardian: Pubkey) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    apply_guardian(&mut ctx.accounts.vault, authority, new_guardian)
+}
+
+/// Shared with multisig execution
+pub(crate) fn apply_guardian(vault: &mut Account<Vault>, authority: Pubkey, new_guardian: Pubkey) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    let old_guardian = vault.guardian;
+    vault.guardian = new_guardian;
+
+    emit!(GuardianUpdated {
+        vault: vault.key(),
+        authority,
+        old_guardian,
+        new_guardian,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
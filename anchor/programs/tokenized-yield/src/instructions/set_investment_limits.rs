@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::{Vault, error::ErrorCode, events::InvestmentLimitsUpdated};
+
+#[derive(Accounts)]
+pub struct SetInvestmentLimits<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Limits apply to future quantity increases only; existing positions and
+/// holders above a lowered cap are left in place.
+pub fn process_set_investment_limits(
+    ctx: Context<SetInvestmentLimits>,
+    min_mint_amount: u64,
+    max_shares_per_holder: u64,
+    max_holder_count: u32,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    apply_investment_limits(
+        &mut ctx.accounts.vault,
+        authority,
+        min_mint_amount,
+        max_shares_per_holder,
+        max_holder_count,
+    )
+}
+
+/// Shared with multisig execution
+pub(crate) fn apply_investment_limits(
+    vault: &mut Account<Vault>,
+    authority: Pubkey,
+    min_mint_amount: u64,
+    max_shares_per_holder: u64,
+    max_holder_count: u32,
+) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+    // A minimum above the cap would make minting impossible
+    require!(
+        max_shares_per_holder == 0 || min_mint_amount <= max_shares_per_holder,
+        ErrorCode::InvalidInvestmentLimits
+    );
+
+    vault.min_mint_amount = min_mint_amount;
+    vault.max_shares_per_holder = max_shares_per_holder;
+    vault.max_holder_count = max_holder_count;
+
+    emit!(InvestmentLimitsUpdated {
+        vault: vault.key(),
+        authority,
+        min_mint_amount,
+        max_shares_per_holder,
+        max_holder_count,
+        holder_count: vault.holder_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::{InvestorAllowlist, Vault, error::ErrorCode, events::InvestorAllowlistUpdated};
+
+#[derive(Accounts)]
+#[instruction(investor: Pubkey)]
+pub struct SetInvestorAllowlist<'info> {
+    pub vault: Account<'info, Vault>,
+
+    /// Compliance authority or vault authority
+    #[account(
+        mut,
+        constraint = vault.is_compliance_officer(&officer.key()) @ ErrorCode::Unauthorized
+    )]
+    pub officer: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = officer,
+        seeds = [b"investor_allowlist", vault.key().as_ref(), investor.as_ref()],
+        bump,
+        space = 8 + InvestorAllowlist::INIT_SPACE
+    )]
+    pub investor_allowlist: Account<'info, InvestorAllowlist>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Adds a verified investor, or renews / updates an existing entry.
+/// `expires_at = 0` never expires.
+pub fn process_set_investor_allowlist(
+    ctx: Context<SetInvestorAllowlist>,
+    investor: Pubkey,
+    expires_at: i64,
+    jurisdiction: [u8; 2],
+) -> Result<()> {
+    let entry = &mut ctx.accounts.investor_allowlist;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(expires_at == 0 || expires_at > now, ErrorCode::InvalidAllowlistExpiry);
+
+    entry.vault = ctx.accounts.vault.key();
+    entry.investor = investor;
+    entry.expires_at = expires_at;
+    entry.jurisdiction = jurisdiction;
+    entry.approved_by = ctx.accounts.officer.key();
+    entry.updated_at = now;
+    entry.bump = ctx.bumps.investor_allowlist;
+
+    emit!(InvestorAllowlistUpdated {
+        vault: entry.vault,
+        officer: entry.approved_by,
+        investor,
+        allowed: true,
+        expires_at,
+        jurisdiction,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::{Vault, error::ErrorCode, events::MintAllowlistRequiredUpdated};
+
+#[derive(Accounts)]
+pub struct SetMintAllowlistRequired<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// `true` limits `mint_shares` to allowlisted investors; `false` makes the vault public
+pub fn process_set_mint_allowlist_required(ctx: Context<SetMintAllowlistRequired>, required: bool) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    apply_mint_allowlist_required(&mut ctx.accounts.vault, authority, required)
+}
+
+/// Shared with multisig execution
+pub(crate) fn apply_mint_allowlist_required(vault: &mut Account<Vault>, authority: Pubkey, required: bool) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    vault.mint_allowlist_required = required;
+
+    emit!(MintAllowlistRequiredUpdated {
+        vault: vault.key(),
+        authority,
+        required,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
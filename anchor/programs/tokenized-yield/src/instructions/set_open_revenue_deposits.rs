@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::{Vault, error::ErrorCode, events::OpenRevenueDepositsUpdated};
+
+#[derive(Accounts)]
+pub struct SetOpenRevenueDeposits<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// `true` lets anyone deposit revenue (donations); `false` limits it to the depositor registry
+pub fn process_set_open_revenue_deposits(ctx: Context<SetOpenRevenueDeposits>, open: bool) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    apply_open_revenue_deposits(&mut ctx.accounts.vault, authority, open)
+}
+
+/// Shared with multisig execution
+pub(crate) fn apply_open_revenue_deposits(vault: &mut Account<Vault>, authority: Pubkey, open: bool) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    vault.open_revenue_deposits = open;
+
+    emit!(OpenRevenueDepositsUpdated {
+        vault: vault.key(),
+        authority,
+        open,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::{Vault, VaultStatus, error::ErrorCode, events::VaultStatusChanged};
+
+#[derive(Accounts)]
+pub struct SetVaultStatus<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn process_set_vault_status(ctx: Context<SetVaultStatus>, new_status: VaultStatus) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    apply_vault_status(&mut ctx.accounts.vault, authority, new_status)
+}
+
+/// Validates and applies a lifecycle transition; shared with multisig execution
+pub(crate) fn apply_vault_status(vault: &mut Account<Vault>, authority: Pubkey, new_status: VaultStatus) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    let old_status = vault.status;
+
+    // A paused vault may only resume into the status it was paused from
+    let allowed = if old_status == VaultStatus::Paused {
+        new_status == vault.status_before_pause
+    } else {
+        old_status.can_transition_to(new_status)
+    };
+    require!(allowed, ErrorCode::InvalidStatusTransition);
+
+    // A raise below its soft cap can only end in refunds
+    if old_status == VaultStatus::Fundraising && new_status == VaultStatus::Active {
+        require!(vault.minted_shares >= vault.soft_cap, ErrorCode::SoftCapNotReached);
+    }
+
+    // Closing is only possible once every share has been redeemed
+    if new_status == VaultStatus::Closed {
+        require!(vault.minted_shares == 0, ErrorCode::SharesOutstanding);
+    }
+
+    if new_status == VaultStatus::Paused {
+        vault.status_before_pause = old_status;
+    }
+    let now = Clock::get()?.unix_timestamp;
+    vault.status = new_status;
+    vault.sync_redemption_halt(now);
+
+    emit!(VaultStatusChanged {
+        vault: vault.key(),
+        authority,
+        old_status,
+        new_status,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use crate::{Vault, error::ErrorCode, events::FeesWithdrawn};
+
+#[derive(Accounts)]
+pub struct SweepFees<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// Anyone may crank a sweep; funds can only reach the registered recipient
+    pub cranker: Signer<'info>,
+
+    /// CHECK: PDA Signer
+    #[account(
+        seeds = [b"vault_signer", vault.key().as_ref()],
+        bump = vault.signer_bump
+    )]
+    pub vault_signer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = treasury.key() == vault.treasury @ ErrorCode::InvalidTreasury,
+        constraint = treasury.owner == vault_signer.key() @ ErrorCode::InvalidTreasury
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = fee_recipient.key() == vault.fee_recipient @ ErrorCode::InvalidFeeRecipient,
+        constraint = fee_recipient.mint == vault.payment_mint @ ErrorCode::InvalidFeeRecipient
+    )]
+    pub fee_recipient: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = payment_mint.key() == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        mint::token_program = token_program
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Permissionless sweep of all withdrawable fees to the pre-registered fee recipient.
+/// Keeps working after `revoke_authority`, so fees are never stranded.
+pub fn process_sweep_fees(ctx: Context<SweepFees>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    require!(
+        vault.fee_recipient != Pubkey::default(),
+        ErrorCode::FeeRecipientNotSet
+    );
+
+    // Never sweep more than the treasury actually holds
+    let withdrawable = vault.withdrawable_fees().ok_or(ErrorCode::Underflow)?;
+    let amount = withdrawable.min(ctx.accounts.treasury.amount);
+    require!(amount > 0, ErrorCode::InsufficientFees);
+
+    // Update counter BEFORE transfer (CEI pattern)
+    vault.total_fees_withdrawn = vault.total_fees_withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+
+    let vault_key = vault.key();
+    let seeds = &[b"vault_signer".as_ref(), vault_key.as_ref(), &[vault.signer_bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.treasury.to_account_info(),
+        mint: ctx.accounts.payment_mint.to_account_info(),
+        to: ctx.accounts.fee_recipient.to_account_info(),
+        authority: ctx.accounts.vault_signer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer);
+    transfer_checked(cpi_ctx, amount, ctx.accounts.payment_mint.decimals)?;
+
+    emit!(FeesWithdrawn {
+        vault: vault_key,
+        caller: ctx.accounts.cranker.key(),
+        recipient: ctx.accounts.fee_recipient.key(),
+        amount,
+        total_fees_withdrawn: vault.total_fees_withdrawn,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
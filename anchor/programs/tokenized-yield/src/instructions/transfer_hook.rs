@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        extension::{transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions},
+        state::Account as SplTokenAccount,
+    },
+    token_interface::{Mint, TokenAccount},
+};
+use spl_discriminator::SplDiscriminate;
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::{UserStake, Vault, error::ErrorCode, events::SharesTransferred};
+use crate::states::vault::PAUSE_TRANSFER;
+
+/// Discriminator Token-2022 uses when invoking the hook
+pub const EXECUTE_DISCRIMINATOR: &[u8] = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE;
+
+/// Account indices in the `Execute` instruction, extra accounts start at 5
+const SOURCE_TOKEN_INDEX: u8 = 0;
+const DESTINATION_TOKEN_INDEX: u8 = 2;
+const VAULT_INDEX: u8 = 5;
+/// Offset and length of `owner` inside an SPL token account
+const TOKEN_ACCOUNT_OWNER_OFFSET: u8 = 32;
+const PUBKEY_LEN: u8 = 32;
+
+/// Extra accounts Token-2022 must pass to the hook: the vault (writable, for
+/// `holder_count`) and the `UserStake` PDAs of the source and destination
+/// token account owners.
+pub fn extra_account_metas(vault: &Pubkey) -> Result<Vec<ExtraAccountMeta>> {
+    let stake_of = |token_account_index: u8| {
+        ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal { bytes: b"shareholder".to_vec() },
+                Seed::AccountKey { index: VAULT_INDEX },
+                Seed::AccountData {
+                    account_index: token_account_index,
+                    data_index: TOKEN_ACCOUNT_OWNER_OFFSET,
+                    length: PUBKEY_LEN,
+                },
+            ],
+            false,
+            true,
+        )
+    };
+
+    Ok(vec![
+        ExtraAccountMeta::new_with_pubkey(vault, false, true)?,
+        stake_of(SOURCE_TOKEN_INDEX)?,
+        stake_of(DESTINATION_TOKEN_INDEX)?,
+    ])
+}
+
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Source owner or delegate, already validated by Token-2022
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: ExtraAccountMetaList PDA
+    #[account(
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = vault.vault_share_mint == mint.key() @ ErrorCode::InvalidShareMint
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [b"shareholder", vault.key().as_ref(), source_token.owner.as_ref()],
+        bump = source_stake.bump
+    )]
+    pub source_stake: Account<'info, UserStake>,
+
+    #[account(
+        mut,
+        seeds = [b"shareholder", vault.key().as_ref(), destination_token.owner.as_ref()],
+        bump = destination_stake.bump
+    )]
+    pub destination_stake: Account<'info, UserStake>,
+}
+
+/// Invoked by Token-2022 on every share transfer. Settles both stakes at the
+/// current accumulator and moves `quantity` with the tokens.
+pub fn process_transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+    assert_is_transferring(&ctx.accounts.source_token.to_account_info())?;
+
+    // Moving shares between two accounts of the same holder changes nothing
+    if ctx.accounts.source_token.owner == ctx.accounts.destination_token.owner {
+        return Ok(());
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    let source_stake = &mut ctx.accounts.source_stake;
+    let destination_stake = &mut ctx.accounts.destination_stake;
+
+    require!(vault.status.allows_exits(), ErrorCode::InvalidVaultStatus);
+    require!(!vault.is_paused(PAUSE_TRANSFER), ErrorCode::InstructionPaused);
+    require!(!source_stake.frozen && !destination_stake.frozen, ErrorCode::ShareholderFrozen);
+    require!(source_stake.unlocked_quantity() >= amount, ErrorCode::InsufficientShares);
+
+    source_stake.checkpoint_rewards(vault.acc_reward_per_share)?;
+    destination_stake.checkpoint_rewards(vault.acc_reward_per_share)?;
+
+    let new_source_quantity = source_stake.quantity
+        .checked_sub(amount)
+        .ok_or(ErrorCode::Underflow)?;
+    let new_destination_quantity = destination_stake.quantity
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+
+    vault.track_holder(source_stake.quantity, new_source_quantity)?;
+    vault.track_holder(destination_stake.quantity, new_destination_quantity)?;
+    source_stake.snapshot_voting_power(vault.active_shareholder_proposal);
+    destination_stake.snapshot_voting_power(vault.active_shareholder_proposal);
+    source_stake.rebase_quantity(new_source_quantity, vault.acc_reward_per_share)?;
+    destination_stake.rebase_quantity(new_destination_quantity, vault.acc_reward_per_share)?;
+
+    emit!(SharesTransferred {
+        vault: vault.key(),
+        from: source_stake.owner,
+        to: destination_stake.owner,
+        amount,
+        from_quantity: new_source_quantity,
+        to_quantity: new_destination_quantity,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Rejects direct calls: Token-2022 sets `transferring` on the source account
+/// only for the duration of a real transfer.
+fn assert_is_transferring(source_token: &AccountInfo) -> Result<()> {
+    let data = source_token.try_borrow_data()?;
+    let account = StateWithExtensions::<SplTokenAccount>::unpack(&data)?;
+    let extension = account.get_extension::<TransferHookAccount>()?;
+
+    require!(bool::from(extension.transferring), ErrorCode::NotTransferring);
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked},
+};
+use crate::{UserStake, Vault, error::ErrorCode, events::SharesTransferred};
+use crate::states::vault::PAUSE_TRANSFER;
+
+#[derive(Accounts)]
+pub struct TransferShares<'info> {
+    #[account(
+        mut,
+        constraint = !vault.transfer_hook_enabled @ ErrorCode::TransferHookManaged
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    /// CHECK: Only used as the recipient's wallet address for PDA/ATA derivation
+    #[account(
+        constraint = recipient.key() != sender.key() @ ErrorCode::InvalidShareholder
+    )]
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = sender_stake.owner == sender.key() @ ErrorCode::InvalidTokenAccountOwner,
+        constraint = sender_stake.vault == vault.key() @ ErrorCode::InvalidShareholder,
+        seeds = [b"shareholder", vault.key().as_ref(), sender.key().as_ref()],
+        bump = sender_stake.bump
+    )]
+    pub sender_stake: Account<'info, UserStake>,
+
+    #[account(
+        init_if_needed,
+        payer = sender,
+        seeds = [b"shareholder", vault.key().as_ref(), recipient.key().as_ref()],
+        bump,
+        space = 8 + UserStake::INIT_SPACE
+    )]
+    pub recipient_stake: Account<'info, UserStake>,
+
+    #[account(
+        seeds = [b"vault_share_mint", vault.key().as_ref()],
+        bump,
+        mint::token_program = share_token_program
+    )]
+    pub vault_share_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = sender_share_ata.mint == vault.vault_share_mint @ ErrorCode::InvalidPaymentMint,
+        constraint = sender_share_ata.owner == sender.key() @ ErrorCode::InvalidTokenAccountOwner
+    )]
+    pub sender_share_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = sender,
+        associated_token::mint = vault_share_mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = share_token_program,
+    )]
+    pub recipient_share_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    #[account(
+        constraint = share_token_program.key() == vault.share_token_program @ ErrorCode::InvalidShareTokenProgram
+    )]
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Moves share tokens together with their `UserStake` entitlement.
+/// Rewards accrued up to this point stay with the side that earned them.
+pub fn process_transfer_shares(ctx: Context<TransferShares>, amount: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let sender_stake = &mut ctx.accounts.sender_stake;
+    let recipient_stake = &mut ctx.accounts.recipient_stake;
+
+    require!(vault.status.allows_exits(), ErrorCode::InvalidVaultStatus);
+    require!(!vault.is_paused(PAUSE_TRANSFER), ErrorCode::InstructionPaused);
+    require!(!sender_stake.frozen && !recipient_stake.frozen, ErrorCode::ShareholderFrozen);
+    require!(amount > 0, ErrorCode::InvalidShareAmount);
+    require!(sender_stake.unlocked_quantity() >= amount, ErrorCode::InsufficientShares);
+
+    if !recipient_stake.is_initialized {
+        recipient_stake.initialize(ctx.accounts.recipient.key(), vault.key(), ctx.bumps.recipient_stake);
+    }
+
+    // 1. Settle rewards for both sides at the current accumulator
+    sender_stake.checkpoint_rewards(vault.acc_reward_per_share)?;
+    recipient_stake.checkpoint_rewards(vault.acc_reward_per_share)?;
+
+    // 2. Move entitlement (Effects)
+    let new_sender_quantity = sender_stake.quantity
+        .checked_sub(amount)
+        .ok_or(ErrorCode::Underflow)?;
+    let new_recipient_quantity = recipient_stake.quantity
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+
+    vault.track_holder(sender_stake.quantity, new_sender_quantity)?;
+    vault.track_holder(recipient_stake.quantity, new_recipient_quantity)?;
+    sender_stake.snapshot_voting_power(vault.active_shareholder_proposal);
+    recipient_stake.snapshot_voting_power(vault.active_shareholder_proposal);
+    sender_stake.rebase_quantity(new_sender_quantity, vault.acc_reward_per_share)?;
+    recipient_stake.rebase_quantity(new_recipient_quantity, vault.acc_reward_per_share)?;
+
+    // 3. Move share tokens (Interactions)
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.sender_share_ata.to_account_info(),
+        mint: ctx.accounts.vault_share_mint.to_account_info(),
+        to: ctx.accounts.recipient_share_ata.to_account_info(),
+        authority: ctx.accounts.sender.to_account_info(),
+    };
+    transfer_checked(
+        CpiContext::new(ctx.accounts.share_token_program.to_account_info(), cpi_accounts),
+        amount,
+        ctx.accounts.vault_share_mint.decimals,
+    )?;
+
+    emit!(SharesTransferred {
+        vault: vault.key(),
+        from: ctx.accounts.sender.key(),
+        to: ctx.accounts.recipient.key(),
+        amount,
+        from_quantity: new_sender_quantity,
+        to_quantity: new_recipient_quantity,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::{Vault, error::ErrorCode, events::PauseUpdated};
+use crate::states::vault::PAUSE_ALL;
+
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Resumes the instructions in `flags` (authority only).
+pub fn process_unpause(ctx: Context<Unpause>, flags: u8) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    apply_unpause(&mut ctx.accounts.vault, authority, flags)
+}
+
+/// Shared with multisig execution
+pub(crate) fn apply_unpause(vault: &mut Account<Vault>, authority: Pubkey, flags: u8) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+
+    require!(flags != 0 && flags & !PAUSE_ALL == 0, ErrorCode::InvalidPauseFlags);
+
+    let now = Clock::get()?.unix_timestamp;
+    vault.paused_instructions &= !flags;
+    vault.sync_redemption_halt(now);
+
+    emit!(PauseUpdated {
+        vault: vault.key(),
+        caller: authority,
+        flags,
+        paused: false,
+        paused_instructions: vault.paused_instructions,
+        timestamp: now,
+    });
+
+    Ok(())
+}
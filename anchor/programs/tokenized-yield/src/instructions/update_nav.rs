@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::{NavHistory, NavRecord, Vault, error::ErrorCode, events::NavUpdated};
+use crate::states::vault::FEE_BPS_DENOMINATOR;
+
+#[derive(Accounts)]
+pub struct UpdateNav<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// Vault authority or the registered NAV oracle
+    #[account(
+        constraint = vault.is_nav_updater(&updater.key()) @ ErrorCode::Unauthorized
+    )]
+    pub updater: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"nav_history", vault.key().as_ref()],
+        bump = nav_history.bump
+    )]
+    pub nav_history: Account<'info, NavHistory>,
+}
+
+pub fn process_update_nav(ctx: Context<UpdateNav>, new_nav: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    require!(vault.nav_pricing_enabled, ErrorCode::NavPricingNotEnabled);
+    require!(vault.minted_shares > 0, ErrorCode::NoSharesMinted);
+    require!(new_nav > 0, ErrorCode::InvalidNav);
+
+    // Bound the move relative to the previous valuation:
+    // |new - old| * 10_000 <= old * max_nav_change_bps
+    let old_nav = vault.net_asset_value;
+    let delta = new_nav.abs_diff(old_nav) as u128;
+    let max_delta = (old_nav as u128)
+        .checked_mul(vault.max_nav_change_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(
+        delta
+            .checked_mul(FEE_BPS_DENOMINATOR as u128)
+            .ok_or(ErrorCode::MathOverflow)?
+            <= max_delta,
+        ErrorCode::NavChangeExceedsBound
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    vault.net_asset_value = new_nav;
+    vault.nav_updated_at = now;
+
+    ctx.accounts.nav_history.push(NavRecord {
+        net_asset_value: new_nav,
+        minted_shares: vault.minted_shares,
+        updater: ctx.accounts.updater.key(),
+        timestamp: now,
+    });
+
+    emit!(NavUpdated {
+        vault: vault.key(),
+        updater: ctx.accounts.updater.key(),
+        old_nav,
+        new_nav,
+        minted_shares: vault.minted_shares,
+        timestamp: now,
+    });
+
+    Ok(())
+}
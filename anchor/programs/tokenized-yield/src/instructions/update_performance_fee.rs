@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::{Vault, error::ErrorCode};
+use crate::{Vault, error::ErrorCode, events::FeeUpdated};
 use crate::states::vault::MAX_PERFORMANCE_FEE_BPS;
 
 #[derive(Accounts)]
@@ -17,8 +17,12 @@ pub fn process_update_performance_fee(
     ctx: Context<UpdatePerformanceFee>,
     new_fee_bps: u16,
 ) -> Result<()> {
-    let vault = &mut ctx.accounts.vault;
+    let authority = ctx.accounts.authority.key();
+    apply_performance_fee_decrease(&mut ctx.accounts.vault, authority, new_fee_bps)
+}
 
+/// Lowers the performance fee; shared with multisig execution
+pub(crate) fn apply_performance_fee_decrease(vault: &mut Account<Vault>, authority: Pubkey, new_fee_bps: u16) -> Result<()> {
     // Check if governance is disabled
     require!(
         !vault.is_governance_disabled(),
@@ -31,8 +35,24 @@ pub fn process_update_performance_fee(
         ErrorCode::PerformanceFeeExceedsMax
     );
 
+    // Only decreases apply immediately; increases go through
+    // `propose_governance_change` so holders can exit before they land
+    require!(
+        new_fee_bps <= vault.performance_fee_bps,
+        ErrorCode::FeeIncreaseRequiresTimelock
+    );
+
     // Update performance fee
+    let old_fee_bps = vault.performance_fee_bps;
     vault.performance_fee_bps = new_fee_bps;
 
+    emit!(FeeUpdated {
+        vault: vault.key(),
+        authority,
+        old_fee_bps,
+        new_fee_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked, transfer_checked};
+use crate::{Vault, error::ErrorCode, events::FeesWithdrawn};
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        constraint = authority.key() == vault.authority @ ErrorCode::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// CHECK: PDA Signer
+    #[account(
+        seeds = [b"vault_signer", vault.key().as_ref()],
+        bump = vault.signer_bump
+    )]
+    pub vault_signer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = treasury.key() == vault.treasury @ ErrorCode::InvalidTreasury,
+        constraint = treasury.owner == vault_signer.key() @ ErrorCode::InvalidTreasury
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = destination.mint == vault.payment_mint @ ErrorCode::InvalidPaymentMint
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = payment_mint.key() == vault.payment_mint @ ErrorCode::InvalidPaymentMint,
+        mint::token_program = token_program
+    )]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Authority-gated withdrawal of collected performance fees to any payment-mint account.
+pub fn process_withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+    apply_withdraw_fees(
+        &mut ctx.accounts.vault,
+        ctx.accounts.authority.key(),
+        &ctx.accounts.treasury,
+        &ctx.accounts.destination,
+        &ctx.accounts.payment_mint,
+        &ctx.accounts.token_program,
+        &ctx.accounts.vault_signer,
+        amount,
+    )
+}
+
+/// Shared with multisig execution; token accounts must already be validated
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn apply_withdraw_fees<'info>(
+    vault: &mut Account<'info, Vault>,
+    authority: Pubkey,
+    treasury: &InterfaceAccount<'info, TokenAccount>,
+    destination: &InterfaceAccount<'info, TokenAccount>,
+    payment_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    vault_signer: &UncheckedAccount<'info>,
+    amount: u64,
+) -> Result<()> {
+    // Check if governance is disabled
+    require!(
+        !vault.is_governance_disabled(),
+        ErrorCode::GovernanceDisabled
+    );
+    require!(amount > 0, ErrorCode::InvalidPaymentAmount);
+
+    let withdrawable = vault.withdrawable_fees().ok_or(ErrorCode::Underflow)?;
+    require!(amount <= withdrawable, ErrorCode::InsufficientFees);
+    require!(
+        treasury.amount >= amount,
+        ErrorCode::InsufficientVaultBalance
+    );
+
+    // Update counter BEFORE transfer (CEI pattern)
+    vault.total_fees_withdrawn = vault.total_fees_withdrawn
+        .checked_add(amount)
+        .ok_or(ErrorCode::Overflow)?;
+
+    let vault_key = vault.key();
+    let seeds = &[b"vault_signer".as_ref(), vault_key.as_ref(), &[vault.signer_bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: treasury.to_account_info(),
+        mint: payment_mint.to_account_info(),
+        to: destination.to_account_info(),
+        authority: vault_signer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+    transfer_checked(cpi_ctx, amount, payment_mint.decimals)?;
+
+    emit!(FeesWithdrawn {
+        vault: vault_key,
+        caller: authority,
+        recipient: destination.key(),
+        amount,
+        total_fees_withdrawn: vault.total_fees_withdrawn,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
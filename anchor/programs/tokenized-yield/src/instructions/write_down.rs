@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::{Vault, VaultStatus, error::ErrorCode, events::PrincipalWrittenDown};
+use crate::states::vault::{FEE_BPS_DENOMINATOR, PAUSE_MINT};
+
+#[derive(Accounts)]
+pub struct WriteDown<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    /// Vault authority or the registered NAV oracle
+    #[account(
+        constraint = vault.is_nav_updater(&updater.key()) @ ErrorCode::Unauthorized
+    )]
+    pub updater: Signer<'info>,
+}
+
+pub fn process_write_down(ctx: Context<WriteDown>, impairment_bps: u16) -> Result<()> {
+    let updater = ctx.accounts.updater.key();
+    apply_write_down(&mut ctx.accounts.vault, updater, impairment_bps)
+}
+
+/// Raises the vault's impairment ratio. Every outstanding share loses the same
+/// fraction of its par value, so redemptions split the remaining principal
+/// pro rata instead of paying early exits in full. Minting is halted so new
+/// money is not taken in at a stale valuation.
+///
+/// Shared with multisig execution
+pub(crate) fn apply_write_down(
+    vault: &mut Account<Vault>,
+    updater: Pubkey,
+    impairment_bps: u16,
+) -> Result<()> {
+    require!(
+        matches!(vault.status, VaultStatus::Active | VaultStatus::Paused | VaultStatus::WindingDown),
+        ErrorCode::InvalidVaultStatus
+    );
+    require!(!vault.nav_pricing_enabled, ErrorCode::WriteDownRequiresFixedPricing);
+    require!(vault.minted_shares > 0, ErrorCode::NoSharesMinted);
+    require!(
+        impairment_bps > vault.impairment_bps && impairment_bps as u64 <= FEE_BPS_DENOMINATOR,
+        ErrorCode::InvalidImpairment
+    );
+
+    // Loss at par on the shares outstanding now: minted * price * Δbps / 10_000
+    let old_impairment_bps = vault.impairment_bps;
+    let loss = (vault.minted_shares as u128)
+        .checked_mul(vault.price_per_share as u128)
+        .and_then(|par| par.checked_mul((impairment_bps - old_impairment_bps) as u128))
+        .map(|scaled| scaled / FEE_BPS_DENOMINATOR as u128)
+        .and_then(|loss| u64::try_from(loss).ok())
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    vault.impairment_bps = impairment_bps;
+    vault.principal_written_down = vault
+        .principal_written_down
+        .checked_add(loss)
+        .ok_or(ErrorCode::Overflow)?;
+    // Only the first impairment starts the emergency exit clock, so repeated
+    // write-downs cannot keep pushing the exit back
+    if vault.written_down_at == 0 {
+        vault.written_down_at = now;
+    }
+    vault.paused_instructions |= PAUSE_MINT;
+
+    emit!(PrincipalWrittenDown {
+        vault: vault.key(),
+        updater,
+        old_impairment_bps,
+        new_impairment_bps: impairment_bps,
+        loss,
+        principal_written_down: vault.principal_written_down,
+        minted_shares: vault.minted_shares,
+        timestamp: now,
+    });
+
+    Ok(())
+}
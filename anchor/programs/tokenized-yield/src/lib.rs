@@ -12,18 +12,80 @@ pub mod error;
 
 pub mod constants;
 
+pub mod events;
+
+pub mod transfer_fee;
+
 #[program]
 pub mod tokenized_yield_infrastructure {
     use super::*;
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_vault(
         ctx: Context<InitializeVault>,
+        vault_id: u64,
+        name: String,
+        total_shares: u64,
+        price_per_share: u64,
+        performance_fee_bps: u16,
+        subscription_start: i64,
+        subscription_end: i64,
+        soft_cap: u64,
+    ) -> Result<()> {
+        instructions::process_initialize_vault(
+            ctx,
+            vault_id,
+            name,
+            total_shares,
+            price_per_share,
+            performance_fee_bps,
+            subscription_start,
+            subscription_end,
+            soft_cap,
+        )
+    }
+
+    /// Initialize a vault whose share mint is a Token-2022 mint with a transfer hook
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_vault_with_transfer_hook(
+        ctx: Context<InitializeVaultWithTransferHook>,
+        vault_id: u64,
         name: String,
         total_shares: u64,
         price_per_share: u64,
         performance_fee_bps: u16,
+        subscription_start: i64,
+        subscription_end: i64,
+        soft_cap: u64,
     ) -> Result<()> {
-        instructions::process_initialize_vault(ctx, name, total_shares, price_per_share, performance_fee_bps)
+        instructions::process_initialize_vault_with_transfer_hook(
+            ctx,
+            vault_id,
+            name,
+            total_shares,
+            price_per_share,
+            performance_fee_bps,
+            subscription_start,
+            subscription_end,
+            soft_cap,
+        )
+    }
+
+    /// Register the extra accounts the share transfer hook needs
+    pub fn initialize_extra_account_meta_list(ctx: Context<InitializeExtraAccountMetaList>) -> Result<()> {
+        instructions::process_initialize_extra_account_meta_list(ctx)
     }
+
+    /// Open an empty shareholder stake so a wallet can receive hooked share transfers
+    pub fn initialize_shareholder(ctx: Context<InitializeShareholder>) -> Result<()> {
+        instructions::process_initialize_shareholder(ctx)
+    }
+
+    /// Token-2022 transfer hook: settles rewards for both holders on every share transfer
+    #[instruction(discriminator = EXECUTE_DISCRIMINATOR)]
+    pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+        instructions::process_transfer_hook(ctx, amount)
+    }
+
     pub fn mint_shares(ctx: Context<MintShares>, amount: u64) -> Result<()> {
         instructions::process_mint_shares(ctx, amount)
     }
@@ -40,23 +102,274 @@ pub mod tokenized_yield_infrastructure {
         instructions::process_redeem_shares(ctx, amount)
     }
 
+    /// Transfer share tokens and the matching reward entitlement to another wallet
+    pub fn transfer_shares(ctx: Context<TransferShares>, amount: u64) -> Result<()> {
+        instructions::process_transfer_shares(ctx, amount)
+    }
+
     // =========================================================================
     // GOVERNANCE INSTRUCTIONS
     // =========================================================================
 
-    /// Update performance fee (authority only, max 20%)
+    /// Move the vault to another lifecycle status (authority only, see STATE_MACHINE.md)
+    pub fn set_vault_status(ctx: Context<SetVaultStatus>, new_status: VaultStatus) -> Result<()> {
+        instructions::process_set_vault_status(ctx, new_status)
+    }
+
+    /// Lower the performance fee immediately (authority only); increases are timelocked
     pub fn update_performance_fee(ctx: Context<UpdatePerformanceFee>, new_fee_bps: u16) -> Result<()> {
         instructions::process_update_performance_fee(ctx, new_fee_bps)
     }
 
-    /// Update treasury account (authority only, must maintain capital segregation)
-    pub fn update_treasury(ctx: Context<UpdateTreasury>) -> Result<()> {
-        instructions::process_update_treasury(ctx)
+    /// Queue a fee, treasury or delay change behind the vault's governance delay (authority only)
+    pub fn propose_governance_change(ctx: Context<ProposeGovernanceChange>, action: GovernanceAction) -> Result<()> {
+        instructions::process_propose_governance_change(ctx, action)
+    }
+
+    /// Apply a queued governance change once its eta has passed (permissionless)
+    pub fn execute_governance_change(ctx: Context<ExecuteGovernanceChange>) -> Result<()> {
+        instructions::process_execute_governance_change(ctx)
+    }
+
+    /// Drop a queued governance change (authority only)
+    pub fn cancel_governance_change(ctx: Context<CancelGovernanceChange>) -> Result<()> {
+        instructions::process_cancel_governance_change(ctx)
+    }
+
+    /// Register the token account that permissionless fee sweeps pay into (authority only)
+    pub fn set_fee_recipient(ctx: Context<SetFeeRecipient>) -> Result<()> {
+        instructions::process_set_fee_recipient(ctx)
+    }
+
+    /// Allow anyone to deposit revenue, or only registered depositors (authority only)
+    pub fn set_open_revenue_deposits(ctx: Context<SetOpenRevenueDeposits>, open: bool) -> Result<()> {
+        instructions::process_set_open_revenue_deposits(ctx, open)
+    }
+
+    /// Register a wallet allowed to deposit revenue (authority only)
+    pub fn add_revenue_depositor(ctx: Context<AddRevenueDepositor>, depositor: Pubkey) -> Result<()> {
+        instructions::process_add_revenue_depositor(ctx, depositor)
+    }
+
+    /// Remove a registered revenue depositor (authority only)
+    pub fn remove_revenue_depositor(ctx: Context<RemoveRevenueDepositor>) -> Result<()> {
+        instructions::process_remove_revenue_depositor(ctx)
+    }
+
+    /// Withdraw collected performance fees from the treasury (authority only)
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, amount: u64) -> Result<()> {
+        instructions::process_withdraw_fees(ctx, amount)
+    }
+
+    /// Sweep all withdrawable fees to the registered fee recipient (permissionless)
+    pub fn sweep_fees(ctx: Context<SweepFees>) -> Result<()> {
+        instructions::process_sweep_fees(ctx)
+    }
+
+    /// Switch the vault to NAV pricing, or change its oracle and per-update bound (authority only)
+    pub fn configure_nav_pricing(ctx: Context<ConfigureNavPricing>, nav_oracle: Pubkey, max_nav_change_bps: u16) -> Result<()> {
+        instructions::process_configure_nav_pricing(ctx, nav_oracle, max_nav_change_bps)
+    }
+
+    /// Post a new net asset value (authority or NAV oracle, bounded per update)
+    pub fn update_nav(ctx: Context<UpdateNav>, new_nav: u64) -> Result<()> {
+        instructions::process_update_nav(ctx, new_nav)
+    }
+
+    /// Set the guardian allowed to pause instructions (authority only)
+    pub fn set_guardian(ctx: Context<SetGuardian>, new_guardian: Pubkey) -> Result<()> {
+        instructions::process_set_guardian(ctx, new_guardian)
+    }
+
+    /// Halt instructions by PAUSE_* bitmask (guardian or authority, survives revocation)
+    pub fn pause(ctx: Context<Pause>, flags: u8) -> Result<()> {
+        instructions::process_pause(ctx, flags)
+    }
+
+    /// Resume instructions by PAUSE_* bitmask (authority only)
+    pub fn unpause(ctx: Context<Unpause>, flags: u8) -> Result<()> {
+        instructions::process_unpause(ctx, flags)
+    }
+
+    /// Set the compliance role that manages investor records (authority only)
+    pub fn set_compliance_authority(ctx: Context<SetComplianceAuthority>, new_compliance_authority: Pubkey) -> Result<()> {
+        instructions::process_set_compliance_authority(ctx, new_compliance_authority)
+    }
+
+    /// Require an investor allowlist entry to mint shares, or open minting to anyone (authority only)
+    pub fn set_mint_allowlist_required(ctx: Context<SetMintAllowlistRequired>, required: bool) -> Result<()> {
+        instructions::process_set_mint_allowlist_required(ctx, required)
+    }
+
+    /// Set the minimum mint, per-holder cap and holder-count cap; 0 disables a limit (authority only)
+    pub fn set_investment_limits(
+        ctx: Context<SetInvestmentLimits>,
+        min_mint_amount: u64,
+        max_shares_per_holder: u64,
+        max_holder_count: u32,
+    ) -> Result<()> {
+        instructions::process_set_investment_limits(ctx, min_mint_amount, max_shares_per_holder, max_holder_count)
+    }
+
+    /// Push back the end of a subscription window that has not yet closed (authority only)
+    pub fn extend_subscription(ctx: Context<ExtendSubscription>, new_subscription_end: i64) -> Result<()> {
+        instructions::process_extend_subscription(ctx, new_subscription_end)
+    }
+
+    /// Settle a closed subscription: Active if the soft cap was met, Refunding otherwise (permissionless)
+    pub fn finalize_subscription(ctx: Context<FinalizeSubscription>) -> Result<()> {
+        instructions::process_finalize_subscription(ctx)
+    }
+
+    /// Burn a holder's shares and return their principal after a failed raise (permissionless)
+    pub fn refund(ctx: Context<Refund>) -> Result<()> {
+        instructions::process_refund(ctx)
+    }
+
+    /// Send principal to the registered operator account to deploy off-chain (authority only)
+    pub fn release_principal(ctx: Context<ReleasePrincipal>, amount: u64) -> Result<()> {
+        instructions::process_release_principal(ctx, amount)
+    }
+
+    /// Bring deployed principal back into the principal vault (permissionless)
+    pub fn return_principal(ctx: Context<ReturnPrincipal>, amount: u64) -> Result<()> {
+        instructions::process_return_principal(ctx, amount)
+    }
+
+    /// Open the operator debt ledger with its interest terms and repayment schedule (authority only)
+    pub fn initialize_operator_debt(
+        ctx: Context<InitializeOperatorDebt>,
+        operator: Pubkey,
+        interest_rate_bps: u16,
+        installment_amount: u64,
+        payment_interval: i64,
+        first_due_at: i64,
+        grace_period: i64,
+    ) -> Result<()> {
+        instructions::process_initialize_operator_debt(
+            ctx,
+            operator,
+            interest_rate_bps,
+            installment_amount,
+            payment_interval,
+            first_due_at,
+            grace_period,
+        )
+    }
+
+    /// Declare an overdue operator in default, halting minting and drawdowns (authority only)
+    pub fn mark_operator_default(ctx: Context<MarkOperatorDefault>) -> Result<()> {
+        instructions::process_mark_operator_default(ctx)
+    }
+
+    /// Raise the impairment ratio so every share absorbs the same fraction of a loss (authority or NAV oracle)
+    pub fn write_down(ctx: Context<WriteDown>, impairment_bps: u16) -> Result<()> {
+        instructions::process_write_down(ctx, impairment_bps)
+    }
+
+    /// Burn the caller's shares for a pro-rata slice of the principal and revenue vaults once the exit delay has run
+    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()> {
+        instructions::process_emergency_withdraw(ctx)
+    }
+
+    /// Lock shares in the redemption queue until the notice period has passed
+    pub fn request_redemption(ctx: Context<RequestRedemption>, amount: u64) -> Result<()> {
+        instructions::process_request_redemption(ctx, amount)
+    }
+
+    /// Fill or skip the request at the head of the redemption queue (permissionless)
+    pub fn process_redemptions(ctx: Context<ProcessRedemptions>) -> Result<()> {
+        instructions::process_process_redemptions(ctx)
+    }
+
+    /// Withdraw a queued redemption and get the locked shares back
+    pub fn cancel_redemption(ctx: Context<CancelRedemption>) -> Result<()> {
+        instructions::process_cancel_redemption(ctx)
+    }
+
+    /// Add or renew a verified investor, with optional expiry and jurisdiction (compliance or authority)
+    pub fn set_investor_allowlist(
+        ctx: Context<SetInvestorAllowlist>,
+        investor: Pubkey,
+        expires_at: i64,
+        jurisdiction: [u8; 2],
+    ) -> Result<()> {
+        instructions::process_set_investor_allowlist(ctx, investor, expires_at, jurisdiction)
+    }
+
+    /// Remove a verified investor (compliance or authority)
+    pub fn remove_investor_allowlist(ctx: Context<RemoveInvestorAllowlist>) -> Result<()> {
+        instructions::process_remove_investor_allowlist(ctx)
+    }
+
+    /// Freeze a holder's share ATA and stake with a compliance reason code (compliance or authority)
+    pub fn freeze_shareholder(ctx: Context<FreezeShareholder>, reason: u16) -> Result<()> {
+        instructions::process_freeze_shareholder(ctx, reason)
+    }
+
+    /// Lift a shareholder freeze (compliance or authority)
+    pub fn unfreeze_shareholder(ctx: Context<UnfreezeShareholder>) -> Result<()> {
+        instructions::process_unfreeze_shareholder(ctx)
+    }
+
+    /// Burn a holder's shares and re-mint them to another wallet for legal recovery (compliance or authority)
+    pub fn force_transfer_shares(
+        ctx: Context<ForceTransferShares>,
+        amount: u64,
+        reason: u16,
+        reference: [u8; 32],
+    ) -> Result<()> {
+        instructions::process_force_transfer_shares(ctx, amount, reason, reference)
+    }
+
+    /// Hand authority to an M-of-N multisig, or reconfigure it (authority only)
+    pub fn create_multisig(ctx: Context<CreateMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        instructions::process_create_multisig(ctx, signers, threshold)
+    }
+
+    /// Propose a governance action for the multisig to approve (multisig member)
+    pub fn create_multisig_transaction(ctx: Context<CreateMultisigTransaction>, action: MultisigAction) -> Result<()> {
+        instructions::process_create_multisig_transaction(ctx, action)
+    }
+
+    /// Add an approval to a multisig transaction (multisig member)
+    pub fn approve_multisig_transaction(ctx: Context<ApproveMultisigTransaction>) -> Result<()> {
+        instructions::process_approve_multisig_transaction(ctx)
+    }
+
+    /// Execute a multisig transaction that reached its threshold (permissionless)
+    pub fn execute_multisig_transaction(ctx: Context<ExecuteMultisigTransaction>) -> Result<()> {
+        instructions::process_execute_multisig_transaction(ctx)
+    }
+
+    /// Open a share-weighted vote on a fee, treasury, authority or wind-down change (1% holders)
+    pub fn create_shareholder_proposal(ctx: Context<CreateShareholderProposal>, action: ShareholderAction) -> Result<()> {
+        instructions::process_create_shareholder_proposal(ctx, action)
+    }
+
+    /// Vote on the open shareholder proposal with the stake held at its creation
+    pub fn cast_shareholder_vote(ctx: Context<CastShareholderVote>, support: bool) -> Result<()> {
+        instructions::process_cast_shareholder_vote(ctx, support)
+    }
+
+    /// Tally a shareholder proposal after voting ends and apply it if it passed (permissionless)
+    pub fn execute_shareholder_proposal(ctx: Context<ExecuteShareholderProposal>) -> Result<()> {
+        instructions::process_execute_shareholder_proposal(ctx)
+    }
+
+    /// Propose a new governance authority; takes effect once it calls `accept_authority`
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::process_propose_authority(ctx, new_authority)
+    }
+
+    /// Accept a pending authority proposal (signed by the proposed key)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::process_accept_authority(ctx)
     }
 
-    /// Transfer governance authority to new address
-    pub fn transfer_authority(ctx: Context<TransferAuthority>, new_authority: Pubkey) -> Result<()> {
-        instructions::process_transfer_authority(ctx, new_authority)
+    /// Withdraw a pending authority proposal (authority only)
+    pub fn cancel_pending_authority(ctx: Context<CancelPendingAuthority>) -> Result<()> {
+        instructions::process_cancel_pending_authority(ctx)
     }
 
     /// Irreversibly revoke governance authority - protocol becomes immutable
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Permanent record of a compliance-forced share movement, one PDA per
+/// `vault.forced_transfer_count`. Never closed.
+#[account]
+#[derive(InitSpace)]
+pub struct ForcedTransferRecord {
+    pub vault: Pubkey,
+    pub record_id: u64,
+    /// Compliance authority or vault authority that executed the transfer
+    pub officer: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    /// Compliance reason code (e.g. court order, key loss)
+    pub reason: u16,
+    /// Hash of or pointer to the off-chain legal document
+    pub reference: [u8; 32],
+    pub timestamp: i64,
+    pub bump: u8,
+}
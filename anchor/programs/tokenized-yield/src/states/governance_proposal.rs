@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+/// Parameter change a governance proposal applies when executed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum GovernanceAction {
+    UpdatePerformanceFee { new_fee_bps: u16 },
+    UpdateTreasury { new_treasury: Pubkey },
+    UpdateGovernanceDelay { new_delay: i64 },
+    /// Token account that `release_principal` pays out to
+    UpdatePrincipalOperator { new_operator: Pubkey },
+    /// Wait before `emergency_withdraw` opens once triggered
+    UpdateEmergencyExitDelay { new_delay: i64 },
+    /// Wait between `request_redemption` and the earliest fill
+    UpdateRedemptionNoticePeriod { new_notice_period: i64 },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum ProposalStatus {
+    Queued,
+    Executed,
+    Cancelled,
+}
+
+/// A timelocked governance change, one PDA per `vault.proposal_count`
+#[account]
+#[derive(InitSpace)]
+pub struct GovernanceProposal {
+    pub vault: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: GovernanceAction,
+    pub status: ProposalStatus,
+    pub created_at: i64,
+    /// Earliest unix timestamp at which the change can be executed
+    pub eta: i64,
+    pub bump: u8,
+}
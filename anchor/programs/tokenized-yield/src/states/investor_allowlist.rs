@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// Verified-investor entry for one (vault, investor) pair, required by
+/// `mint_shares` while `vault.mint_allowlist_required` is set
+#[account]
+#[derive(InitSpace)]
+pub struct InvestorAllowlist {
+    pub vault: Pubkey,
+    pub investor: Pubkey,
+    /// Unix timestamp the verification lapses (0 = never)
+    pub expires_at: i64,
+    /// ISO 3166-1 alpha-2 country code of the investor (e.g. `*b"US"`), informational
+    pub jurisdiction: [u8; 2],
+    /// Compliance officer that last wrote the entry
+    pub approved_by: Pubkey,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl InvestorAllowlist {
+    pub fn is_valid_at(&self, now: i64) -> bool {
+        self.expires_at == 0 || now < self.expires_at
+    }
+}
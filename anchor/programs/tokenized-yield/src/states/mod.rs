@@ -2,4 +2,31 @@ pub mod vault;
 pub use vault::*;
 
 pub mod user_stake;
-pub use user_stake::*;
\ No newline at end of file
+pub use user_stake::*;
+
+pub mod nav_history;
+pub use nav_history::*;
+
+pub mod governance_proposal;
+pub use governance_proposal::*;
+
+pub mod multisig;
+pub use multisig::*;
+
+pub mod shareholder_proposal;
+pub use shareholder_proposal::*;
+
+pub mod revenue_depositor;
+pub use revenue_depositor::*;
+
+pub mod investor_allowlist;
+pub use investor_allowlist::*;
+
+pub mod forced_transfer;
+pub use forced_transfer::*;
+
+pub mod operator_debt;
+pub use operator_debt::*;
+
+pub mod redemption_request;
+pub use redemption_request::*;
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::{GovernanceAction, VaultStatus};
+
+/// Upper bound on multisig members (approvals are tracked in a u16 bitmask)
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+/// Governance call a multisig executes once enough members approve
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum MultisigAction {
+    /// Timelocked change; executes `vault.governance_delay` after the threshold is met
+    Governance { action: GovernanceAction },
+    CancelGovernanceChange { proposal_id: u64 },
+    LowerPerformanceFee { new_fee_bps: u16 },
+    SetFeeRecipient { fee_recipient: Pubkey },
+    SetGuardian { guardian: Pubkey },
+    SetOpenRevenueDeposits { open: bool },
+    SetComplianceAuthority { compliance_authority: Pubkey },
+    SetMintAllowlistRequired { required: bool },
+    SetInvestmentLimits { min_mint_amount: u64, max_shares_per_holder: u64, max_holder_count: u32 },
+    ExtendSubscription { subscription_end: i64 },
+    WriteDown { impairment_bps: u16 },
+    WithdrawFees { amount: u64 },
+    ReleasePrincipal { amount: u64 },
+    /// Opens the vault's `operator_debt` ledger, paid by the executor
+    InitializeOperatorDebt {
+        operator: Pubkey,
+        interest_rate_bps: u16,
+        installment_amount: u64,
+        payment_interval: i64,
+        first_due_at: i64,
+        grace_period: i64,
+    },
+    MarkOperatorDefault,
+    /// Creates the vault's `nav_history` on first use, paid by the executor
+    ConfigureNavPricing { nav_oracle: Pubkey, max_nav_change_bps: u16 },
+    /// Creates the depositor's registry entry, paid by the executor
+    AddRevenueDepositor { depositor: Pubkey },
+    /// Closes the registry entry, refunding its rent to the executor
+    RemoveRevenueDepositor { depositor: Pubkey },
+    Unpause { flags: u8 },
+    SetVaultStatus { status: VaultStatus },
+    /// Hand authority to another key or program, which must `accept_authority`
+    ProposeAuthority { new_authority: Pubkey },
+    CancelPendingAuthority,
+    RevokeAuthority,
+}
+
+/// M-of-N signer set that can hold `vault.authority`
+#[account]
+#[derive(InitSpace)]
+pub struct Multisig {
+    pub vault: Pubkey,
+    #[max_len(MAX_MULTISIG_SIGNERS)]
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    /// Bumped on every reconfiguration so approvals collected under an
+    /// old signer set can never execute
+    pub config_version: u32,
+    /// Number of transactions ever created; seeds the next transaction PDA
+    pub transaction_count: u64,
+    pub bump: u8,
+}
+
+impl Multisig {
+    pub fn signer_index(&self, key: &Pubkey) -> Option<usize> {
+        self.signers.iter().position(|signer| signer == key)
+    }
+}
+
+/// A pending multisig action and the approvals collected for it
+#[account]
+#[derive(InitSpace)]
+pub struct MultisigTransaction {
+    pub multisig: Pubkey,
+    pub transaction_id: u64,
+    pub proposer: Pubkey,
+    pub action: MultisigAction,
+    /// Bit `i` set = `multisig.signers[i]` approved
+    pub approvals: u16,
+    /// `multisig.config_version` when the transaction was created
+    pub config_version: u32,
+    /// Unix timestamp the threshold was first met (0 = not yet)
+    pub approved_at: i64,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl MultisigTransaction {
+    pub fn approval_count(&self) -> u32 {
+        self.approvals.count_ones()
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+/// Number of valuations kept before the oldest is overwritten
+pub const NAV_HISTORY_LEN: usize = 32;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct NavRecord {
+    pub net_asset_value: u64,
+    pub minted_shares: u64,
+    pub updater: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Ring buffer of the most recent NAV valuations posted for a vault
+#[account]
+#[derive(InitSpace)]
+pub struct NavHistory {
+    pub vault: Pubkey,
+    /// Slot the next record is written to once the buffer is full
+    pub head: u8,
+    /// Total valuations ever recorded
+    pub count: u64,
+    #[max_len(NAV_HISTORY_LEN)]
+    pub records: Vec<NavRecord>,
+    pub bump: u8,
+}
+
+impl NavHistory {
+    pub fn push(&mut self, record: NavRecord) {
+        if self.records.len() < NAV_HISTORY_LEN {
+            self.records.push(record);
+        } else {
+            self.records[self.head as usize] = record;
+            self.head = ((self.head as usize + 1) % NAV_HISTORY_LEN) as u8;
+        }
+        self.count = self.count.saturating_add(1);
+    }
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::states::vault::FEE_BPS_DENOMINATOR;
+
+/// Seconds used to turn an annual interest rate into per-second accrual
+pub const SECONDS_PER_YEAR: u128 = 365 * 24 * 60 * 60;
+
+/// What the operator owes for principal released to it, one PDA per vault.
+/// Outstanding principal is `vault.principal_deployed`; this ledger adds the
+/// drawdown history, interest terms and repayment schedule.
+#[account]
+#[derive(InitSpace)]
+pub struct OperatorDebt {
+    pub vault: Pubkey,
+    /// Wallet whose `deposit_revenue` payments count as interest
+    pub operator: Pubkey,
+
+    /// Simple annual interest on outstanding principal, in basis points
+    pub interest_rate_bps: u16,
+    /// Amount due each `payment_interval`, principal and interest combined
+    pub installment_amount: u64,
+    pub payment_interval: i64,
+    /// Unix timestamp the first installment falls due
+    pub first_due_at: i64,
+    /// Time after a due date before a missed installment counts as overdue
+    pub grace_period: i64,
+
+    /// Lifetime principal released to the operator
+    pub total_drawn: u64,
+    /// Lifetime principal returned through `return_principal`
+    pub principal_repaid: u64,
+    /// Interest accrued and not yet paid
+    pub accrued_interest: u64,
+    /// Lifetime interest paid through `deposit_revenue`
+    pub interest_paid: u64,
+    /// Sub-unit interest carried between accruals (scaled by bps * seconds per year)
+    pub interest_remainder: u128,
+    pub interest_updated_at: i64,
+
+    pub defaulted: bool,
+    pub defaulted_at: i64,
+    pub bump: u8,
+}
+
+impl OperatorDebt {
+    /// Must run before `principal_deployed` changes. Carries the division
+    /// remainder so frequent accruals cannot round interest away.
+    pub fn accrue_interest(&mut self, principal_deployed: u64, now: i64) -> Result<()> {
+        let elapsed = now.saturating_sub(self.interest_updated_at).max(0) as u128;
+        self.interest_updated_at = now;
+        if elapsed == 0 || principal_deployed == 0 || self.interest_rate_bps == 0 {
+            return Ok(());
+        }
+
+        let denominator = (FEE_BPS_DENOMINATOR as u128)
+            .checked_mul(SECONDS_PER_YEAR)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let numerator = (principal_deployed as u128)
+            .checked_mul(self.interest_rate_bps as u128)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_mul(elapsed)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_add(self.interest_remainder)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        let interest = u64::try_from(numerator / denominator).map_err(|_| ErrorCode::Overflow)?;
+        self.interest_remainder = numerator % denominator;
+        self.accrued_interest = self.accrued_interest
+            .checked_add(interest)
+            .ok_or(ErrorCode::Overflow)?;
+
+        Ok(())
+    }
+
+    /// Applies an interest payment; anything above accrued interest is plain revenue
+    pub fn record_interest_payment(&mut self, amount: u64) -> Result<()> {
+        let applied = amount.min(self.accrued_interest);
+        self.accrued_interest -= applied;
+        self.interest_paid = self.interest_paid
+            .checked_add(applied)
+            .ok_or(ErrorCode::Overflow)?;
+        Ok(())
+    }
+
+    /// Installments that have fallen due by `at`
+    pub fn installments_due(&self, at: i64) -> u64 {
+        if at < self.first_due_at || self.payment_interval <= 0 {
+            return 0;
+        }
+        ((at - self.first_due_at) / self.payment_interval) as u64 + 1
+    }
+
+    /// True when the operator has paid less than the schedule required a
+    /// grace period ago, capped at what it has actually owed
+    pub fn is_overdue(&self, principal_deployed: u64, now: i64) -> bool {
+        let paid = self.principal_repaid as u128 + self.interest_paid as u128;
+        let owed = paid + principal_deployed as u128 + self.accrued_interest as u128;
+        let scheduled = (self.installments_due(now.saturating_sub(self.grace_period)) as u128)
+            .saturating_mul(self.installment_amount as u128);
+        paid < scheduled.min(owed)
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum RedemptionStatus {
+    /// Waiting for its unlock time and for liquid principal
+    Queued,
+    Filled,
+    Cancelled,
+}
+
+/// A queued redemption, one PDA per `vault.redemption_request_count`.
+/// The shares are burned when queued but stay in `UserStake.quantity`
+/// (and keep earning) until the request is filled or cancelled.
+#[account]
+#[derive(InitSpace)]
+pub struct RedemptionRequest {
+    pub vault: Pubkey,
+    /// Position in the vault's FIFO queue
+    pub request_id: u64,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub status: RedemptionStatus,
+    pub requested_at: i64,
+    /// Earliest unix timestamp at which `process_redemptions` may fill it
+    pub unlock_at: i64,
+    /// Principal paid when filled, priced at fill time
+    pub principal_paid: u64,
+    pub bump: u8,
+}
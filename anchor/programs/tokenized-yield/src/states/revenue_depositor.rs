@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+/// Registry entry allowing `depositor` to call `deposit_revenue` on `vault`
+/// while the vault does not accept open deposits. Closing the PDA revokes it.
+#[account]
+#[derive(InitSpace)]
+pub struct RevenueDepositor {
+    pub vault: Pubkey,
+    pub depositor: Pubkey,
+    pub added_at: i64,
+    pub bump: u8,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::states::vault::FEE_BPS_DENOMINATOR;
+
+/// How long a shareholder proposal accepts votes (5 days)
+pub const SHAREHOLDER_VOTING_PERIOD: i64 = 5 * 24 * 60 * 60;
+/// A passed proposal must be executed within this window after voting ends,
+/// otherwise it expires and frees the vault for a new proposal (7 days)
+pub const SHAREHOLDER_EXECUTION_WINDOW: i64 = 7 * 24 * 60 * 60;
+/// Share of `minted_shares` a holder needs to open a proposal (1%)
+pub const SHAREHOLDER_PROPOSAL_THRESHOLD_BPS: u64 = 100;
+/// Share of the snapshot supply that must vote for a result to count (20%)
+pub const SHAREHOLDER_QUORUM_BPS: u64 = 2_000;
+/// Votes in favour must exceed this share of votes cast (simple majority)
+pub const SHAREHOLDER_APPROVAL_BPS: u64 = 5_000;
+
+/// Change a shareholder proposal applies when it passes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum ShareholderAction {
+    UpdatePerformanceFee { new_fee_bps: u16 },
+    UpdateTreasury { new_treasury: Pubkey },
+    /// Install a new authority directly, including on a revoked vault
+    ReplaceAuthority { new_authority: Pubkey },
+    /// Move the vault to `WindingDown` so holders can redeem out
+    WindDown,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum ShareholderProposalStatus {
+    Voting,
+    Executed,
+    /// Voting ended without quorum or approval
+    Defeated,
+    /// Passed but not executed within `SHAREHOLDER_EXECUTION_WINDOW`
+    Expired,
+}
+
+/// A share-weighted vote on a vault parameter, one PDA per `vault.shareholder_proposal_count`.
+/// Only one proposal per vault is open at a time (`vault.active_shareholder_proposal`).
+#[account]
+#[derive(InitSpace)]
+pub struct ShareholderProposal {
+    pub vault: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: ShareholderAction,
+    pub status: ShareholderProposalStatus,
+    /// `vault.minted_shares` when the proposal was created; the total voting power
+    pub snapshot_supply: u64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    pub bump: u8,
+}
+
+impl ShareholderProposal {
+    pub fn quorum_reached(&self) -> Option<bool> {
+        let cast = (self.votes_for as u128).checked_add(self.votes_against as u128)?;
+        let required = (self.snapshot_supply as u128).checked_mul(SHAREHOLDER_QUORUM_BPS as u128)?;
+        Some(cast.checked_mul(FEE_BPS_DENOMINATOR as u128)? >= required)
+    }
+
+    pub fn approved(&self) -> Option<bool> {
+        let cast = (self.votes_for as u128).checked_add(self.votes_against as u128)?;
+        let required = cast.checked_mul(SHAREHOLDER_APPROVAL_BPS as u128)?;
+        Some((self.votes_for as u128).checked_mul(FEE_BPS_DENOMINATOR as u128)? > required)
+    }
+}
+
+/// One holder's vote on a shareholder proposal; its existence prevents voting twice
+#[account]
+#[derive(InitSpace)]
+pub struct ShareholderVote {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub support: bool,
+    /// Holder's `UserStake.quantity` at proposal creation
+    pub weight: u64,
+    pub bump: u8,
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::{constants::PRECISION, error::ErrorCode};
+
 #[account]
 #[derive(InitSpace)]
 pub struct UserStake {
@@ -10,4 +12,95 @@ pub struct UserStake {
 
     pub reward_debt: u128,
     pub bump: u8,
+
+    /// Rewards settled at a checkpoint (e.g. a share transfer) but not yet paid out
+    pub pending_rewards: u64,
+
+    /// Shareholder proposal open when `quantity` last changed (Pubkey::default() = none)
+    pub vote_snapshot_proposal: Pubkey,
+    /// `quantity` at the creation of `vote_snapshot_proposal`
+    pub vote_snapshot_quantity: u64,
+
+    /// Set by compliance; blocks harvesting, redeeming and moving shares
+    pub frozen: bool,
+    /// Compliance reason code of the current freeze (0 = not frozen)
+    pub freeze_reason: u16,
+
+    /// Part of `quantity` locked in the redemption queue; its share tokens are already burned
+    pub queued_quantity: u64,
+}
+
+impl UserStake {
+    pub fn initialize(&mut self, owner: Pubkey, vault: Pubkey, bump: u8) {
+        self.is_initialized = true;
+        self.owner = owner;
+        self.vault = vault;
+        self.quantity = 0u64;
+        self.reward_debt = 0u128;
+        self.bump = bump;
+        self.pending_rewards = 0;
+        self.vote_snapshot_proposal = Pubkey::default();
+        self.vote_snapshot_quantity = 0;
+        self.frozen = false;
+        self.freeze_reason = 0;
+        self.queued_quantity = 0;
+    }
+
+    /// Shares the holder can still redeem, transfer or queue
+    pub fn unlocked_quantity(&self) -> u64 {
+        self.quantity.saturating_sub(self.queued_quantity)
+    }
+
+    /// Reward entitlement of the current quantity at `acc_reward_per_share`
+    pub fn accumulated_rewards(&self, acc_reward_per_share: u128) -> Result<u128> {
+        Ok((self.quantity as u128)
+            .checked_mul(acc_reward_per_share)
+            .ok_or(ErrorCode::Overflow)?
+            .checked_div(PRECISION)
+            .ok_or(ErrorCode::MathOverflow)?)
+    }
+
+    /// Moves rewards accrued since the last checkpoint into `pending_rewards`
+    /// and re-baselines `reward_debt`, so `quantity` can change without
+    /// gaining or losing yield.
+    pub fn checkpoint_rewards(&mut self, acc_reward_per_share: u128) -> Result<()> {
+        let accumulated = self.accumulated_rewards(acc_reward_per_share)?;
+        let accrued = accumulated
+            .checked_sub(self.reward_debt)
+            .ok_or(ErrorCode::Underflow)?;
+        let accrued = u64::try_from(accrued).map_err(|_| ErrorCode::Overflow)?;
+
+        self.pending_rewards = self.pending_rewards
+            .checked_add(accrued)
+            .ok_or(ErrorCode::Overflow)?;
+        self.reward_debt = accumulated;
+
+        Ok(())
+    }
+
+    /// Must run before every `quantity` change. The first change while a
+    /// shareholder proposal is open records the quantity the holder had when
+    /// it was created, so shares cannot vote twice by moving between wallets.
+    pub fn snapshot_voting_power(&mut self, active_proposal: Pubkey) {
+        if active_proposal != Pubkey::default() && self.vote_snapshot_proposal != active_proposal {
+            self.vote_snapshot_proposal = active_proposal;
+            self.vote_snapshot_quantity = self.quantity;
+        }
+    }
+
+    /// Voting weight on `proposal`: the snapshot if `quantity` changed since it was created
+    pub fn voting_power(&self, proposal: Pubkey) -> u64 {
+        if self.vote_snapshot_proposal == proposal {
+            self.vote_snapshot_quantity
+        } else {
+            self.quantity
+        }
+    }
+
+    /// Sets `quantity` after a checkpoint and rebases `reward_debt` to match
+    pub fn rebase_quantity(&mut self, quantity: u64, acc_reward_per_share: u128) -> Result<()> {
+        self.quantity = quantity;
+        self.reward_debt = self.accumulated_rewards(acc_reward_per_share)?;
+        Ok(())
+    }
 }
@@ -1,18 +1,105 @@
 use anchor_lang::prelude::*;
 
+use crate::error::ErrorCode;
+use crate::states::OperatorDebt;
+
 /// Maximum performance fee in basis points (20% = 2000 bps)
 pub const MAX_PERFORMANCE_FEE_BPS: u16 = 2000;
 /// Basis points denominator (100% = 10_000 bps)
 pub const FEE_BPS_DENOMINATOR: u64 = 10_000;
+/// Delay applied to timelocked governance changes on new vaults (48 hours)
+pub const DEFAULT_GOVERNANCE_DELAY: i64 = 48 * 60 * 60;
+/// Bounds for `Vault::governance_delay` (1 hour to 30 days)
+pub const MIN_GOVERNANCE_DELAY: i64 = 60 * 60;
+pub const MAX_GOVERNANCE_DELAY: i64 = 30 * 24 * 60 * 60;
+/// Pause flags for `Vault::paused_instructions`
+pub const PAUSE_MINT: u8 = 1 << 0;
+pub const PAUSE_DEPOSIT_REVENUE: u8 = 1 << 1;
+pub const PAUSE_HARVEST: u8 = 1 << 2;
+pub const PAUSE_REDEEM: u8 = 1 << 3;
+pub const PAUSE_TRANSFER: u8 = 1 << 4;
+pub const PAUSE_ALL: u8 = PAUSE_MINT | PAUSE_DEPOSIT_REVENUE | PAUSE_HARVEST | PAUSE_REDEEM | PAUSE_TRANSFER;
+/// Widest allowed per-update NAV move in basis points (100% = 10_000 bps)
+pub const MAX_NAV_CHANGE_BPS: u16 = 10_000;
+/// Wait before `emergency_withdraw` opens on new vaults (14 days)
+pub const DEFAULT_EMERGENCY_EXIT_DELAY: i64 = 14 * 24 * 60 * 60;
+/// Bounds for `Vault::emergency_exit_delay` (1 day to 90 days)
+pub const MIN_EMERGENCY_EXIT_DELAY: i64 = 24 * 60 * 60;
+pub const MAX_EMERGENCY_EXIT_DELAY: i64 = 90 * 24 * 60 * 60;
+/// Longest notice `request_redemption` may impose (180 days)
+pub const MAX_REDEMPTION_NOTICE_PERIOD: i64 = 180 * 24 * 60 * 60;
+
+/// Lifecycle stage of a vault, see `STATE_MACHINE.md`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum VaultStatus {
+    /// Raising capital: shares can be minted
+    Fundraising,
+    /// Capital deployed: normal operation
+    Active,
+    /// All user-facing instructions halted until the authority resumes
+    Paused,
+    /// No new shares; holders harvest and redeem out
+    WindingDown,
+    /// Terminal: every share has been redeemed
+    Closed,
+    /// Raise missed its soft cap: holders take their principal back with `refund`
+    Refunding,
+}
+
+impl VaultStatus {
+    pub fn allows_minting(&self) -> bool {
+        matches!(self, VaultStatus::Fundraising | VaultStatus::Active)
+    }
+
+    pub fn allows_revenue(&self) -> bool {
+        matches!(self, VaultStatus::Fundraising | VaultStatus::Active | VaultStatus::WindingDown)
+    }
+
+    /// Redeeming and moving shares between holders
+    pub fn allows_exits(&self) -> bool {
+        matches!(self, VaultStatus::Fundraising | VaultStatus::Active | VaultStatus::WindingDown)
+    }
+
+    /// Claiming rewards stays open after close so settled `pending_rewards` are never stranded
+    pub fn allows_harvest(&self) -> bool {
+        *self != VaultStatus::Paused
+    }
+
+    /// Transitions the authority may request with `set_vault_status`.
+    /// Leaving `Paused` is validated separately against the status it paused from.
+    pub fn can_transition_to(&self, next: VaultStatus) -> bool {
+        use VaultStatus::*;
+        matches!(
+            (self, next),
+            (Fundraising, Active)
+                | (Fundraising, WindingDown)
+                | (Active, WindingDown)
+                | (WindingDown, Closed)
+                | (Refunding, Closed)
+                | (Fundraising | Active | WindingDown, Paused)
+        )
+    }
+}
 
 #[account]
 #[derive(InitSpace)]
 pub struct Vault {
     pub owner: Pubkey,
+    /// Owner-chosen index, part of the vault PDA seeds so one owner can run many vaults
+    pub vault_id: u64,
     #[max_len(50)]
     pub name: String,
 
     pub vault_share_mint: Pubkey,
+    /// Token program that owns the share mint (SPL Token or Token-2022)
+    pub share_token_program: Pubkey,
+    /// Share transfers are settled by this program's transfer hook
+    pub transfer_hook_enabled: bool,
+
+    /// Lifecycle status; gates which instructions the vault accepts
+    pub status: VaultStatus,
+    /// Status to return to when the vault is unpaused
+    pub status_before_pause: VaultStatus,
 
     // vaults
     pub payment_mint: Pubkey,
@@ -33,10 +120,106 @@ pub struct Vault {
     pub treasury: Pubkey,
     /// Total fees collected (for Protocol Revenue Invariant verification)
     pub total_fees_collected: u64,
+    /// Total fees paid out of the treasury
+    pub total_fees_withdrawn: u64,
+    /// Token account that permissionless fee sweeps pay into (Pubkey::default() = unset)
+    pub fee_recipient: Pubkey,
+
+    // Revenue Access Layer
+    /// Anyone may call `deposit_revenue`; when false only registered `RevenueDepositor`s can
+    pub open_revenue_deposits: bool,
+
+    // NAV Pricing Layer
+    /// Shares are priced from `net_asset_value / minted_shares` instead of `price_per_share`
+    pub nav_pricing_enabled: bool,
+    /// Valuation of the vault's assets backing minted shares, in payment tokens
+    pub net_asset_value: u64,
+    /// Account that may post valuations besides the authority (Pubkey::default() = authority only)
+    pub nav_oracle: Pubkey,
+    /// Maximum NAV move per `update_nav` call, in basis points of the previous NAV
+    pub max_nav_change_bps: u16,
+    /// Unix timestamp of the last posted valuation
+    pub nav_updated_at: i64,
 
     // Governance Layer
     /// Authority that can modify protocol parameters (Pubkey::default() = governance disabled)
     pub authority: Pubkey,
+    /// Proposed authority awaiting `accept_authority` (Pubkey::default() = none)
+    pub pending_authority: Pubkey,
+    /// Seconds a queued governance change must wait before it can execute
+    pub governance_delay: i64,
+    /// Number of governance proposals ever queued; seeds the next proposal PDA
+    pub proposal_count: u64,
+    /// Number of shareholder proposals ever created; seeds the next shareholder proposal PDA
+    pub shareholder_proposal_count: u64,
+    /// Shareholder proposal that is open or awaiting execution (Pubkey::default() = none)
+    pub active_shareholder_proposal: Pubkey,
+
+    // Emergency Pause Layer
+    /// Can pause instructions, but never unpause (Pubkey::default() = no guardian)
+    pub guardian: Pubkey,
+    /// Bitmask of `PAUSE_*` flags for instructions currently halted
+    pub paused_instructions: u8,
+
+    // Compliance Layer
+    /// Manages the investor allowlist alongside the authority (Pubkey::default() = authority only)
+    pub compliance_authority: Pubkey,
+    /// `mint_shares` requires a valid `InvestorAllowlist` entry for the buyer
+    pub mint_allowlist_required: bool,
+    /// Number of forced transfers ever executed; seeds the next `ForcedTransferRecord`
+    pub forced_transfer_count: u64,
+
+    // Investment Limits Layer
+    /// Smallest `mint_shares` amount accepted (0 = no minimum)
+    pub min_mint_amount: u64,
+    /// Largest `UserStake.quantity` a single holder may reach (0 = unlimited)
+    pub max_shares_per_holder: u64,
+    /// Most holders with a non-zero quantity at once (0 = unlimited)
+    pub max_holder_count: u32,
+    /// Holders whose `UserStake.quantity` is currently non-zero
+    pub holder_count: u32,
+
+    // Subscription Window Layer
+    /// Unix timestamp from which `mint_shares` is accepted
+    pub subscription_start: i64,
+    /// Unix timestamp at which `mint_shares` closes (0 = open-ended)
+    pub subscription_end: i64,
+    /// Shares that must be sold by `subscription_end` for the raise to go ahead (0 = no soft cap)
+    pub soft_cap: u64,
+
+    // Principal Deployment Layer
+    /// Payment-mint token account `release_principal` pays out to (default = none registered)
+    pub principal_operator: Pubkey,
+    /// Principal released to the operator and not yet returned; held off-chain
+    pub principal_deployed: u64,
+    /// An `OperatorDebt` ledger exists and must accompany harvests and redemptions
+    pub has_operator_debt: bool,
+
+    // Impairment Layer
+    /// Share of par value written off, in basis points; fixed-price shares are worth
+    /// `price_per_share * (10_000 - impairment_bps) / 10_000`
+    pub impairment_bps: u16,
+    /// Cumulative loss recognised by `write_down`, in payment tokens at par
+    pub principal_written_down: u64,
+    /// Unix timestamp of the first write-down (0 = never impaired)
+    pub written_down_at: i64,
+
+    // Emergency Exit Layer
+    /// Seconds after redemptions halt, a write-down or an operator default before
+    /// holders may leave through `emergency_withdraw`
+    pub emergency_exit_delay: i64,
+    /// Unix timestamp since which redemptions have been halted (0 = not halted)
+    pub redemptions_halted_at: i64,
+
+    // Redemption Queue Layer
+    /// Seconds between `request_redemption` and the earliest fill (0 = no notice)
+    pub redemption_notice_period: i64,
+    /// Number of redemption requests ever queued; seeds the next request PDA
+    pub redemption_request_count: u64,
+    /// Id of the request at the head of the FIFO queue
+    pub next_redemption_to_fill: u64,
+    /// Shares locked in queued requests; counted in `minted_shares`, already burned
+    pub queued_shares: u64,
 
     pub bump: u8,
     pub signer_bump: u8,
@@ -47,4 +230,153 @@ impl Vault {
     pub fn is_governance_disabled(&self) -> bool {
         self.authority == Pubkey::default()
     }
+
+    /// Fees collected into the treasury that have not yet been withdrawn
+    pub fn withdrawable_fees(&self) -> Option<u64> {
+        self.total_fees_collected.checked_sub(self.total_fees_withdrawn)
+    }
+
+    /// Returns true if `key` may pause instructions.
+    /// The guardian keeps this power after governance is revoked.
+    pub fn is_pauser(&self, key: &Pubkey) -> bool {
+        (self.guardian != Pubkey::default() && *key == self.guardian)
+            || (!self.is_governance_disabled() && *key == self.authority)
+    }
+
+    /// Returns true if `key` may manage compliance records.
+    /// The compliance authority keeps this power after governance is revoked.
+    pub fn is_compliance_officer(&self, key: &Pubkey) -> bool {
+        (self.compliance_authority != Pubkey::default() && *key == self.compliance_authority)
+            || (!self.is_governance_disabled() && *key == self.authority)
+    }
+
+    /// Overdue status of the operator debt, surfaced by harvest and redeem.
+    /// Vaults with a ledger must supply it so the status is never dropped.
+    pub fn operator_overdue(&self, debt: Option<&OperatorDebt>, now: i64) -> Result<bool> {
+        match debt {
+            Some(debt) => Ok(debt.is_overdue(self.principal_deployed, now)),
+            None => {
+                require!(!self.has_operator_debt, ErrorCode::OperatorDebtRequired);
+                Ok(false)
+            }
+        }
+    }
+
+    /// Returns true once the subscription window has closed for good
+    pub fn subscription_ended(&self, now: i64) -> bool {
+        self.subscription_end != 0 && now >= self.subscription_end
+    }
+
+    /// Must run for every stake whose `quantity` changes. Enforces the per-holder
+    /// and holder-count limits when a position grows, and keeps `holder_count`
+    /// in step as quantities cross zero. On transfers, apply the sending side first.
+    pub fn track_holder(&mut self, old_quantity: u64, new_quantity: u64) -> Result<()> {
+        if new_quantity > old_quantity {
+            require!(
+                self.max_shares_per_holder == 0 || new_quantity <= self.max_shares_per_holder,
+                ErrorCode::HolderShareLimitExceeded
+            );
+        }
+
+        if old_quantity == 0 && new_quantity > 0 {
+            require!(
+                self.max_holder_count == 0 || self.holder_count < self.max_holder_count,
+                ErrorCode::HolderCountLimitReached
+            );
+            self.holder_count = self.holder_count
+                .checked_add(1)
+                .ok_or(ErrorCode::Overflow)?;
+        } else if old_quantity > 0 && new_quantity == 0 {
+            self.holder_count = self.holder_count
+                .checked_sub(1)
+                .ok_or(ErrorCode::Underflow)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if any of the instructions in `flags` is paused
+    pub fn is_paused(&self, flags: u8) -> bool {
+        self.paused_instructions & flags != 0
+    }
+
+    /// Returns true while `redeem_shares` is blocked by the vault status or a pause flag
+    pub fn redemptions_halted(&self) -> bool {
+        self.status == VaultStatus::Paused || self.is_paused(PAUSE_REDEEM)
+    }
+
+    /// Returns true while exits must go through `request_redemption`: a notice period
+    /// is set or earlier requests are still waiting in the FIFO queue
+    pub fn requires_redemption_queue(&self) -> bool {
+        self.redemption_notice_period > 0 || self.queued_shares > 0
+    }
+
+    /// Must run after every change to `status` or `paused_instructions`. Starts the
+    /// emergency-exit clock when redemptions halt and clears it when they resume.
+    pub fn sync_redemption_halt(&mut self, now: i64) {
+        if !self.redemptions_halted() {
+            self.redemptions_halted_at = 0;
+        } else if self.redemptions_halted_at == 0 {
+            self.redemptions_halted_at = now;
+        }
+    }
+
+    /// Unix timestamp from which `emergency_withdraw` is open: `emergency_exit_delay`
+    /// after the earliest of a redemption halt, a write-down or `debt_defaulted_at`.
+    /// None while nothing has triggered it.
+    pub fn emergency_exit_opens_at(&self, debt_defaulted_at: Option<i64>) -> Option<i64> {
+        [self.redemptions_halted_at, self.written_down_at, debt_defaulted_at.unwrap_or(0)]
+            .into_iter()
+            .filter(|&at| at != 0)
+            .min()
+            .map(|at| at.saturating_add(self.emergency_exit_delay))
+    }
+
+    /// Returns true if `key` may post a NAV valuation
+    pub fn is_nav_updater(&self, key: &Pubkey) -> bool {
+        (!self.is_governance_disabled() && *key == self.authority)
+            || (self.nav_oracle != Pubkey::default() && *key == self.nav_oracle)
+    }
+
+    /// `amount * price_per_share` less the written-down fraction, so every share
+    /// carries the same slice of the loss whenever it exits
+    pub fn fixed_price_value(&self, amount: u64, round_up: bool) -> Option<u64> {
+        let retained_bps = FEE_BPS_DENOMINATOR.checked_sub(self.impairment_bps as u64)? as u128;
+        let denominator = FEE_BPS_DENOMINATOR as u128;
+        let scaled = (amount as u128)
+            .checked_mul(self.price_per_share as u128)?
+            .checked_mul(retained_bps)?;
+        let value = if round_up {
+            scaled.checked_add(denominator - 1)? / denominator
+        } else {
+            scaled / denominator
+        };
+        u64::try_from(value).ok()
+    }
+
+    /// Payment owed for minting `amount` shares.
+    ///
+    /// In NAV mode the price is `net_asset_value / minted_shares`, rounded up so
+    /// new investors never dilute existing holders. An empty NAV vault falls back
+    /// to the (possibly written-down) fixed price.
+    pub fn mint_cost(&self, amount: u64) -> Option<u64> {
+        if !self.nav_pricing_enabled || self.minted_shares == 0 {
+            return self.fixed_price_value(amount, true);
+        }
+        let numerator = (amount as u128).checked_mul(self.net_asset_value as u128)?;
+        let minted = self.minted_shares as u128;
+        let cost = numerator.checked_add(minted - 1)?.checked_div(minted)?;
+        u64::try_from(cost).ok()
+    }
+
+    /// Principal owed for redeeming `amount` shares, rounded down
+    pub fn redemption_value(&self, amount: u64) -> Option<u64> {
+        if !self.nav_pricing_enabled || self.minted_shares == 0 {
+            return self.fixed_price_value(amount, false);
+        }
+        let value = (amount as u128)
+            .checked_mul(self.net_asset_value as u128)?
+            .checked_div(self.minted_shares as u128)?;
+        u64::try_from(value).ok()
+    }
 }
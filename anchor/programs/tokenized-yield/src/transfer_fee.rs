@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+    state::Mint,
+};
+
+use crate::error::ErrorCode;
+
+/// Fee Token-2022 withholds when `amount` is sent. Zero for SPL Token mints
+/// and Token-2022 mints without the transfer-fee extension.
+pub fn transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    match epoch_fee_config(mint)? {
+        Some((config, epoch)) => Ok(config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or(ErrorCode::MathOverflow)?),
+        None => Ok(0),
+    }
+}
+
+/// Amount that has to be sent so the destination is credited exactly `net_amount`.
+pub fn gross_amount_for_net(mint: &AccountInfo, net_amount: u64) -> Result<u64> {
+    let fee = match epoch_fee_config(mint)? {
+        Some((config, epoch)) => config
+            .calculate_inverse_epoch_fee(epoch, net_amount)
+            .ok_or(ErrorCode::MathOverflow)?,
+        None => 0,
+    };
+
+    Ok(net_amount.checked_add(fee).ok_or(ErrorCode::Overflow)?)
+}
+
+/// Amount the destination is credited when `amount` is sent.
+pub fn net_amount_received(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let fee = transfer_fee(mint, amount)?;
+    Ok(amount.checked_sub(fee).ok_or(ErrorCode::Underflow)?)
+}
+
+fn epoch_fee_config(mint: &AccountInfo) -> Result<Option<(TransferFeeConfig, u64)>> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(None);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<Mint>::unpack(&data)?;
+    match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => Ok(Some((*config, Clock::get()?.epoch))),
+        Err(_) => Ok(None),
+    }
+}
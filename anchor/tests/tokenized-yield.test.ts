@@ -1,14 +1,32 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
+import { BankrunProvider } from "anchor-bankrun";
+import path from "path";
+import { Clock, ProgramTestContext, start } from "solana-bankrun";
 import {
   PublicKey,
   SystemProgram,
 } from "@solana/web3.js";
 import {
+  AccountLayout,
   ASSOCIATED_TOKEN_PROGRAM_ID,
   createAccount,
+  createAssociatedTokenAccountIdempotentInstruction,
+  createAssociatedTokenAccountInstruction,
   createMint,
+  createInitializeMintInstruction,
+  createInitializeMint2Instruction,
+  createInitializeTransferFeeConfigInstruction,
+  createMintToInstruction,
+  createTransferCheckedWithTransferHookInstruction,
+  ExtensionType,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getMintLen,
+  getTransferFeeAmount,
+  MINT_SIZE,
   mintTo,
+  TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { TokenizedYieldInfrastructure } from "../target/types/tokenized_yield_infrastructure";
@@ -34,7 +52,7 @@ describe("Tokenized Yield Lifecycle", () => {
 
   beforeAll(async () => {
     [vaultPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vault"), vaultOwnerPubKey.toBuffer()],
+      [Buffer.from("vault"), vaultOwnerPubKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
       program.programId
     );
 
@@ -97,7 +115,7 @@ describe("Tokenized Yield Lifecycle", () => {
 
   it("initializes the Vault", async () => {
     await program.methods
-      .initializeVault("Ramesh Vault", new anchor.BN(10_000_000_000), new anchor.BN(100), 1000) // 10% fee
+      .initializeVault(new anchor.BN(0), "Ramesh Vault", new anchor.BN(10_000_000_000), new anchor.BN(100), 1000, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0)) // 10% fee
       .accounts({
         owner: vaultOwnerPubKey,
         vault: vaultPda,
@@ -109,6 +127,7 @@ describe("Tokenized Yield Lifecycle", () => {
         vaultShareMint: vaultShareMintPda,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
       })
@@ -130,6 +149,41 @@ describe("Tokenized Yield Lifecycle", () => {
     expect(vaultAccount.authority.toString()).toBe(vaultOwnerPubKey.toString());
   });
 
+  it("initializes a second vault for the same owner with a new vault_id", async () => {
+    const vaultId = new anchor.BN(1);
+    const [secondVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), vaultOwnerPubKey.toBuffer(), vaultId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const derive = (seed: string) =>
+      PublicKey.findProgramAddressSync([Buffer.from(seed), secondVaultPda.toBuffer()], program.programId)[0];
+
+    await program.methods
+      .initializeVault(vaultId, "Ramesh Vault II", new anchor.BN(1_000_000), new anchor.BN(100), 1000, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: vaultOwnerPubKey,
+        vault: secondVaultPda,
+        vaultSigner: derive("vault_signer"),
+        paymentMint,
+        principalVault: derive("principal-vault"),
+        revenueVault: derive("revenue-vault"),
+        treasury: derive("treasury"),
+        vaultShareMint: derive("vault_share_mint"),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([(payer as anchor.Wallet).payer])
+      .rpc();
+
+    const secondVault = await program.account.vault.fetch(secondVaultPda);
+    expect(secondVault.vaultId.toNumber()).toBe(1);
+    expect(secondVault.owner.toString()).toBe(vaultOwnerPubKey.toString());
+    expect(secondVault.vaultShareMint.toString()).toBe(derive("vault_share_mint").toString());
+  });
+
   it("mints shares", async () => {
     const amount = new anchor.BN(500);
     const payAmount = new anchor.BN(50_000);
@@ -159,6 +213,8 @@ describe("Tokenized Yield Lifecycle", () => {
         investorShareAta: investorShareAta,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         tokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
@@ -197,6 +253,8 @@ describe("Tokenized Yield Lifecycle", () => {
         investorShareAta: investorShareAta,
         systemProgram: SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([buyer]);
 
@@ -214,7 +272,7 @@ describe("Tokenized Yield Lifecycle", () => {
       vault: vaultPda, vaultSigner: vaultSignerPda, payer: buyer.publicKey, payerAta: buyerPaymentAta,
       principalVault: principalVaultPda, revenueVault: revenueVaultPda, vaultShareMint: vaultShareMintPda,
       shareholder: shareholderPda, investorShareAta: investorShareAta, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-      tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID, paymentMint, shareTokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
     }).signers([buyer]).rpc();
 
     const shareholder = await program.account.userStake.fetch(shareholderPda);
@@ -231,7 +289,7 @@ describe("Tokenized Yield Lifecycle", () => {
       vault: vaultPda, vaultSigner: vaultSignerPda, payer: buyer.publicKey, payerAta: fakeAta,
       principalVault: principalVaultPda, revenueVault: revenueVaultPda, vaultShareMint: vaultShareMintPda,
       shareholder: shareholderPda, investorShareAta: investorShareAta, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-      tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID, paymentMint, shareTokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
     }).signers([buyer]);
 
     await expect(tx.rpc()).rejects.toThrow();
@@ -257,7 +315,7 @@ describe("Tokenized Yield Lifecycle", () => {
       vault: vaultPda, vaultSigner: vaultSignerPda, payer: buyer.publicKey, payerAta: buyerPaymentAta,
       principalVault: principalVaultPda, revenueVault: revenueVaultPda, vaultShareMint: vaultShareMintPda,
       shareholder: shareholderPda, investorShareAta: investorShareAta, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-      tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID, paymentMint, shareTokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
     }).signers([buyer]);
 
     await expect(tx.rpc()).rejects.toThrow("ExceedsTotalSupply");
@@ -275,7 +333,7 @@ describe("Tokenized Yield Lifecycle", () => {
       vault: vaultPda, vaultSigner: vaultSignerPda, payer: buyer.publicKey, payerAta: buyerPaymentAta,
       principalVault: principalVaultPda, revenueVault: revenueVaultPda, vaultShareMint: vaultShareMintPda,
       shareholder: shareholderPda, investorShareAta: investorShareAta, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-      tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID, paymentMint, shareTokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
     }).signers([buyer]).rpc();
 
     const endVaultBalance = (await provider.connection.getTokenAccountBalance(principalVaultPda)).value.amount;
@@ -295,7 +353,7 @@ describe("Tokenized Yield Lifecycle", () => {
       vault: vaultPda, vaultSigner: vaultSignerPda, payer: buyer2.publicKey, payerAta: buyer2PaymentAta,
       principalVault: principalVaultPda, revenueVault: revenueVaultPda, vaultShareMint: vaultShareMintPda,
       shareholder: shareholder2Pda, investorShareAta: investorShareAta2, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-      tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID, paymentMint, shareTokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
     }).signers([buyer2]).rpc();
 
     await assertVaultInvariant();
@@ -308,7 +366,7 @@ describe("Tokenized Yield Lifecycle", () => {
       vault: vaultPda, vaultSigner: vaultSignerPda, payer: buyer.publicKey, payerAta: buyerPaymentAta,
       principalVault: principalVaultPda, revenueVault: revenueVaultPda, vaultShareMint: vaultShareMintPda,
       shareholder: shareholderPda, investorShareAta: investorShareAta, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-      tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID, paymentMint, shareTokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
     }).signers([buyer]);
     await expect(tx.rpc()).rejects.toThrow("InvalidShareAmount");
   });
@@ -326,18 +384,20 @@ describe("Tokenized Yield Lifecycle", () => {
       vault: vaultPda, vaultSigner: vaultSignerPda, payer: user.publicKey, payerAta: userPaymentAta,
       principalVault: principalVaultPda, revenueVault: revenueVaultPda, vaultShareMint: vaultShareMintPda,
       shareholder: userShareholderPda, investorShareAta: userShareAta, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-      tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID, paymentMint, shareTokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
     }).signers([user]).rpc();
 
     await program.methods.depositRevenue(new anchor.BN(10_000)).accounts({
       vault: vaultPda, payer: (payer as anchor.Wallet).publicKey, payerAta: buyerPaymentAta,
       revenueVault: revenueVaultPda, treasury: treasuryPda, vaultSigner: vaultSignerPda, tokenProgram: TOKEN_PROGRAM_ID,
+      paymentMint,
     }).signers([(payer as anchor.Wallet).payer]).rpc();
 
     const initialBalance = (await provider.connection.getTokenAccountBalance(userPaymentAta)).value.amount;
     await program.methods.harvest().accounts({
       vault: vaultPda, vaultSigner: vaultSignerPda, payer: user.publicKey, shareholder: userShareholderPda,
       revenueVault: revenueVaultPda, userAta: userPaymentAta, tokenProgram: TOKEN_PROGRAM_ID,
+      paymentMint,
     }).signers([user]).rpc();
     const finalBalance = (await provider.connection.getTokenAccountBalance(userPaymentAta)).value.amount;
     expect(new anchor.BN(finalBalance).sub(new anchor.BN(initialBalance)).gt(new anchor.BN(0))).toBe(true);
@@ -349,6 +409,7 @@ describe("Tokenized Yield Lifecycle", () => {
     await program.methods.depositRevenue(hugeRevenue).accounts({
       vault: vaultPda, payer: (payer as anchor.Wallet).publicKey, payerAta: buyerPaymentAta,
       revenueVault: revenueVaultPda, treasury: treasuryPda, vaultSigner: vaultSignerPda, tokenProgram: TOKEN_PROGRAM_ID,
+      paymentMint,
     }).signers([(payer as anchor.Wallet).payer]).rpc();
   });
 
@@ -357,7 +418,53 @@ describe("Tokenized Yield Lifecycle", () => {
     await program.methods.harvest().accounts({
       vault: vaultPda, vaultSigner: vaultSignerPda, payer: buyer.publicKey, shareholder: buyerShareholderPda,
       revenueVault: revenueVaultPda, userAta: buyerPaymentAta, tokenProgram: TOKEN_PROGRAM_ID,
+      paymentMint,
+    }).signers([buyer]).rpc();
+  });
+
+  it("EVENTS: deposit_revenue emits RevenueDeposited with fee split", async () => {
+    const sig = await program.methods.depositRevenue(new anchor.BN(10_000)).accounts({
+      vault: vaultPda, payer: (payer as anchor.Wallet).publicKey, payerAta: buyerPaymentAta,
+      revenueVault: revenueVaultPda, treasury: treasuryPda, vaultSigner: vaultSignerPda, tokenProgram: TOKEN_PROGRAM_ID,
+      paymentMint,
+    }).signers([(payer as anchor.Wallet).payer]).rpc({ commitment: "confirmed" });
+
+    const tx = await provider.connection.getTransaction(sig, { commitment: "confirmed", maxSupportedTransactionVersion: 0 });
+    const parser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+    const events = [...parser.parseLogs(tx!.meta!.logMessages!)];
+    const deposited = events.find((e) => e.name === "revenueDeposited");
+
+    const vault = await program.account.vault.fetch(vaultPda);
+    expect(deposited).toBeDefined();
+    expect(deposited!.data.amount.toNumber()).toBe(10_000);
+    expect(deposited!.data.performanceFee.toNumber()).toBe(1_000);
+    expect(deposited!.data.distributableAmount.toNumber()).toBe(9_000);
+    expect(deposited!.data.accRewardPerShare.toString()).toBe(vault.accRewardPerShare.toString());
+  });
+
+  it("TRANSFER: transfer_shares moves quantity and keeps accrued rewards with the sender", async () => {
+    const recipient = anchor.web3.Keypair.generate();
+    const [buyerShareholderPda] = PublicKey.findProgramAddressSync([Buffer.from("shareholder"), vaultPda.toBuffer(), buyer.publicKey.toBuffer()], program.programId);
+    const [recipientShareholderPda] = PublicKey.findProgramAddressSync([Buffer.from("shareholder"), vaultPda.toBuffer(), recipient.publicKey.toBuffer()], program.programId);
+    const buyerShareAta = await anchor.utils.token.associatedAddress({ mint: vaultShareMintPda, owner: buyer.publicKey });
+    const recipientShareAta = await anchor.utils.token.associatedAddress({ mint: vaultShareMintPda, owner: recipient.publicKey });
+
+    const senderBefore = await program.account.userStake.fetch(buyerShareholderPda);
+
+    await program.methods.transferShares(new anchor.BN(100)).accounts({
+      vault: vaultPda, sender: buyer.publicKey, recipient: recipient.publicKey,
+      senderStake: buyerShareholderPda, recipientStake: recipientShareholderPda, vaultShareMint: vaultShareMintPda,
+      senderShareAta: buyerShareAta, recipientShareAta, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId, tokenProgram: TOKEN_PROGRAM_ID,
+      shareTokenProgram: TOKEN_PROGRAM_ID,
     }).signers([buyer]).rpc();
+
+    const senderAfter = await program.account.userStake.fetch(buyerShareholderPda);
+    const recipientStake = await program.account.userStake.fetch(recipientShareholderPda);
+    expect(senderAfter.quantity.toNumber()).toBe(senderBefore.quantity.toNumber() - 100);
+    expect(recipientStake.quantity.toNumber()).toBe(100);
+    expect(recipientStake.pendingRewards.toNumber()).toBe(0);
+    expect((await provider.connection.getTokenAccountBalance(recipientShareAta)).value.amount).toBe("100");
   });
 
   // --- REDEMPTION ENGINE TESTS ---
@@ -373,7 +480,7 @@ describe("Tokenized Yield Lifecycle", () => {
       vault: vaultPda, vaultSigner: vaultSignerPda, payer: user.publicKey, payerAta: userPaymentAta,
       principalVault: principalVaultPda, revenueVault: revenueVaultPda, vaultShareMint: vaultShareMintPda,
       shareholder: shareholderPda, investorShareAta: userShareAta, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-      tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID, paymentMint, shareTokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
     }).signers([user]).rpc();
 
     const startBalance = (await provider.connection.getTokenAccountBalance(userPaymentAta)).value.amount;
@@ -381,6 +488,8 @@ describe("Tokenized Yield Lifecycle", () => {
       vault: vaultPda, vaultSigner: vaultSignerPda, payer: user.publicKey, shareholder: shareholderPda,
       principalVault: principalVaultPda, revenueVault: revenueVaultPda, investorShareAta: userShareAta,
       vaultShareMint: vaultShareMintPda, payerAta: userPaymentAta, tokenProgram: TOKEN_PROGRAM_ID,
+      paymentMint,
+      shareTokenProgram: TOKEN_PROGRAM_ID,
     }).signers([user]).rpc();
 
     const endBalance = (await provider.connection.getTokenAccountBalance(userPaymentAta)).value.amount;
@@ -411,12 +520,13 @@ describe("Tokenized Yield Lifecycle", () => {
         vault: vaultPda, vaultSigner: vaultSignerPda, payer: userA.publicKey, payerAta: ataA,
         principalVault: principalVaultPda, revenueVault: revenueVaultPda, vaultShareMint: vaultShareMintPda,
         shareholder: pdaA, investorShareAta: shareAtaA, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID, paymentMint, shareTokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
       }).signers([userA]).rpc();
 
       await program.methods.depositRevenue(new anchor.BN(1000)).accounts({
         vault: vaultPda, payer: (payer as anchor.Wallet).publicKey, payerAta: buyerPaymentAta,
         revenueVault: revenueVaultPda, treasury: treasuryPda, vaultSigner: vaultSignerPda, tokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint,
       }).signers([(payer as anchor.Wallet).payer]).rpc();
 
       await provider.connection.confirmTransaction(await provider.connection.requestAirdrop(userB.publicKey, 1e9));
@@ -428,13 +538,14 @@ describe("Tokenized Yield Lifecycle", () => {
         vault: vaultPda, vaultSigner: vaultSignerPda, payer: userB.publicKey, payerAta: ataB,
         principalVault: principalVaultPda, revenueVault: revenueVaultPda, vaultShareMint: vaultShareMintPda,
         shareholder: pdaB, investorShareAta: shareAtaB, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID, paymentMint, shareTokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
       }).signers([userB]).rpc();
 
       const preA = (await provider.connection.getTokenAccountBalance(ataA)).value.amount;
       await program.methods.harvest().accounts({
         vault: vaultPda, vaultSigner: vaultSignerPda, payer: userA.publicKey, shareholder: pdaA,
         revenueVault: revenueVaultPda, userAta: ataA, tokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint,
       }).signers([userA]).rpc();
       const postA = (await provider.connection.getTokenAccountBalance(ataA)).value.amount;
       expect(new anchor.BN(postA).sub(new anchor.BN(preA)).gt(new anchor.BN(0))).toBe(true);
@@ -443,6 +554,7 @@ describe("Tokenized Yield Lifecycle", () => {
       await program.methods.harvest().accounts({
         vault: vaultPda, vaultSigner: vaultSignerPda, payer: userB.publicKey, shareholder: pdaB,
         revenueVault: revenueVaultPda, userAta: ataB, tokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint,
       }).signers([userB]).rpc();
       const postB = (await provider.connection.getTokenAccountBalance(ataB)).value.amount;
       expect(new anchor.BN(postB).sub(new anchor.BN(preB)).toNumber()).toBe(0);
@@ -454,11 +566,13 @@ describe("Tokenized Yield Lifecycle", () => {
       await program.methods.harvest().accounts({
         vault: vaultPda, vaultSigner: vaultSignerPda, payer: buyer.publicKey, shareholder: shareholderPda,
         revenueVault: revenueVaultPda, userAta: buyerPaymentAta, tokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint,
       }).signers([buyer]).rpc();
       const balanceInterim = (await provider.connection.getTokenAccountBalance(buyerPaymentAta)).value.amount;
       await program.methods.harvest().accounts({
         vault: vaultPda, vaultSigner: vaultSignerPda, payer: buyer.publicKey, shareholder: shareholderPda,
         revenueVault: revenueVaultPda, userAta: buyerPaymentAta, tokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint,
       }).signers([buyer]).rpc();
       const balanceFinal = (await provider.connection.getTokenAccountBalance(buyerPaymentAta)).value.amount;
       expect(balanceFinal).toBe(balanceInterim);
@@ -470,6 +584,7 @@ describe("Tokenized Yield Lifecycle", () => {
         await program.methods.depositRevenue(new anchor.BN(1)).accounts({
           vault: vaultPda, payer: (payer as anchor.Wallet).publicKey, payerAta: buyerPaymentAta,
           revenueVault: revenueVaultPda, treasury: treasuryPda, vaultSigner: vaultSignerPda, tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint,
         }).signers([(payer as anchor.Wallet).payer]).rpc();
       }
       await assertFullInvariant();
@@ -486,13 +601,15 @@ describe("Tokenized Yield Lifecycle", () => {
         vault: vaultPda, vaultSigner: vaultSignerPda, payer: user.publicKey, payerAta: userPaymentAta,
         principalVault: principalVaultPda, revenueVault: revenueVaultPda, vaultShareMint: vaultShareMintPda,
         shareholder: shareholderPda, investorShareAta: userShareAta, associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-        tokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID, paymentMint, shareTokenProgram: TOKEN_PROGRAM_ID, systemProgram: SystemProgram.programId,
       }).signers([user]).rpc();
       for (let i = 0; i < 5; i++) {
         await program.methods.redeemShares(new anchor.BN(1)).accounts({
           vault: vaultPda, vaultSigner: vaultSignerPda, payer: user.publicKey, shareholder: shareholderPda,
           principalVault: principalVaultPda, revenueVault: revenueVaultPda, investorShareAta: userShareAta,
           vaultShareMint: vaultShareMintPda, payerAta: userPaymentAta, tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
         }).signers([user]).rpc();
       }
       expect((await program.account.userStake.fetch(shareholderPda)).quantity.toNumber()).toBe(0);
@@ -531,7 +648,7 @@ describe("Performance Fee Layer", () => {
       );
 
       [feeVaultPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vault"), feeOwner.publicKey.toBuffer()],
+        [Buffer.from("vault"), feeOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
         program.programId
       );
 
@@ -609,7 +726,7 @@ describe("Performance Fee Layer", () => {
     it("FEE-1: Revenue deposit with 10% fee - treasury receives exact fee", async () => {
       // Initialize vault with 10% fee (1000 bps)
       await program.methods
-        .initializeVault("Fee Test Vault", new anchor.BN(1_000_000), new anchor.BN(100), 1000)
+        .initializeVault(new anchor.BN(0), "Fee Test Vault", new anchor.BN(1_000_000), new anchor.BN(100), 1000, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
         .accounts({
           owner: feeOwner.publicKey,
           vault: feeVaultPda,
@@ -621,6 +738,7 @@ describe("Performance Fee Layer", () => {
           vaultShareMint: feeVaultShareMintPda,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
@@ -651,6 +769,8 @@ describe("Performance Fee Layer", () => {
           investorShareAta: shareholderShareAta,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint: feePaymentMint,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([shareholder])
@@ -672,6 +792,7 @@ describe("Performance Fee Layer", () => {
           treasury: feeTreasuryPda,
           vaultSigner: feeVaultSignerPda,
           tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint: feePaymentMint,
         })
         .signers([(payer as anchor.Wallet).payer])
         .rpc();
@@ -711,6 +832,7 @@ describe("Performance Fee Layer", () => {
           revenueVault: feeRevenueVaultPda,
           userAta: shareholderPaymentAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint: feePaymentMint,
         })
         .signers([shareholder])
         .rpc();
@@ -739,6 +861,7 @@ describe("Performance Fee Layer", () => {
           treasury: feeTreasuryPda,
           vaultSigner: feeVaultSignerPda,
           tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint: feePaymentMint,
         })
         .signers([(payer as anchor.Wallet).payer])
         .rpc();
@@ -750,6 +873,94 @@ describe("Performance Fee Layer", () => {
       expect(vaultAfter.totalFeesCollected.toNumber()).toBe(totalFeesBefore + 500);
       expect(new anchor.BN(treasuryAfter).sub(new anchor.BN(treasuryBefore)).toNumber()).toBe(500);
     });
+
+    it("FEE-7: Authority withdraws fees, capped by total_fees_collected", async () => {
+      const vaultBefore = await program.account.vault.fetch(feeVaultPda);
+      const withdrawable = vaultBefore.totalFeesCollected.sub(vaultBefore.totalFeesWithdrawn);
+
+      const tooMuch = program.methods
+        .withdrawFees(withdrawable.addn(1))
+        .accounts({
+          vault: feeVaultPda,
+          authority: feeOwner.publicKey,
+          vaultSigner: feeVaultSignerPda,
+          treasury: feeTreasuryPda,
+          destination: depositorPaymentAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint: feePaymentMint,
+        })
+        .signers([feeOwner]);
+      await expect(tooMuch.rpc()).rejects.toThrow("InsufficientFees");
+
+      await program.methods
+        .withdrawFees(new anchor.BN(500))
+        .accounts({
+          vault: feeVaultPda,
+          authority: feeOwner.publicKey,
+          vaultSigner: feeVaultSignerPda,
+          treasury: feeTreasuryPda,
+          destination: depositorPaymentAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint: feePaymentMint,
+        })
+        .signers([feeOwner])
+        .rpc();
+
+      const vaultAfter = await program.account.vault.fetch(feeVaultPda);
+      expect(vaultAfter.totalFeesWithdrawn.toNumber()).toBe(vaultBefore.totalFeesWithdrawn.toNumber() + 500);
+    });
+
+    it("FEE-8: Permissionless sweep pays only the registered fee recipient", async () => {
+      const cranker = anchor.web3.Keypair.generate();
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(cranker.publicKey, 1e9)
+      );
+      const recipientAta = await createAccount(
+        provider.connection,
+        (payer as anchor.Wallet).payer,
+        feePaymentMint,
+        feeOwner.publicKey
+      );
+
+      await program.methods
+        .setFeeRecipient()
+        .accounts({ vault: feeVaultPda, authority: feeOwner.publicKey, feeRecipient: recipientAta })
+        .signers([feeOwner])
+        .rpc();
+
+      const wrongRecipient = program.methods
+        .sweepFees()
+        .accounts({
+          vault: feeVaultPda,
+          cranker: cranker.publicKey,
+          vaultSigner: feeVaultSignerPda,
+          treasury: feeTreasuryPda,
+          feeRecipient: depositorPaymentAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint: feePaymentMint,
+        })
+        .signers([cranker]);
+      await expect(wrongRecipient.rpc()).rejects.toThrow("InvalidFeeRecipient");
+
+      await program.methods
+        .sweepFees()
+        .accounts({
+          vault: feeVaultPda,
+          cranker: cranker.publicKey,
+          vaultSigner: feeVaultSignerPda,
+          treasury: feeTreasuryPda,
+          feeRecipient: recipientAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint: feePaymentMint,
+        })
+        .signers([cranker])
+        .rpc();
+
+      const vault = await program.account.vault.fetch(feeVaultPda);
+      expect(vault.totalFeesWithdrawn.toString()).toBe(vault.totalFeesCollected.toString());
+      const treasuryBalance = (await provider.connection.getTokenAccountBalance(feeTreasuryPda)).value.amount;
+      expect(treasuryBalance).toBe("0");
+    });
   });
 
   describe("Zero Fee Vault", () => {
@@ -772,7 +983,7 @@ describe("Performance Fee Layer", () => {
       );
 
       [zeroFeeVaultPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vault"), zeroFeeOwner.publicKey.toBuffer()],
+        [Buffer.from("vault"), zeroFeeOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
         program.programId
       );
 
@@ -850,7 +1061,7 @@ describe("Performance Fee Layer", () => {
     it("FEE-4: 0% fee works - full amount goes to shareholders", async () => {
       // Initialize vault with 0% fee
       await program.methods
-        .initializeVault("Zero Fee Vault", new anchor.BN(1_000_000), new anchor.BN(100), 0)
+        .initializeVault(new anchor.BN(0), "Zero Fee Vault", new anchor.BN(1_000_000), new anchor.BN(100), 0, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
         .accounts({
           owner: zeroFeeOwner.publicKey,
           vault: zeroFeeVaultPda,
@@ -862,6 +1073,7 @@ describe("Performance Fee Layer", () => {
           vaultShareMint: zeroFeeVaultShareMintPda,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
@@ -896,6 +1108,8 @@ describe("Performance Fee Layer", () => {
           investorShareAta: shareholderShareAta,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint: zeroFeePaymentMint,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([zeroFeeShareholder])
@@ -916,6 +1130,7 @@ describe("Performance Fee Layer", () => {
           treasury: zeroFeeTreasuryPda,
           vaultSigner: zeroFeeVaultSignerPda,
           tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint: zeroFeePaymentMint,
         })
         .signers([(payer as anchor.Wallet).payer])
         .rpc();
@@ -945,6 +1160,7 @@ describe("Performance Fee Layer", () => {
           revenueVault: zeroFeeRevenueVaultPda,
           userAta: zeroFeeShareholderPaymentAta,
           tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint: zeroFeePaymentMint,
         })
         .signers([zeroFeeShareholder])
         .rpc();
@@ -962,7 +1178,7 @@ describe("Performance Fee Layer", () => {
       );
 
       const [invalidVaultPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vault"), invalidFeeOwner.publicKey.toBuffer()],
+        [Buffer.from("vault"), invalidFeeOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
         program.programId
       );
 
@@ -1001,7 +1217,7 @@ describe("Performance Fee Layer", () => {
 
       // Try to initialize with 21% fee (2100 bps) - should fail
       const tx = program.methods
-        .initializeVault("Invalid Fee Vault", new anchor.BN(1_000_000), new anchor.BN(100), 2100)
+        .initializeVault(new anchor.BN(0), "Invalid Fee Vault", new anchor.BN(1_000_000), new anchor.BN(100), 2100, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
         .accounts({
           owner: invalidFeeOwner.publicKey,
           vault: invalidVaultPda,
@@ -1013,6 +1229,7 @@ describe("Performance Fee Layer", () => {
           vaultShareMint: invalidVaultShareMintPda,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
@@ -1028,7 +1245,7 @@ describe("Performance Fee Layer", () => {
       );
 
       const [maxFeeVaultPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("vault"), maxFeeOwner.publicKey.toBuffer()],
+        [Buffer.from("vault"), maxFeeOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
         program.programId
       );
 
@@ -1067,7 +1284,7 @@ describe("Performance Fee Layer", () => {
 
       // Initialize with exactly 20% fee (2000 bps) - should succeed
       await program.methods
-        .initializeVault("Max Fee Vault", new anchor.BN(1_000_000), new anchor.BN(100), 2000)
+        .initializeVault(new anchor.BN(0), "Max Fee Vault", new anchor.BN(1_000_000), new anchor.BN(100), 2000, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
         .accounts({
           owner: maxFeeOwner.publicKey,
           vault: maxFeeVaultPda,
@@ -1079,6 +1296,7 @@ describe("Performance Fee Layer", () => {
           vaultShareMint: maxFeeVaultShareMintPda,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         })
@@ -1123,7 +1341,7 @@ describe("Governance Controls", () => {
     );
 
     [govVaultPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vault"), govOwner.publicKey.toBuffer()],
+      [Buffer.from("vault"), govOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
       program.programId
     );
 
@@ -1162,7 +1380,7 @@ describe("Governance Controls", () => {
 
     // Initialize the governance test vault
     await program.methods
-      .initializeVault("Governance Test Vault", new anchor.BN(1_000_000), new anchor.BN(100), 500)
+      .initializeVault(new anchor.BN(0), "Governance Test Vault", new anchor.BN(1_000_000), new anchor.BN(100), 500, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
       .accounts({
         owner: govOwner.publicKey,
         vault: govVaultPda,
@@ -1174,6 +1392,7 @@ describe("Governance Controls", () => {
         vaultShareMint: govVaultShareMintPda,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
       })
@@ -1193,9 +1412,9 @@ describe("Governance Controls", () => {
     await expect(tx.rpc()).rejects.toThrow("Unauthorized");
   });
 
-  it("GOV-2: Authorized fee update succeeds", async () => {
+  it("GOV-2: Authorized fee decrease succeeds immediately", async () => {
     await program.methods
-      .updatePerformanceFee(1500)
+      .updatePerformanceFee(400)
       .accounts({
         vault: govVaultPda,
         authority: govOwner.publicKey,
@@ -1204,7 +1423,7 @@ describe("Governance Controls", () => {
       .rpc();
 
     const vault = await program.account.vault.fetch(govVaultPda);
-    expect(vault.performanceFeeBps).toBe(1500);
+    expect(vault.performanceFeeBps).toBe(400);
   });
 
   it("GOV-3: Fee above 2000 bps fails", async () => {
@@ -1225,9 +1444,9 @@ describe("Governance Controls", () => {
       await provider.connection.requestAirdrop(newAuthority.publicKey, 1e9)
     );
 
-    // Transfer authority
+    // Propose new authority - nothing changes until it accepts
     await program.methods
-      .transferAuthority(newAuthority.publicKey)
+      .proposeAuthority(newAuthority.publicKey)
       .accounts({
         vault: govVaultPda,
         authority: govOwner.publicKey,
@@ -1235,13 +1454,38 @@ describe("Governance Controls", () => {
       .signers([govOwner])
       .rpc();
 
-    // Verify new authority
     let vault = await program.account.vault.fetch(govVaultPda);
+    expect(vault.authority.toString()).toBe(govOwner.publicKey.toString());
+    expect(vault.pendingAuthority.toString()).toBe(newAuthority.publicKey.toString());
+
+    // Only the proposed key can accept
+    const wrongAcceptTx = program.methods
+      .acceptAuthority()
+      .accounts({
+        vault: govVaultPda,
+        newAuthority: unauthorizedUser.publicKey,
+      })
+      .signers([unauthorizedUser]);
+
+    await expect(wrongAcceptTx.rpc()).rejects.toThrow("Unauthorized");
+
+    await program.methods
+      .acceptAuthority()
+      .accounts({
+        vault: govVaultPda,
+        newAuthority: newAuthority.publicKey,
+      })
+      .signers([newAuthority])
+      .rpc();
+
+    // Verify new authority
+    vault = await program.account.vault.fetch(govVaultPda);
     expect(vault.authority.toString()).toBe(newAuthority.publicKey.toString());
+    expect(vault.pendingAuthority.toString()).toBe(PublicKey.default.toString());
 
     // Old authority should fail
     const oldAuthorityTx = program.methods
-      .updatePerformanceFee(1000)
+      .updatePerformanceFee(300)
       .accounts({
         vault: govVaultPda,
         authority: govOwner.publicKey,
@@ -1252,7 +1496,7 @@ describe("Governance Controls", () => {
 
     // New authority should succeed
     await program.methods
-      .updatePerformanceFee(1000)
+      .updatePerformanceFee(300)
       .accounts({
         vault: govVaultPda,
         authority: newAuthority.publicKey,
@@ -1261,17 +1505,25 @@ describe("Governance Controls", () => {
       .rpc();
 
     vault = await program.account.vault.fetch(govVaultPda);
-    expect(vault.performanceFeeBps).toBe(1000);
+    expect(vault.performanceFeeBps).toBe(300);
 
     // Transfer back for remaining tests
     await program.methods
-      .transferAuthority(govOwner.publicKey)
+      .proposeAuthority(govOwner.publicKey)
       .accounts({
         vault: govVaultPda,
         authority: newAuthority.publicKey,
       })
       .signers([newAuthority])
       .rpc();
+    await program.methods
+      .acceptAuthority()
+      .accounts({
+        vault: govVaultPda,
+        newAuthority: govOwner.publicKey,
+      })
+      .signers([govOwner])
+      .rpc();
   });
 
   it("GOV-5: After revoke -> all governance calls fail", async () => {
@@ -1282,7 +1534,7 @@ describe("Governance Controls", () => {
     );
 
     const [revokeVaultPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vault"), revokeOwner.publicKey.toBuffer()],
+      [Buffer.from("vault"), revokeOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
       program.programId
     );
 
@@ -1321,7 +1573,7 @@ describe("Governance Controls", () => {
 
     // Initialize vault
     await program.methods
-      .initializeVault("Revoke Test Vault", new anchor.BN(1_000_000), new anchor.BN(100), 500)
+      .initializeVault(new anchor.BN(0), "Revoke Test Vault", new anchor.BN(1_000_000), new anchor.BN(100), 500, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
       .accounts({
         owner: revokeOwner.publicKey,
         vault: revokeVaultPda,
@@ -1333,6 +1585,7 @@ describe("Governance Controls", () => {
         vaultShareMint: revokeVaultShareMintPda,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
       })
@@ -1343,6 +1596,17 @@ describe("Governance Controls", () => {
     let vault = await program.account.vault.fetch(revokeVaultPda);
     expect(vault.authority.toString()).toBe(revokeOwner.publicKey.toString());
 
+    // Appoint a guardian before giving up governance
+    const revokeGuardian = anchor.web3.Keypair.generate();
+    await program.methods
+      .setGuardian(revokeGuardian.publicKey)
+      .accounts({
+        vault: revokeVaultPda,
+        authority: revokeOwner.publicKey,
+      })
+      .signers([revokeOwner])
+      .rpc();
+
     // Revoke authority
     await program.methods
       .revokeAuthority()
@@ -1370,9 +1634,9 @@ describe("Governance Controls", () => {
 
     await expect(feeTx.rpc()).rejects.toThrow();
 
-    // transfer_authority should fail
+    // propose_authority should fail
     const transferTx = program.methods
-      .transferAuthority(revokeOwner.publicKey)
+      .proposeAuthority(revokeOwner.publicKey)
       .accounts({
         vault: revokeVaultPda,
         authority: revokeOwner.publicKey,
@@ -1391,11 +1655,24 @@ describe("Governance Controls", () => {
       .signers([revokeOwner]);
 
     await expect(revokeTx.rpc()).rejects.toThrow();
+
+    // The guardian can still pause after revocation
+    await program.methods
+      .pause(0x1f)
+      .accounts({
+        vault: revokeVaultPda,
+        pauser: revokeGuardian.publicKey,
+      })
+      .signers([revokeGuardian])
+      .rpc();
+
+    vault = await program.account.vault.fetch(revokeVaultPda);
+    expect(vault.pausedInstructions).toBe(0x1f);
   });
 
   it("GOV-6: Cannot transfer authority to zero address", async () => {
     const tx = program.methods
-      .transferAuthority(PublicKey.default)
+      .proposeAuthority(PublicKey.default)
       .accounts({
         vault: govVaultPda,
         authority: govOwner.publicKey,
@@ -1404,6 +1681,3327 @@ describe("Governance Controls", () => {
 
     await expect(tx.rpc()).rejects.toThrow("InvalidAuthority");
   });
+
+  it("GOV-7: Guardian pauses instructions, only authority unpauses", async () => {
+    const guardian = anchor.web3.Keypair.generate();
+    const PAUSE_MINT = 1 << 0;
+    const PAUSE_HARVEST = 1 << 2;
+
+    await program.methods
+      .setGuardian(guardian.publicKey)
+      .accounts({ vault: govVaultPda, authority: govOwner.publicKey })
+      .signers([govOwner])
+      .rpc();
+
+    const strangerPause = program.methods
+      .pause(PAUSE_MINT)
+      .accounts({ vault: govVaultPda, pauser: unauthorizedUser.publicKey })
+      .signers([unauthorizedUser]);
+    await expect(strangerPause.rpc()).rejects.toThrow("Unauthorized");
+
+    await program.methods
+      .pause(PAUSE_MINT | PAUSE_HARVEST)
+      .accounts({ vault: govVaultPda, pauser: guardian.publicKey })
+      .signers([guardian])
+      .rpc();
+
+    let vault = await program.account.vault.fetch(govVaultPda);
+    expect(vault.pausedInstructions).toBe(PAUSE_MINT | PAUSE_HARVEST);
+
+    // Paused mint is rejected before any tokens move
+    const buyerPaymentAta = await createAccount(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      govPaymentMint,
+      unauthorizedUser.publicKey
+    );
+    const [buyerShareholderPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder"), govVaultPda.toBuffer(), unauthorizedUser.publicKey.toBuffer()],
+      program.programId
+    );
+    const pausedMint = program.methods
+      .mintShares(new anchor.BN(1))
+      .accounts({
+        vault: govVaultPda,
+        vaultSigner: govVaultSignerPda,
+        payer: unauthorizedUser.publicKey,
+        payerAta: buyerPaymentAta,
+        principalVault: govPrincipalVaultPda,
+        revenueVault: govRevenueVaultPda,
+        vaultShareMint: govVaultShareMintPda,
+        investorShareAta: getAssociatedTokenAddressSync(govVaultShareMintPda, unauthorizedUser.publicKey),
+        shareholder: buyerShareholderPda,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        paymentMint: govPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([unauthorizedUser]);
+    await expect(pausedMint.rpc()).rejects.toThrow("InstructionPaused");
+
+    // The guardian cannot unpause
+    const guardianUnpause = program.methods
+      .unpause(PAUSE_MINT)
+      .accounts({ vault: govVaultPda, authority: guardian.publicKey })
+      .signers([guardian]);
+    await expect(guardianUnpause.rpc()).rejects.toThrow("Unauthorized");
+
+    await program.methods
+      .unpause(PAUSE_MINT)
+      .accounts({ vault: govVaultPda, authority: govOwner.publicKey })
+      .signers([govOwner])
+      .rpc();
+
+    vault = await program.account.vault.fetch(govVaultPda);
+    expect(vault.pausedInstructions).toBe(PAUSE_HARVEST);
+  });
+
+  it("GOV-8: Pending authority can be cancelled before acceptance", async () => {
+    const typo = anchor.web3.Keypair.generate();
+
+    await program.methods
+      .proposeAuthority(typo.publicKey)
+      .accounts({ vault: govVaultPda, authority: govOwner.publicKey })
+      .signers([govOwner])
+      .rpc();
+
+    await program.methods
+      .cancelPendingAuthority()
+      .accounts({ vault: govVaultPda, authority: govOwner.publicKey })
+      .signers([govOwner])
+      .rpc();
+
+    const vault = await program.account.vault.fetch(govVaultPda);
+    expect(vault.authority.toString()).toBe(govOwner.publicKey.toString());
+    expect(vault.pendingAuthority.toString()).toBe(PublicKey.default.toString());
+
+    const lateAccept = program.methods
+      .acceptAuthority()
+      .accounts({ vault: govVaultPda, newAuthority: typo.publicKey })
+      .signers([typo]);
+    await expect(lateAccept.rpc()).rejects.toThrow();
+
+    const secondCancel = program.methods
+      .cancelPendingAuthority()
+      .accounts({ vault: govVaultPda, authority: govOwner.publicKey })
+      .signers([govOwner]);
+    await expect(secondCancel.rpc()).rejects.toThrow("NoPendingAuthority");
+  });
+
+  it("GOV-9: Fee increases are timelocked behind a cancellable proposal", async () => {
+    // Immediate increase is refused
+    const directIncrease = program.methods
+      .updatePerformanceFee(1500)
+      .accounts({ vault: govVaultPda, authority: govOwner.publicKey })
+      .signers([govOwner]);
+    await expect(directIncrease.rpc()).rejects.toThrow("FeeIncreaseRequiresTimelock");
+
+    let vault = await program.account.vault.fetch(govVaultPda);
+    const [proposalPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("proposal"), govVaultPda.toBuffer(), vault.proposalCount.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .proposeGovernanceChange({ updatePerformanceFee: { newFeeBps: 1500 } })
+      .accounts({
+        vault: govVaultPda,
+        authority: govOwner.publicKey,
+        proposal: proposalPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([govOwner])
+      .rpc();
+
+    const proposal = await program.account.governanceProposal.fetch(proposalPda);
+    expect(proposal.status).toEqual({ queued: {} });
+    expect(proposal.eta.sub(proposal.createdAt).toNumber()).toBe(vault.governanceDelay.toNumber());
+
+    // Executing before the eta fails
+    const earlyExecute = program.methods
+      .executeGovernanceChange()
+      .accounts({
+        vault: govVaultPda,
+        proposal: proposalPda,
+        executor: unauthorizedUser.publicKey,
+        newTreasury: null,
+        vaultSigner: govVaultSignerPda,
+      })
+      .signers([unauthorizedUser]);
+    await expect(earlyExecute.rpc()).rejects.toThrow("TimelockNotElapsed");
+
+    await program.methods
+      .cancelGovernanceChange()
+      .accounts({ vault: govVaultPda, authority: govOwner.publicKey, proposal: proposalPda })
+      .signers([govOwner])
+      .rpc();
+
+    await expect(earlyExecute.rpc()).rejects.toThrow("ProposalNotQueued");
+
+    vault = await program.account.vault.fetch(govVaultPda);
+    expect(vault.performanceFeeBps).toBe(300);
+  });
+});
+
+// =============================================================================
+// TOKEN-2022 TRANSFER HOOK TESTS
+// =============================================================================
+describe("Token-2022 Transfer Hook", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.TokenizedYieldInfrastructure as Program<TokenizedYieldInfrastructure>;
+
+  const payer = provider.wallet;
+
+  let hookVaultPda: PublicKey;
+  let hookVaultSignerPda: PublicKey;
+  let hookShareMintPda: PublicKey;
+  let hookPrincipalVaultPda: PublicKey;
+  let hookRevenueVaultPda: PublicKey;
+  let hookTreasuryPda: PublicKey;
+  let hookPaymentMint: PublicKey;
+  let hookOwner: anchor.web3.Keypair;
+  let seller: anchor.web3.Keypair;
+  let sellerPaymentAta: PublicKey;
+  let buyerWallet: anchor.web3.Keypair;
+
+  const stakePda = (owner: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder"), hookVaultPda.toBuffer(), owner.toBuffer()],
+      program.programId
+    )[0];
+
+  beforeAll(async () => {
+    hookOwner = anchor.web3.Keypair.generate();
+    seller = anchor.web3.Keypair.generate();
+    buyerWallet = anchor.web3.Keypair.generate();
+    for (const kp of [hookOwner, seller, buyerWallet]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(kp.publicKey, 5e9)
+      );
+    }
+
+    [hookVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), hookOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    [hookVaultSignerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_signer"), hookVaultPda.toBuffer()],
+      program.programId
+    );
+    [hookShareMintPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_share_mint"), hookVaultPda.toBuffer()],
+      program.programId
+    );
+    [hookPrincipalVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("principal-vault"), hookVaultPda.toBuffer()],
+      program.programId
+    );
+    [hookRevenueVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("revenue-vault"), hookVaultPda.toBuffer()],
+      program.programId
+    );
+    [hookTreasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury"), hookVaultPda.toBuffer()],
+      program.programId
+    );
+
+    hookPaymentMint = await createMint(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      (payer as anchor.Wallet).publicKey,
+      null,
+      6
+    );
+    sellerPaymentAta = await createAccount(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      hookPaymentMint,
+      seller.publicKey
+    );
+    await mintTo(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      hookPaymentMint,
+      sellerPaymentAta,
+      (payer as anchor.Wallet).publicKey,
+      1_000_000
+    );
+
+    await program.methods
+      .initializeVaultWithTransferHook(new anchor.BN(0), "Hook Vault", new anchor.BN(1_000_000), new anchor.BN(100), 1000, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: hookOwner.publicKey,
+        vault: hookVaultPda,
+        vaultShareMint: hookShareMintPda,
+        vaultSigner: hookVaultSignerPda,
+        principalVault: hookPrincipalVaultPda,
+        paymentMint: hookPaymentMint,
+        revenueVault: hookRevenueVaultPda,
+        treasury: hookTreasuryPda,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([hookOwner])
+      .rpc();
+
+    const [extraAccountMetaList] = PublicKey.findProgramAddressSync(
+      [Buffer.from("extra-account-metas"), hookShareMintPda.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .initializeExtraAccountMetaList()
+      .accounts({
+        payer: hookOwner.publicKey,
+        vault: hookVaultPda,
+        vaultShareMint: hookShareMintPda,
+        extraAccountMetaList,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([hookOwner])
+      .rpc();
+
+    await program.methods
+      .mintShares(new anchor.BN(1_000))
+      .accounts({
+        vault: hookVaultPda,
+        vaultSigner: hookVaultSignerPda,
+        payer: seller.publicKey,
+        payerAta: sellerPaymentAta,
+        principalVault: hookPrincipalVaultPda,
+        revenueVault: hookRevenueVaultPda,
+        vaultShareMint: hookShareMintPda,
+        investorShareAta: getAssociatedTokenAddressSync(hookShareMintPda, seller.publicKey, false, TOKEN_2022_PROGRAM_ID),
+        shareholder: stakePda(seller.publicKey),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint: hookPaymentMint,
+        shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([seller])
+      .rpc();
+  });
+
+  it("HOOK-1: Plain Token-2022 transfer moves UserStake quantity", async () => {
+    await program.methods
+      .initializeShareholder()
+      .accounts({
+        vault: hookVaultPda,
+        payer: buyerWallet.publicKey,
+        holder: buyerWallet.publicKey,
+        shareholder: stakePda(buyerWallet.publicKey),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyerWallet])
+      .rpc();
+
+    const sellerShareAta = getAssociatedTokenAddressSync(hookShareMintPda, seller.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const buyerShareAta = getAssociatedTokenAddressSync(hookShareMintPda, buyerWallet.publicKey, false, TOKEN_2022_PROGRAM_ID);
+
+    const tx = new anchor.web3.Transaction().add(
+      createAssociatedTokenAccountIdempotentInstruction(
+        buyerWallet.publicKey,
+        buyerShareAta,
+        buyerWallet.publicKey,
+        hookShareMintPda,
+        TOKEN_2022_PROGRAM_ID
+      ),
+      await createTransferCheckedWithTransferHookInstruction(
+        provider.connection,
+        sellerShareAta,
+        hookShareMintPda,
+        buyerShareAta,
+        seller.publicKey,
+        BigInt(400),
+        6,
+        [],
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+    await anchor.web3.sendAndConfirmTransaction(provider.connection, tx, [buyerWallet, seller]);
+
+    const sellerStake = await program.account.userStake.fetch(stakePda(seller.publicKey));
+    const buyerStake = await program.account.userStake.fetch(stakePda(buyerWallet.publicKey));
+    expect(sellerStake.quantity.toNumber()).toBe(600);
+    expect(buyerStake.quantity.toNumber()).toBe(400);
+  });
+
+  it("HOOK-2: transfer_shares is rejected for hooked vaults", async () => {
+    const tx = program.methods
+      .transferShares(new anchor.BN(1))
+      .accounts({
+        vault: hookVaultPda,
+        sender: seller.publicKey,
+        recipient: buyerWallet.publicKey,
+        senderStake: stakePda(seller.publicKey),
+        recipientStake: stakePda(buyerWallet.publicKey),
+        vaultShareMint: hookShareMintPda,
+        senderShareAta: getAssociatedTokenAddressSync(hookShareMintPda, seller.publicKey, false, TOKEN_2022_PROGRAM_ID),
+        recipientShareAta: getAssociatedTokenAddressSync(hookShareMintPda, buyerWallet.publicKey, false, TOKEN_2022_PROGRAM_ID),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([seller]);
+
+    await expect(tx.rpc()).rejects.toThrow("TransferHookManaged");
+  });
+
+  it("HOOK-3: Compliance can force-transfer a position and leaves a record", async () => {
+    const recoveryWallet = anchor.web3.Keypair.generate();
+    const [record] = PublicKey.findProgramAddressSync(
+      [Buffer.from("forced_transfer"), hookVaultPda.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    const reference = Array.from(Buffer.alloc(32, 7));
+
+    await program.methods
+      .forceTransferShares(new anchor.BN(250), 1, reference)
+      .accounts({
+        vault: hookVaultPda,
+        officer: hookOwner.publicKey,
+        fromHolder: seller.publicKey,
+        toHolder: recoveryWallet.publicKey,
+        fromStake: stakePda(seller.publicKey),
+        toStake: stakePda(recoveryWallet.publicKey),
+        fromShareAccount: getAssociatedTokenAddressSync(hookShareMintPda, seller.publicKey, false, TOKEN_2022_PROGRAM_ID),
+        toShareAta: getAssociatedTokenAddressSync(hookShareMintPda, recoveryWallet.publicKey, false, TOKEN_2022_PROGRAM_ID),
+        vaultShareMint: hookShareMintPda,
+        vaultSigner: hookVaultSignerPda,
+        record,
+        shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([hookOwner])
+      .rpc();
+
+    const sellerStake = await program.account.userStake.fetch(stakePda(seller.publicKey));
+    const recoveryStake = await program.account.userStake.fetch(stakePda(recoveryWallet.publicKey));
+    expect(sellerStake.quantity.toNumber()).toBe(350);
+    expect(recoveryStake.quantity.toNumber()).toBe(250);
+
+    const recoveryAta = await getAccount(
+      provider.connection,
+      getAssociatedTokenAddressSync(hookShareMintPda, recoveryWallet.publicKey, false, TOKEN_2022_PROGRAM_ID),
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    expect(Number(recoveryAta.amount)).toBe(250);
+
+    const rec = await program.account.forcedTransferRecord.fetch(record);
+    expect(rec.from.toBase58()).toBe(seller.publicKey.toBase58());
+    expect(rec.to.toBase58()).toBe(recoveryWallet.publicKey.toBase58());
+    expect(rec.amount.toNumber()).toBe(250);
+    expect(rec.reason).toBe(1);
+
+    const vault = await program.account.vault.fetch(hookVaultPda);
+    expect(vault.forcedTransferCount.toNumber()).toBe(1);
+    expect(vault.mintedShares.toNumber()).toBe(1_000);
+  });
+});
+
+// =============================================================================
+// TOKEN-2022 PAYMENT MINT TESTS
+// =============================================================================
+describe("Token-2022 Payment Mint", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.TokenizedYieldInfrastructure as Program<TokenizedYieldInfrastructure>;
+
+  const payer = provider.wallet;
+  const FEE_BPS = 100; // 1% transfer fee
+
+  let t22VaultPda: PublicKey;
+  let t22VaultSignerPda: PublicKey;
+  let t22ShareMintPda: PublicKey;
+  let t22PrincipalVaultPda: PublicKey;
+  let t22RevenueVaultPda: PublicKey;
+  let t22TreasuryPda: PublicKey;
+  let t22PaymentMint: PublicKey;
+  let t22Owner: anchor.web3.Keypair;
+  let investor: anchor.web3.Keypair;
+  let investorPaymentAta: PublicKey;
+
+  beforeAll(async () => {
+    t22Owner = anchor.web3.Keypair.generate();
+    investor = anchor.web3.Keypair.generate();
+    for (const kp of [t22Owner, investor]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(kp.publicKey, 5e9)
+      );
+    }
+
+    // Token-2022 payment mint with a 1% transfer fee
+    const mintKeypair = anchor.web3.Keypair.generate();
+    t22PaymentMint = mintKeypair.publicKey;
+    const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+    const lamports = await provider.connection.getMinimumBalanceForRentExemption(mintLen);
+    const createMintTx = new anchor.web3.Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: payer.publicKey,
+        newAccountPubkey: t22PaymentMint,
+        space: mintLen,
+        lamports,
+        programId: TOKEN_2022_PROGRAM_ID,
+      }),
+      createInitializeTransferFeeConfigInstruction(
+        t22PaymentMint,
+        payer.publicKey,
+        payer.publicKey,
+        FEE_BPS,
+        BigInt(1_000_000_000),
+        TOKEN_2022_PROGRAM_ID
+      ),
+      createInitializeMintInstruction(t22PaymentMint, 6, payer.publicKey, null, TOKEN_2022_PROGRAM_ID)
+    );
+    await anchor.web3.sendAndConfirmTransaction(provider.connection, createMintTx, [
+      (payer as anchor.Wallet).payer,
+      mintKeypair,
+    ]);
+
+    investorPaymentAta = await createAccount(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      t22PaymentMint,
+      investor.publicKey,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    await mintTo(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      t22PaymentMint,
+      investorPaymentAta,
+      payer.publicKey,
+      10_000_000,
+      [],
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    [t22VaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), t22Owner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    [t22VaultSignerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_signer"), t22VaultPda.toBuffer()],
+      program.programId
+    );
+    [t22ShareMintPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_share_mint"), t22VaultPda.toBuffer()],
+      program.programId
+    );
+    [t22PrincipalVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("principal-vault"), t22VaultPda.toBuffer()],
+      program.programId
+    );
+    [t22RevenueVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("revenue-vault"), t22VaultPda.toBuffer()],
+      program.programId
+    );
+    [t22TreasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury"), t22VaultPda.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeVault(new anchor.BN(0), "Token-2022 Vault", new anchor.BN(1_000_000), new anchor.BN(100), 1000, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: t22Owner.publicKey,
+        vault: t22VaultPda,
+        vaultSigner: t22VaultSignerPda,
+        paymentMint: t22PaymentMint,
+        principalVault: t22PrincipalVaultPda,
+        revenueVault: t22RevenueVaultPda,
+        treasury: t22TreasuryPda,
+        vaultShareMint: t22ShareMintPda,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([t22Owner])
+      .rpc();
+  });
+
+  it("T22-1: Principal vault is credited exactly amount * price_per_share", async () => {
+    const [shareholderPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder"), t22VaultPda.toBuffer(), investor.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .mintShares(new anchor.BN(1_000))
+      .accounts({
+        vault: t22VaultPda,
+        vaultSigner: t22VaultSignerPda,
+        payer: investor.publicKey,
+        payerAta: investorPaymentAta,
+        principalVault: t22PrincipalVaultPda,
+        revenueVault: t22RevenueVaultPda,
+        vaultShareMint: t22ShareMintPda,
+        investorShareAta: getAssociatedTokenAddressSync(t22ShareMintPda, investor.publicKey),
+        shareholder: shareholderPda,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        paymentMint: t22PaymentMint,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor])
+      .rpc();
+
+    const principal = (await provider.connection.getTokenAccountBalance(t22PrincipalVaultPda)).value.amount;
+    expect(principal).toBe("100000");
+  });
+
+  it("T22-2: Revenue accounting books only what the vault received", async () => {
+    await program.methods
+      .depositRevenue(new anchor.BN(100_000))
+      .accounts({
+        vault: t22VaultPda,
+        payer: investor.publicKey,
+        payerAta: investorPaymentAta,
+        revenueVault: t22RevenueVaultPda,
+        treasury: t22TreasuryPda,
+        vaultSigner: t22VaultSignerPda,
+        paymentMint: t22PaymentMint,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([investor])
+      .rpc();
+
+    const vault = await program.account.vault.fetch(t22VaultPda);
+    // 10% performance fee = 10,000 sent, 1% transfer fee withheld => 9,900 booked
+    expect(vault.totalFeesCollected.toNumber()).toBe(9_900);
+
+    const revenueAccount = await getAccount(provider.connection, t22RevenueVaultPda, undefined, TOKEN_2022_PROGRAM_ID);
+    const withheld = getTransferFeeAmount(revenueAccount)!.withheldAmount;
+    // 90,000 sent, 900 withheld in the revenue vault
+    expect(Number(revenueAccount.amount)).toBe(89_100);
+    expect(Number(withheld)).toBe(900);
+  });
+});
+
+// =============================================================================
+// NAV PRICING TESTS
+// =============================================================================
+describe("NAV Pricing", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.TokenizedYieldInfrastructure as Program<TokenizedYieldInfrastructure>;
+
+  const payer = provider.wallet;
+
+  let navVaultPda: PublicKey;
+  let navVaultSignerPda: PublicKey;
+  let navShareMintPda: PublicKey;
+  let navPrincipalVaultPda: PublicKey;
+  let navRevenueVaultPda: PublicKey;
+  let navTreasuryPda: PublicKey;
+  let navHistoryPda: PublicKey;
+  let navPaymentMint: PublicKey;
+  let navOwner: anchor.web3.Keypair;
+  let oracle: anchor.web3.Keypair;
+  let investor: anchor.web3.Keypair;
+  let investorPaymentAta: PublicKey;
+  let investorShareholderPda: PublicKey;
+
+  const mintAccounts = () => ({
+    vault: navVaultPda,
+    vaultSigner: navVaultSignerPda,
+    payer: investor.publicKey,
+    payerAta: investorPaymentAta,
+    principalVault: navPrincipalVaultPda,
+    revenueVault: navRevenueVaultPda,
+    vaultShareMint: navShareMintPda,
+    investorShareAta: getAssociatedTokenAddressSync(navShareMintPda, investor.publicKey),
+    shareholder: investorShareholderPda,
+    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    systemProgram: SystemProgram.programId,
+    paymentMint: navPaymentMint,
+    tokenProgram: TOKEN_PROGRAM_ID,
+    shareTokenProgram: TOKEN_PROGRAM_ID,
+  });
+
+  beforeAll(async () => {
+    navOwner = anchor.web3.Keypair.generate();
+    oracle = anchor.web3.Keypair.generate();
+    investor = anchor.web3.Keypair.generate();
+    for (const kp of [navOwner, investor]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(kp.publicKey, 5e9)
+      );
+    }
+
+    navPaymentMint = await createMint(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      payer.publicKey,
+      null,
+      6
+    );
+    investorPaymentAta = await createAccount(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      navPaymentMint,
+      investor.publicKey
+    );
+    await mintTo(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      navPaymentMint,
+      investorPaymentAta,
+      payer.publicKey,
+      10_000_000
+    );
+
+    [navVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), navOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    [navVaultSignerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_signer"), navVaultPda.toBuffer()],
+      program.programId
+    );
+    [navShareMintPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_share_mint"), navVaultPda.toBuffer()],
+      program.programId
+    );
+    [navPrincipalVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("principal-vault"), navVaultPda.toBuffer()],
+      program.programId
+    );
+    [navRevenueVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("revenue-vault"), navVaultPda.toBuffer()],
+      program.programId
+    );
+    [navTreasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury"), navVaultPda.toBuffer()],
+      program.programId
+    );
+    [navHistoryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nav_history"), navVaultPda.toBuffer()],
+      program.programId
+    );
+    [investorShareholderPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder"), navVaultPda.toBuffer(), investor.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeVault(new anchor.BN(0), "NAV Vault", new anchor.BN(1_000_000), new anchor.BN(100), 0, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: navOwner.publicKey,
+        vault: navVaultPda,
+        vaultSigner: navVaultSignerPda,
+        paymentMint: navPaymentMint,
+        principalVault: navPrincipalVaultPda,
+        revenueVault: navRevenueVaultPda,
+        treasury: navTreasuryPda,
+        vaultShareMint: navShareMintPda,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([navOwner])
+      .rpc();
+
+    // 1,000 shares at the fixed price of 100
+    await program.methods
+      .mintShares(new anchor.BN(1_000))
+      .accounts(mintAccounts())
+      .signers([investor])
+      .rpc();
+  });
+
+  it("NAV-1: Enabling NAV pricing seeds NAV from the fixed price", async () => {
+    await program.methods
+      .configureNavPricing(oracle.publicKey, 1000) // 10% max move per update
+      .accounts({
+        vault: navVaultPda,
+        authority: navOwner.publicKey,
+        navHistory: navHistoryPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([navOwner])
+      .rpc();
+
+    const vault = await program.account.vault.fetch(navVaultPda);
+    expect(vault.navPricingEnabled).toBe(true);
+    expect(vault.netAssetValue.toNumber()).toBe(100_000);
+    expect(vault.navOracle.toBase58()).toBe(oracle.publicKey.toBase58());
+    expect(vault.maxNavChangeBps).toBe(1000);
+  });
+
+  it("NAV-2: Oracle posts a bounded valuation and it is recorded", async () => {
+    await program.methods
+      .updateNav(new anchor.BN(105_000))
+      .accounts({ vault: navVaultPda, updater: oracle.publicKey, navHistory: navHistoryPda })
+      .signers([oracle])
+      .rpc();
+
+    const vault = await program.account.vault.fetch(navVaultPda);
+    expect(vault.netAssetValue.toNumber()).toBe(105_000);
+
+    const history = await program.account.navHistory.fetch(navHistoryPda);
+    expect(history.count.toNumber()).toBe(1);
+    expect(history.records[0].netAssetValue.toNumber()).toBe(105_000);
+    expect(history.records[0].updater.toBase58()).toBe(oracle.publicKey.toBase58());
+  });
+
+  it("NAV-3: Valuations outside the bound or from other signers are rejected", async () => {
+    // 105,000 -> 120,000 is a ~14% move, above the 10% bound
+    const tooFar = program.methods
+      .updateNav(new anchor.BN(120_000))
+      .accounts({ vault: navVaultPda, updater: oracle.publicKey, navHistory: navHistoryPda })
+      .signers([oracle]);
+    await expect(tooFar.rpc()).rejects.toThrow("NavChangeExceedsBound");
+
+    const unauthorized = program.methods
+      .updateNav(new anchor.BN(106_000))
+      .accounts({ vault: navVaultPda, updater: investor.publicKey, navHistory: navHistoryPda })
+      .signers([investor]);
+    await expect(unauthorized.rpc()).rejects.toThrow("Unauthorized");
+  });
+
+  it("NAV-4: Mint and redeem are priced from NAV / minted_shares", async () => {
+    const principalBefore = Number(
+      (await provider.connection.getTokenAccountBalance(navPrincipalVaultPda)).value.amount
+    );
+
+    // 100 shares at 105,000 / 1,000 = 105 each
+    await program.methods
+      .mintShares(new anchor.BN(100))
+      .accounts(mintAccounts())
+      .signers([investor])
+      .rpc();
+
+    const principalAfterMint = Number(
+      (await provider.connection.getTokenAccountBalance(navPrincipalVaultPda)).value.amount
+    );
+    expect(principalAfterMint - principalBefore).toBe(10_500);
+
+    let vault = await program.account.vault.fetch(navVaultPda);
+    expect(vault.netAssetValue.toNumber()).toBe(115_500);
+
+    await program.methods
+      .redeemShares(new anchor.BN(100))
+      .accounts({
+        vault: navVaultPda,
+        vaultSigner: navVaultSignerPda,
+        payer: investor.publicKey,
+        shareholder: investorShareholderPda,
+        principalVault: navPrincipalVaultPda,
+        revenueVault: navRevenueVaultPda,
+        investorShareAta: getAssociatedTokenAddressSync(navShareMintPda, investor.publicKey),
+        vaultShareMint: navShareMintPda,
+        payerAta: investorPaymentAta,
+        paymentMint: navPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor])
+      .rpc();
+
+    const principalAfterRedeem = Number(
+      (await provider.connection.getTokenAccountBalance(navPrincipalVaultPda)).value.amount
+    );
+    expect(principalAfterMint - principalAfterRedeem).toBe(10_500);
+
+    vault = await program.account.vault.fetch(navVaultPda);
+    expect(vault.netAssetValue.toNumber()).toBe(105_000);
+    expect(vault.mintedShares.toNumber()).toBe(1_000);
+  });
+});
+
+// =============================================================================
+// VAULT LIFECYCLE STATUS TESTS
+// =============================================================================
+describe("Vault Lifecycle Status", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.TokenizedYieldInfrastructure as Program<TokenizedYieldInfrastructure>;
+
+  const payer = provider.wallet;
+
+  let statusVaultPda: PublicKey;
+  let statusVaultSignerPda: PublicKey;
+  let statusShareMintPda: PublicKey;
+  let statusPrincipalVaultPda: PublicKey;
+  let statusRevenueVaultPda: PublicKey;
+  let statusTreasuryPda: PublicKey;
+  let statusPaymentMint: PublicKey;
+  let statusOwner: anchor.web3.Keypair;
+  let investor: anchor.web3.Keypair;
+  let investorPaymentAta: PublicKey;
+  let investorShareholderPda: PublicKey;
+
+  const setStatus = (status: object) =>
+    program.methods
+      .setVaultStatus(status as any)
+      .accounts({ vault: statusVaultPda, authority: statusOwner.publicKey })
+      .signers([statusOwner]);
+
+  const mintShares = (amount: number) =>
+    program.methods
+      .mintShares(new anchor.BN(amount))
+      .accounts({
+        vault: statusVaultPda,
+        vaultSigner: statusVaultSignerPda,
+        payer: investor.publicKey,
+        payerAta: investorPaymentAta,
+        principalVault: statusPrincipalVaultPda,
+        revenueVault: statusRevenueVaultPda,
+        vaultShareMint: statusShareMintPda,
+        investorShareAta: getAssociatedTokenAddressSync(statusShareMintPda, investor.publicKey),
+        shareholder: investorShareholderPda,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        paymentMint: statusPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor]);
+
+  beforeAll(async () => {
+    statusOwner = anchor.web3.Keypair.generate();
+    investor = anchor.web3.Keypair.generate();
+    for (const kp of [statusOwner, investor]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(kp.publicKey, 5e9)
+      );
+    }
+
+    statusPaymentMint = await createMint(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      payer.publicKey,
+      null,
+      6
+    );
+    investorPaymentAta = await createAccount(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      statusPaymentMint,
+      investor.publicKey
+    );
+    await mintTo(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      statusPaymentMint,
+      investorPaymentAta,
+      payer.publicKey,
+      10_000_000
+    );
+
+    [statusVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), statusOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    [statusVaultSignerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_signer"), statusVaultPda.toBuffer()],
+      program.programId
+    );
+    [statusShareMintPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_share_mint"), statusVaultPda.toBuffer()],
+      program.programId
+    );
+    [statusPrincipalVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("principal-vault"), statusVaultPda.toBuffer()],
+      program.programId
+    );
+    [statusRevenueVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("revenue-vault"), statusVaultPda.toBuffer()],
+      program.programId
+    );
+    [statusTreasuryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury"), statusVaultPda.toBuffer()],
+      program.programId
+    );
+    [investorShareholderPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder"), statusVaultPda.toBuffer(), investor.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeVault(new anchor.BN(0), "Status Vault", new anchor.BN(1_000_000), new anchor.BN(100), 0, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: statusOwner.publicKey,
+        vault: statusVaultPda,
+        vaultSigner: statusVaultSignerPda,
+        paymentMint: statusPaymentMint,
+        principalVault: statusPrincipalVaultPda,
+        revenueVault: statusRevenueVaultPda,
+        treasury: statusTreasuryPda,
+        vaultShareMint: statusShareMintPda,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([statusOwner])
+      .rpc();
+
+    await mintShares(1_000).rpc();
+  });
+
+  it("STATUS-1: Vault starts Fundraising and only moves forward", async () => {
+    let vault = await program.account.vault.fetch(statusVaultPda);
+    expect(vault.status).toEqual({ fundraising: {} });
+
+    await setStatus({ active: {} }).rpc();
+    vault = await program.account.vault.fetch(statusVaultPda);
+    expect(vault.status).toEqual({ active: {} });
+
+    await expect(setStatus({ fundraising: {} }).rpc()).rejects.toThrow("InvalidStatusTransition");
+  });
+
+  it("STATUS-2: Paused halts minting and resumes only to the prior status", async () => {
+    await setStatus({ paused: {} }).rpc();
+    await expect(mintShares(10).rpc()).rejects.toThrow("InvalidVaultStatus");
+
+    await expect(setStatus({ windingDown: {} }).rpc()).rejects.toThrow("InvalidStatusTransition");
+    await setStatus({ active: {} }).rpc();
+
+    await mintShares(10).rpc();
+  });
+
+  it("STATUS-3: WindingDown blocks minting and Closed requires every share redeemed", async () => {
+    await setStatus({ windingDown: {} }).rpc();
+    await expect(mintShares(10).rpc()).rejects.toThrow("InvalidVaultStatus");
+    await expect(setStatus({ closed: {} }).rpc()).rejects.toThrow("SharesOutstanding");
+
+    await program.methods
+      .redeemShares(new anchor.BN(1_010))
+      .accounts({
+        vault: statusVaultPda,
+        vaultSigner: statusVaultSignerPda,
+        payer: investor.publicKey,
+        shareholder: investorShareholderPda,
+        principalVault: statusPrincipalVaultPda,
+        revenueVault: statusRevenueVaultPda,
+        investorShareAta: getAssociatedTokenAddressSync(statusShareMintPda, investor.publicKey),
+        vaultShareMint: statusShareMintPda,
+        payerAta: investorPaymentAta,
+        paymentMint: statusPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor])
+      .rpc();
+
+    await setStatus({ closed: {} }).rpc();
+    const vault = await program.account.vault.fetch(statusVaultPda);
+    expect(vault.status).toEqual({ closed: {} });
+
+    const deposit = program.methods
+      .depositRevenue(new anchor.BN(1_000))
+      .accounts({
+        vault: statusVaultPda,
+        payer: investor.publicKey,
+        payerAta: investorPaymentAta,
+        revenueVault: statusRevenueVaultPda,
+        treasury: statusTreasuryPda,
+        vaultSigner: statusVaultSignerPda,
+        paymentMint: statusPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor]);
+    await expect(deposit.rpc()).rejects.toThrow("InvalidVaultStatus");
+  });
+});
+
+describe("Multisig Governance", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.TokenizedYieldInfrastructure as Program<TokenizedYieldInfrastructure>;
+
+  const payer = provider.wallet;
+
+  let msVaultPda: PublicKey;
+  let msVaultSignerPda: PublicKey;
+  let msMultisigPda: PublicKey;
+  let msOwner: anchor.web3.Keypair;
+  let signers: anchor.web3.Keypair[];
+
+  const transactionPda = (id: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("multisig_tx"), msMultisigPda.toBuffer(), new anchor.BN(id).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const execute = (id: number) =>
+    program.methods
+      .executeMultisigTransaction()
+      .accounts({
+        vault: msVaultPda,
+        multisig: msMultisigPda,
+        transaction: transactionPda(id),
+        executor: payer.publicKey,
+        tokenAccount: null,
+        proposal: null,
+        vaultSigner: msVaultSignerPda,
+      } as any);
+
+  beforeAll(async () => {
+    msOwner = anchor.web3.Keypair.generate();
+    signers = [0, 1, 2].map(() => anchor.web3.Keypair.generate());
+    for (const kp of [msOwner, ...signers]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(kp.publicKey, 5e9)
+      );
+    }
+
+    const msPaymentMint = await createMint(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      payer.publicKey,
+      null,
+      6
+    );
+
+    [msVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), msOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    [msVaultSignerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault_signer"), msVaultPda.toBuffer()],
+      program.programId
+    );
+    [msMultisigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("multisig"), msVaultPda.toBuffer()],
+      program.programId
+    );
+    const pda = (seed: string) =>
+      PublicKey.findProgramAddressSync([Buffer.from(seed), msVaultPda.toBuffer()], program.programId)[0];
+
+    await program.methods
+      .initializeVault(new anchor.BN(0), "Multisig Vault", new anchor.BN(1_000_000), new anchor.BN(100), 1000, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: msOwner.publicKey,
+        vault: msVaultPda,
+        vaultSigner: msVaultSignerPda,
+        paymentMint: msPaymentMint,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        treasury: pda("treasury"),
+        vaultShareMint: pda("vault_share_mint"),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([msOwner])
+      .rpc();
+  });
+
+  it("MS-1: Rejects a threshold above the signer count", async () => {
+    await expect(
+      program.methods
+        .createMultisig(signers.map((s) => s.publicKey), 4)
+        .accounts({
+          vault: msVaultPda,
+          authority: msOwner.publicKey,
+          multisig: msMultisigPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([msOwner])
+        .rpc()
+    ).rejects.toThrow("InvalidMultisigConfig");
+  });
+
+  it("MS-2: 2-of-3 multisig becomes the vault authority", async () => {
+    await program.methods
+      .createMultisig(signers.map((s) => s.publicKey), 2)
+      .accounts({
+        vault: msVaultPda,
+        authority: msOwner.publicKey,
+        multisig: msMultisigPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([msOwner])
+      .rpc();
+
+    const vault = await program.account.vault.fetch(msVaultPda);
+    expect(vault.authority.toBase58()).toBe(msMultisigPda.toBase58());
+
+    // The former owner key can no longer govern alone
+    await expect(
+      program.methods
+        .updatePerformanceFee(500)
+        .accounts({ vault: msVaultPda, authority: msOwner.publicKey })
+        .signers([msOwner])
+        .rpc()
+    ).rejects.toThrow("Unauthorized");
+  });
+
+  it("MS-3: Action executes only after approvals reach the threshold", async () => {
+    await program.methods
+      .createMultisigTransaction({ lowerPerformanceFee: { newFeeBps: 500 } } as any)
+      .accounts({
+        vault: msVaultPda,
+        multisig: msMultisigPda,
+        proposer: signers[0].publicKey,
+        transaction: transactionPda(0),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signers[0]])
+      .rpc();
+
+    await expect(execute(0).rpc()).rejects.toThrow("ThresholdNotMet");
+
+    const approve = (signer: anchor.web3.Keypair) =>
+      program.methods
+        .approveMultisigTransaction()
+        .accounts({ multisig: msMultisigPda, transaction: transactionPda(0), approver: signer.publicKey })
+        .signers([signer]);
+
+    await expect(approve(signers[0]).rpc()).rejects.toThrow("AlreadyApproved");
+    await expect(approve(msOwner).rpc()).rejects.toThrow("NotMultisigSigner");
+    await approve(signers[1]).rpc();
+
+    await execute(0).rpc();
+
+    const vault = await program.account.vault.fetch(msVaultPda);
+    expect(vault.performanceFeeBps).toBe(500);
+
+    await expect(execute(0).rpc()).rejects.toThrow("TransactionAlreadyExecuted");
+  });
+
+  it("MS-4: The multisig enables NAV pricing, creating the history account", async () => {
+    const navHistoryPda = PublicKey.findProgramAddressSync(
+      [Buffer.from("nav_history"), msVaultPda.toBuffer()],
+      program.programId
+    )[0];
+    const oracle = anchor.web3.Keypair.generate().publicKey;
+
+    await expect(
+      program.methods
+        .createMultisigTransaction({ configureNavPricing: { navOracle: oracle, maxNavChangeBps: 0 } } as any)
+        .accounts({
+          vault: msVaultPda,
+          multisig: msMultisigPda,
+          proposer: signers[0].publicKey,
+          transaction: transactionPda(1),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([signers[0]])
+        .rpc()
+    ).rejects.toThrow("InvalidNavChangeBound");
+
+    await program.methods
+      .createMultisigTransaction({ configureNavPricing: { navOracle: oracle, maxNavChangeBps: 2000 } } as any)
+      .accounts({
+        vault: msVaultPda,
+        multisig: msMultisigPda,
+        proposer: signers[0].publicKey,
+        transaction: transactionPda(1),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signers[0]])
+      .rpc();
+    await program.methods
+      .approveMultisigTransaction()
+      .accounts({ multisig: msMultisigPda, transaction: transactionPda(1), approver: signers[2].publicKey })
+      .signers([signers[2]])
+      .rpc();
+
+    await expect(execute(1).rpc()).rejects.toThrow("MultisigAccountMissing");
+    await execute(1)
+      .accounts({
+        vault: msVaultPda,
+        multisig: msMultisigPda,
+        transaction: transactionPda(1),
+        executor: payer.publicKey,
+        vaultSigner: msVaultSignerPda,
+        stateAccount: navHistoryPda,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .rpc();
+
+    const vault = await program.account.vault.fetch(msVaultPda);
+    expect(vault.navPricingEnabled).toBe(true);
+    expect(vault.navOracle.toBase58()).toBe(oracle.toBase58());
+    expect(vault.maxNavChangeBps).toBe(2000);
+    const history = await program.account.navHistory.fetch(navHistoryPda);
+    expect(history.vault.toBase58()).toBe(msVaultPda.toBase58());
+  });
+
+  it("MS-5: The multisig adds and removes a revenue depositor", async () => {
+    const depositor = anchor.web3.Keypair.generate().publicKey;
+    const entryPda = PublicKey.findProgramAddressSync(
+      [Buffer.from("revenue_depositor"), msVaultPda.toBuffer(), depositor.toBuffer()],
+      program.programId
+    )[0];
+
+    const run = async (id: number, action: object) => {
+      await program.methods
+        .createMultisigTransaction(action as any)
+        .accounts({
+          vault: msVaultPda,
+          multisig: msMultisigPda,
+          proposer: signers[0].publicKey,
+          transaction: transactionPda(id),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([signers[0]])
+        .rpc();
+      await program.methods
+        .approveMultisigTransaction()
+        .accounts({ multisig: msMultisigPda, transaction: transactionPda(id), approver: signers[1].publicKey })
+        .signers([signers[1]])
+        .rpc();
+      await execute(id)
+        .accounts({
+          vault: msVaultPda,
+          multisig: msMultisigPda,
+          transaction: transactionPda(id),
+          executor: payer.publicKey,
+          vaultSigner: msVaultSignerPda,
+          stateAccount: entryPda,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .rpc();
+    };
+
+    await run(2, { addRevenueDepositor: { depositor } });
+    const entry = await program.account.revenueDepositor.fetch(entryPda);
+    expect(entry.vault.toBase58()).toBe(msVaultPda.toBase58());
+    expect(entry.depositor.toBase58()).toBe(depositor.toBase58());
+
+    await run(3, { removeRevenueDepositor: { depositor } });
+    expect(await provider.connection.getAccountInfo(entryPda)).toBeNull();
+  });
+
+  it("MS-6: The multisig opens the operator debt ledger, even at a pre-funded address", async () => {
+    const debtPda = PublicKey.findProgramAddressSync(
+      [Buffer.from("operator_debt"), msVaultPda.toBuffer()],
+      program.programId
+    )[0];
+    const operator = anchor.web3.Keypair.generate().publicKey;
+
+    await program.methods
+      .createMultisigTransaction({
+        initializeOperatorDebt: {
+          operator,
+          interestRateBps: 800,
+          installmentAmount: new anchor.BN(1_000),
+          paymentInterval: new anchor.BN(30 * 86_400),
+          firstDueAt: new anchor.BN(0),
+          gracePeriod: new anchor.BN(86_400),
+        },
+      } as any)
+      .accounts({
+        vault: msVaultPda,
+        multisig: msMultisigPda,
+        proposer: signers[1].publicKey,
+        transaction: transactionPda(4),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signers[1]])
+      .rpc();
+    await program.methods
+      .approveMultisigTransaction()
+      .accounts({ multisig: msMultisigPda, transaction: transactionPda(4), approver: signers[2].publicKey })
+      .signers([signers[2]])
+      .rpc();
+
+    // Lamports sent to the predictable PDA ahead of time must not block the action
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        SystemProgram.transfer({ fromPubkey: payer.publicKey, toPubkey: debtPda, lamports: 1_000_000 })
+      )
+    );
+    await execute(4)
+      .accounts({
+        vault: msVaultPda,
+        multisig: msMultisigPda,
+        transaction: transactionPda(4),
+        executor: payer.publicKey,
+        vaultSigner: msVaultSignerPda,
+        stateAccount: debtPda,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .rpc();
+
+    const debt = await program.account.operatorDebt.fetch(debtPda);
+    expect(debt.operator.toBase58()).toBe(operator.toBase58());
+    expect(debt.interestRateBps).toBe(800);
+    expect((await program.account.vault.fetch(msVaultPda)).hasOperatorDebt).toBe(true);
+  });
+});
+
+describe("Shareholder Voting", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.TokenizedYieldInfrastructure as Program<TokenizedYieldInfrastructure>;
+
+  const payer = provider.wallet;
+
+  let shVaultPda: PublicKey;
+  let shVaultSignerPda: PublicKey;
+  let shShareMintPda: PublicKey;
+  let shPaymentMint: PublicKey;
+  let shOwner: anchor.web3.Keypair;
+  let whale: anchor.web3.Keypair;
+  let minnow: anchor.web3.Keypair;
+  let buyer: anchor.web3.Keypair;
+
+  const pda = (seed: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from(seed), shVaultPda.toBuffer()], program.programId)[0];
+  const stakePda = (holder: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder"), shVaultPda.toBuffer(), holder.toBuffer()],
+      program.programId
+    )[0];
+  const proposalPda = (id: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder_proposal"), shVaultPda.toBuffer(), new anchor.BN(id).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const propose = (proposer: anchor.web3.Keypair, id: number, action: object) =>
+    program.methods
+      .createShareholderProposal(action as any)
+      .accounts({
+        vault: shVaultPda,
+        proposer: proposer.publicKey,
+        proposerStake: stakePda(proposer.publicKey),
+        proposal: proposalPda(id),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([proposer]);
+
+  const vote = (voter: anchor.web3.Keypair, id: number, support: boolean) =>
+    program.methods
+      .castShareholderVote(support)
+      .accounts({
+        vault: shVaultPda,
+        proposal: proposalPda(id),
+        voter: voter.publicKey,
+        voterStake: stakePda(voter.publicKey),
+        vote: PublicKey.findProgramAddressSync(
+          [Buffer.from("shareholder_vote"), proposalPda(id).toBuffer(), voter.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([voter]);
+
+  const mintShares = async (investor: anchor.web3.Keypair, amount: number) => {
+    const investorPaymentAta = await createAccount(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      shPaymentMint,
+      investor.publicKey
+    );
+    await mintTo(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      shPaymentMint,
+      investorPaymentAta,
+      payer.publicKey,
+      amount * 100
+    );
+    await program.methods
+      .mintShares(new anchor.BN(amount))
+      .accounts({
+        vault: shVaultPda,
+        vaultSigner: shVaultSignerPda,
+        payer: investor.publicKey,
+        payerAta: investorPaymentAta,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        vaultShareMint: shShareMintPda,
+        investorShareAta: getAssociatedTokenAddressSync(shShareMintPda, investor.publicKey),
+        shareholder: stakePda(investor.publicKey),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        paymentMint: shPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor])
+      .rpc();
+  };
+
+  beforeAll(async () => {
+    shOwner = anchor.web3.Keypair.generate();
+    whale = anchor.web3.Keypair.generate();
+    minnow = anchor.web3.Keypair.generate();
+    buyer = anchor.web3.Keypair.generate();
+    for (const kp of [shOwner, whale, minnow, buyer]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(kp.publicKey, 5e9)
+      );
+    }
+
+    shPaymentMint = await createMint(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      payer.publicKey,
+      null,
+      6
+    );
+
+    [shVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), shOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    shVaultSignerPda = pda("vault_signer");
+    shShareMintPda = pda("vault_share_mint");
+
+    await program.methods
+      .initializeVault(new anchor.BN(0), "Voting Vault", new anchor.BN(1_000_000), new anchor.BN(100), 1000, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: shOwner.publicKey,
+        vault: shVaultPda,
+        vaultSigner: shVaultSignerPda,
+        paymentMint: shPaymentMint,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        treasury: pda("treasury"),
+        vaultShareMint: shShareMintPda,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([shOwner])
+      .rpc();
+
+    await mintShares(whale, 1_000);
+    await mintShares(minnow, 5);
+  });
+
+  it("SH-1: Only holders above the threshold can open a proposal, one at a time", async () => {
+    await expect(propose(minnow, 0, { windDown: {} }).rpc()).rejects.toThrow("ProposalThresholdNotMet");
+
+    await propose(whale, 0, { updatePerformanceFee: { newFeeBps: 500 } }).rpc();
+
+    const proposal = await program.account.shareholderProposal.fetch(proposalPda(0));
+    expect(proposal.status).toEqual({ voting: {} });
+    expect(proposal.snapshotSupply.toNumber()).toBe(1_005);
+
+    const vault = await program.account.vault.fetch(shVaultPda);
+    expect(vault.activeShareholderProposal.toBase58()).toBe(proposalPda(0).toBase58());
+
+    await expect(propose(whale, 1, { windDown: {} }).rpc()).rejects.toThrow("ShareholderProposalActive");
+  });
+
+  it("SH-2: Votes use the stake held at creation, once per holder", async () => {
+    // Shares moved after creation keep voting with the sender only
+    await program.methods
+      .transferShares(new anchor.BN(400))
+      .accounts({
+        vault: shVaultPda,
+        sender: whale.publicKey,
+        recipient: buyer.publicKey,
+        senderStake: stakePda(whale.publicKey),
+        recipientStake: stakePda(buyer.publicKey),
+        vaultShareMint: shShareMintPda,
+        senderShareAta: getAssociatedTokenAddressSync(shShareMintPda, whale.publicKey),
+        recipientShareAta: getAssociatedTokenAddressSync(shShareMintPda, buyer.publicKey),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([whale])
+      .rpc();
+
+    await vote(whale, 0, true).rpc();
+    await expect(vote(whale, 0, true).rpc()).rejects.toThrow();
+    await expect(vote(buyer, 0, true).rpc()).rejects.toThrow("NoVotingPower");
+    await vote(minnow, 0, false).rpc();
+
+    const proposal = await program.account.shareholderProposal.fetch(proposalPda(0));
+    expect(proposal.votesFor.toNumber()).toBe(1_000);
+    expect(proposal.votesAgainst.toNumber()).toBe(5);
+
+    // Nothing applies before the voting period ends
+    await expect(
+      program.methods
+        .executeShareholderProposal()
+        .accounts({
+          vault: shVaultPda,
+          proposal: proposalPda(0),
+          executor: buyer.publicKey,
+          newTreasury: null,
+          vaultSigner: shVaultSignerPda,
+        })
+        .signers([buyer])
+        .rpc()
+    ).rejects.toThrow("VotingNotEnded");
+  });
+});
+
+describe("Revenue Depositor Registry", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.TokenizedYieldInfrastructure as Program<TokenizedYieldInfrastructure>;
+
+  const payer = provider.wallet;
+
+  let revVaultPda: PublicKey;
+  let revVaultSignerPda: PublicKey;
+  let revShareMintPda: PublicKey;
+  let revPaymentMint: PublicKey;
+  let revOwner: anchor.web3.Keypair;
+  let operator: anchor.web3.Keypair;
+  let stranger: anchor.web3.Keypair;
+
+  const pda = (seed: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from(seed), revVaultPda.toBuffer()], program.programId)[0];
+  const approvalPda = (depositor: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("revenue_depositor"), revVaultPda.toBuffer(), depositor.toBuffer()],
+      program.programId
+    )[0];
+
+  const fundedAta = async (owner: PublicKey) => {
+    const ata = await createAccount(provider.connection, (payer as anchor.Wallet).payer, revPaymentMint, owner);
+    await mintTo(provider.connection, (payer as anchor.Wallet).payer, revPaymentMint, ata, payer.publicKey, 1_000_000);
+    return ata;
+  };
+
+  const deposit = (depositor: anchor.web3.Keypair, depositorAta: PublicKey, approval: PublicKey | null) =>
+    program.methods
+      .depositRevenue(new anchor.BN(1_000))
+      .accounts({
+        vault: revVaultPda,
+        payer: depositor.publicKey,
+        payerAta: depositorAta,
+        revenueVault: pda("revenue-vault"),
+        treasury: pda("treasury"),
+        vaultSigner: revVaultSignerPda,
+        paymentMint: revPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        depositorApproval: approval,
+      } as any)
+      .signers([depositor]);
+
+  let operatorAta: PublicKey;
+  let strangerAta: PublicKey;
+
+  beforeAll(async () => {
+    revOwner = anchor.web3.Keypair.generate();
+    operator = anchor.web3.Keypair.generate();
+    stranger = anchor.web3.Keypair.generate();
+    for (const kp of [revOwner, operator, stranger]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(kp.publicKey, 5e9)
+      );
+    }
+
+    revPaymentMint = await createMint(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      payer.publicKey,
+      null,
+      6
+    );
+
+    [revVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), revOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    revVaultSignerPda = pda("vault_signer");
+    revShareMintPda = pda("vault_share_mint");
+
+    await program.methods
+      .initializeVault(new anchor.BN(0), "Registry Vault", new anchor.BN(1_000_000), new anchor.BN(100), 1000, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: revOwner.publicKey,
+        vault: revVaultPda,
+        vaultSigner: revVaultSignerPda,
+        paymentMint: revPaymentMint,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        treasury: pda("treasury"),
+        vaultShareMint: revShareMintPda,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([revOwner])
+      .rpc();
+
+    operatorAta = await fundedAta(operator.publicKey);
+    strangerAta = await fundedAta(stranger.publicKey);
+
+    // Deposits need minted shares
+    await program.methods
+      .mintShares(new anchor.BN(100))
+      .accounts({
+        vault: revVaultPda,
+        vaultSigner: revVaultSignerPda,
+        payer: stranger.publicKey,
+        payerAta: strangerAta,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        vaultShareMint: revShareMintPda,
+        investorShareAta: getAssociatedTokenAddressSync(revShareMintPda, stranger.publicKey),
+        shareholder: PublicKey.findProgramAddressSync(
+          [Buffer.from("shareholder"), revVaultPda.toBuffer(), stranger.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        paymentMint: revPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([stranger])
+      .rpc();
+  });
+
+  it("REV-1: New vaults accept revenue from anyone", async () => {
+    const vault = await program.account.vault.fetch(revVaultPda);
+    expect(vault.openRevenueDeposits).toBe(true);
+
+    await deposit(stranger, strangerAta, null).rpc();
+  });
+
+  it("REV-2: Restricted vaults accept revenue only from registered depositors", async () => {
+    await program.methods
+      .setOpenRevenueDeposits(false)
+      .accounts({ vault: revVaultPda, authority: revOwner.publicKey })
+      .signers([revOwner])
+      .rpc();
+
+    await expect(deposit(stranger, strangerAta, null).rpc()).rejects.toThrow("UnauthorizedDepositor");
+
+    await program.methods
+      .addRevenueDepositor(operator.publicKey)
+      .accounts({
+        vault: revVaultPda,
+        authority: revOwner.publicKey,
+        depositorApproval: approvalPda(operator.publicKey),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([revOwner])
+      .rpc();
+
+    await deposit(operator, operatorAta, approvalPda(operator.publicKey)).rpc();
+
+    // Another wallet's registry entry does not authorize the stranger
+    await expect(deposit(stranger, strangerAta, approvalPda(operator.publicKey)).rpc()).rejects.toThrow();
+
+    await program.methods
+      .removeRevenueDepositor()
+      .accounts({
+        vault: revVaultPda,
+        authority: revOwner.publicKey,
+        depositorApproval: approvalPda(operator.publicKey),
+      })
+      .signers([revOwner])
+      .rpc();
+
+    await expect(deposit(operator, operatorAta, null).rpc()).rejects.toThrow("UnauthorizedDepositor");
+  });
+});
+
+describe("Investor Compliance", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.TokenizedYieldInfrastructure as Program<TokenizedYieldInfrastructure>;
+
+  const payer = provider.wallet;
+
+  let kycVaultPda: PublicKey;
+  let kycVaultSignerPda: PublicKey;
+  let kycShareMintPda: PublicKey;
+  let kycPaymentMint: PublicKey;
+  let kycOwner: anchor.web3.Keypair;
+  let complianceOfficer: anchor.web3.Keypair;
+  let investor: anchor.web3.Keypair;
+  let investorPaymentAta: PublicKey;
+
+  const pda = (seed: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from(seed), kycVaultPda.toBuffer()], program.programId)[0];
+  const allowlistPda = (wallet: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("investor_allowlist"), kycVaultPda.toBuffer(), wallet.toBuffer()],
+      program.programId
+    )[0];
+
+  const mintShares = (allowlist: PublicKey | null) =>
+    program.methods
+      .mintShares(new anchor.BN(10))
+      .accounts({
+        vault: kycVaultPda,
+        vaultSigner: kycVaultSignerPda,
+        payer: investor.publicKey,
+        payerAta: investorPaymentAta,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        vaultShareMint: kycShareMintPda,
+        investorShareAta: getAssociatedTokenAddressSync(kycShareMintPda, investor.publicKey),
+        shareholder: PublicKey.findProgramAddressSync(
+          [Buffer.from("shareholder"), kycVaultPda.toBuffer(), investor.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        paymentMint: kycPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        investorAllowlist: allowlist,
+      } as any)
+      .signers([investor]);
+
+  beforeAll(async () => {
+    kycOwner = anchor.web3.Keypair.generate();
+    complianceOfficer = anchor.web3.Keypair.generate();
+    investor = anchor.web3.Keypair.generate();
+    for (const kp of [kycOwner, complianceOfficer, investor]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(kp.publicKey, 5e9)
+      );
+    }
+
+    kycPaymentMint = await createMint(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      payer.publicKey,
+      null,
+      6
+    );
+    investorPaymentAta = await createAccount(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      kycPaymentMint,
+      investor.publicKey
+    );
+    await mintTo(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      kycPaymentMint,
+      investorPaymentAta,
+      payer.publicKey,
+      1_000_000
+    );
+
+    [kycVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), kycOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    kycVaultSignerPda = pda("vault_signer");
+    kycShareMintPda = pda("vault_share_mint");
+
+    await program.methods
+      .initializeVault(new anchor.BN(0), "KYC Vault", new anchor.BN(1_000_000), new anchor.BN(100), 0, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: kycOwner.publicKey,
+        vault: kycVaultPda,
+        vaultSigner: kycVaultSignerPda,
+        paymentMint: kycPaymentMint,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        treasury: pda("treasury"),
+        vaultShareMint: kycShareMintPda,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([kycOwner])
+      .rpc();
+
+    await program.methods
+      .setComplianceAuthority(complianceOfficer.publicKey)
+      .accounts({ vault: kycVaultPda, authority: kycOwner.publicKey })
+      .signers([kycOwner])
+      .rpc();
+  });
+
+  it("KYC-1: Unrestricted vaults mint to anyone", async () => {
+    await mintShares(null).rpc();
+  });
+
+  it("KYC-2: Restricted vaults mint only to allowlisted investors", async () => {
+    await program.methods
+      .setMintAllowlistRequired(true)
+      .accounts({ vault: kycVaultPda, authority: kycOwner.publicKey })
+      .signers([kycOwner])
+      .rpc();
+
+    await expect(mintShares(null).rpc()).rejects.toThrow("InvestorNotAllowlisted");
+
+    // The compliance role cannot toggle the requirement itself
+    await expect(
+      program.methods
+        .setMintAllowlistRequired(false)
+        .accounts({ vault: kycVaultPda, authority: complianceOfficer.publicKey })
+        .signers([complianceOfficer])
+        .rpc()
+    ).rejects.toThrow("Unauthorized");
+
+    const setEntry = (expiresAt: number) =>
+      program.methods
+        .setInvestorAllowlist(investor.publicKey, new anchor.BN(expiresAt), Array.from(Buffer.from("DE")))
+        .accounts({
+          vault: kycVaultPda,
+          officer: complianceOfficer.publicKey,
+          investorAllowlist: allowlistPda(investor.publicKey),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([complianceOfficer]);
+
+    await expect(setEntry(1).rpc()).rejects.toThrow("InvalidAllowlistExpiry");
+    await setEntry(0).rpc();
+
+    const entry = await program.account.investorAllowlist.fetch(allowlistPda(investor.publicKey));
+    expect(Buffer.from(entry.jurisdiction).toString()).toBe("DE");
+    expect(entry.approvedBy.toBase58()).toBe(complianceOfficer.publicKey.toBase58());
+
+    await mintShares(allowlistPda(investor.publicKey)).rpc();
+
+    await program.methods
+      .removeInvestorAllowlist()
+      .accounts({
+        vault: kycVaultPda,
+        officer: complianceOfficer.publicKey,
+        investorAllowlist: allowlistPda(investor.publicKey),
+      })
+      .signers([complianceOfficer])
+      .rpc();
+
+    await expect(mintShares(null).rpc()).rejects.toThrow("InvestorNotAllowlisted");
+  });
+
+  it("KYC-3: Frozen holders cannot harvest until compliance unfreezes them", async () => {
+    const shareholderPda = PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder"), kycVaultPda.toBuffer(), investor.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    const investorShareAta = getAssociatedTokenAddressSync(kycShareMintPda, investor.publicKey);
+    const freezeAccounts = {
+      vault: kycVaultPda,
+      officer: complianceOfficer.publicKey,
+      holder: investor.publicKey,
+      shareholder: shareholderPda,
+      holderShareAta: investorShareAta,
+      vaultShareMint: kycShareMintPda,
+      vaultSigner: kycVaultSignerPda,
+      shareTokenProgram: TOKEN_PROGRAM_ID,
+    };
+    const harvest = program.methods
+      .harvest()
+      .accounts({
+        vault: kycVaultPda,
+        vaultSigner: kycVaultSignerPda,
+        payer: investor.publicKey,
+        shareholder: shareholderPda,
+        revenueVault: pda("revenue-vault"),
+        userAta: investorPaymentAta,
+        paymentMint: kycPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor]);
+
+    await expect(
+      program.methods.freezeShareholder(0).accounts(freezeAccounts).signers([complianceOfficer]).rpc()
+    ).rejects.toThrow("InvalidReasonCode");
+    await program.methods.freezeShareholder(7).accounts(freezeAccounts).signers([complianceOfficer]).rpc();
+
+    const stake = await program.account.userStake.fetch(shareholderPda);
+    expect(stake.frozen).toBe(true);
+    expect(stake.freezeReason).toBe(7);
+    expect((await getAccount(provider.connection, investorShareAta)).isFrozen).toBe(true);
+
+    await expect(harvest.rpc()).rejects.toThrow("ShareholderFrozen");
+
+    await program.methods.unfreezeShareholder().accounts(freezeAccounts).signers([complianceOfficer]).rpc();
+    await harvest.rpc();
+  });
+});
+
+describe("Investment Limits", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.TokenizedYieldInfrastructure as Program<TokenizedYieldInfrastructure>;
+
+  const payer = provider.wallet;
+
+  let limVaultPda: PublicKey;
+  let limVaultSignerPda: PublicKey;
+  let limShareMintPda: PublicKey;
+  let limPaymentMint: PublicKey;
+  let limOwner: anchor.web3.Keypair;
+  let alice: anchor.web3.Keypair;
+  let bob: anchor.web3.Keypair;
+  const paymentAtas = new Map<string, PublicKey>();
+
+  const pda = (seed: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from(seed), limVaultPda.toBuffer()], program.programId)[0];
+  const stakePda = (owner: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder"), limVaultPda.toBuffer(), owner.toBuffer()],
+      program.programId
+    )[0];
+
+  const mint = (investor: anchor.web3.Keypair, amount: number) =>
+    program.methods
+      .mintShares(new anchor.BN(amount))
+      .accounts({
+        vault: limVaultPda,
+        vaultSigner: limVaultSignerPda,
+        payer: investor.publicKey,
+        payerAta: paymentAtas.get(investor.publicKey.toBase58())!,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        vaultShareMint: limShareMintPda,
+        investorShareAta: getAssociatedTokenAddressSync(limShareMintPda, investor.publicKey),
+        shareholder: stakePda(investor.publicKey),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        paymentMint: limPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor])
+      .rpc();
+
+  const setLimits = (min: number, maxPerHolder: number, maxHolders: number) =>
+    program.methods
+      .setInvestmentLimits(new anchor.BN(min), new anchor.BN(maxPerHolder), maxHolders)
+      .accounts({ vault: limVaultPda, authority: limOwner.publicKey })
+      .signers([limOwner])
+      .rpc();
+
+  beforeAll(async () => {
+    limOwner = anchor.web3.Keypair.generate();
+    alice = anchor.web3.Keypair.generate();
+    bob = anchor.web3.Keypair.generate();
+    for (const kp of [limOwner, alice, bob]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(kp.publicKey, 5e9)
+      );
+    }
+
+    limPaymentMint = await createMint(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      payer.publicKey,
+      null,
+      6
+    );
+    for (const kp of [alice, bob]) {
+      const ata = await createAccount(provider.connection, (payer as anchor.Wallet).payer, limPaymentMint, kp.publicKey);
+      await mintTo(provider.connection, (payer as anchor.Wallet).payer, limPaymentMint, ata, payer.publicKey, 1_000_000);
+      paymentAtas.set(kp.publicKey.toBase58(), ata);
+    }
+
+    [limVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), limOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    limVaultSignerPda = pda("vault_signer");
+    limShareMintPda = pda("vault_share_mint");
+
+    await program.methods
+      .initializeVault(new anchor.BN(0), "Limits Vault", new anchor.BN(1_000_000), new anchor.BN(100), 1000, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: limOwner.publicKey,
+        vault: limVaultPda,
+        vaultSigner: limVaultSignerPda,
+        paymentMint: limPaymentMint,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        treasury: pda("treasury"),
+        vaultShareMint: limShareMintPda,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([limOwner])
+      .rpc();
+  });
+
+  it("LIM-1: Mints below the minimum or above the per-holder cap are rejected", async () => {
+    await expect(setLimits(200, 100, 0)).rejects.toThrow("InvalidInvestmentLimits");
+    await setLimits(10, 100, 0);
+
+    await expect(mint(alice, 5)).rejects.toThrow("BelowMinimumMint");
+    await mint(alice, 60);
+    await expect(mint(alice, 50)).rejects.toThrow("HolderShareLimitExceeded");
+    await mint(alice, 40);
+
+    const stake = await program.account.userStake.fetch(stakePda(alice.publicKey));
+    expect(stake.quantity.toNumber()).toBe(100);
+  });
+
+  it("LIM-2: Holder count is capped and freed when a holder fully exits", async () => {
+    await setLimits(10, 100, 1);
+    expect((await program.account.vault.fetch(limVaultPda)).holderCount).toBe(1);
+
+    await expect(mint(bob, 10)).rejects.toThrow("HolderCountLimitReached");
+
+    await program.methods
+      .redeemShares(new anchor.BN(100))
+      .accounts({
+        vault: limVaultPda,
+        vaultSigner: limVaultSignerPda,
+        payer: alice.publicKey,
+        shareholder: stakePda(alice.publicKey),
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        investorShareAta: getAssociatedTokenAddressSync(limShareMintPda, alice.publicKey),
+        vaultShareMint: limShareMintPda,
+        payerAta: paymentAtas.get(alice.publicKey.toBase58())!,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint: limPaymentMint,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([alice])
+      .rpc();
+    expect((await program.account.vault.fetch(limVaultPda)).holderCount).toBe(0);
+
+    await mint(bob, 10);
+    expect((await program.account.vault.fetch(limVaultPda)).holderCount).toBe(1);
+  });
+});
+
+describe("Subscription Window", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.TokenizedYieldInfrastructure as Program<TokenizedYieldInfrastructure>;
+
+  const payer = provider.wallet;
+
+  let subOwner: anchor.web3.Keypair;
+  let investor: anchor.web3.Keypair;
+  let subPaymentMint: PublicKey;
+  let investorPaymentAta: PublicKey;
+  let chainNow: number;
+
+  const vaultPda = (vaultId: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), subOwner.publicKey.toBuffer(), new anchor.BN(vaultId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+  const pda = (seed: string, vault: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from(seed), vault.toBuffer()], program.programId)[0];
+
+  const initVault = (vaultId: number, start: number, end: number, softCap = 0) => {
+    const vault = vaultPda(vaultId);
+    return program.methods
+      .initializeVault(new anchor.BN(vaultId), "Window Vault", new anchor.BN(1_000_000), new anchor.BN(100), 0, new anchor.BN(start), new anchor.BN(end), new anchor.BN(softCap))
+      .accounts({
+        owner: subOwner.publicKey,
+        vault,
+        vaultSigner: pda("vault_signer", vault),
+        paymentMint: subPaymentMint,
+        principalVault: pda("principal-vault", vault),
+        revenueVault: pda("revenue-vault", vault),
+        treasury: pda("treasury", vault),
+        vaultShareMint: pda("vault_share_mint", vault),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([subOwner])
+      .rpc();
+  };
+
+  const stakePda = (vault: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder"), vault.toBuffer(), investor.publicKey.toBuffer()],
+      program.programId
+    )[0];
+
+  const mint = (vault: PublicKey, amount: number) => {
+    const shareMint = pda("vault_share_mint", vault);
+    return program.methods
+      .mintShares(new anchor.BN(amount))
+      .accounts({
+        vault,
+        vaultSigner: pda("vault_signer", vault),
+        payer: investor.publicKey,
+        payerAta: investorPaymentAta,
+        principalVault: pda("principal-vault", vault),
+        revenueVault: pda("revenue-vault", vault),
+        vaultShareMint: shareMint,
+        investorShareAta: getAssociatedTokenAddressSync(shareMint, investor.publicKey),
+        shareholder: stakePda(vault),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        paymentMint: subPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor])
+      .rpc();
+  };
+
+  beforeAll(async () => {
+    subOwner = anchor.web3.Keypair.generate();
+    investor = anchor.web3.Keypair.generate();
+    for (const kp of [subOwner, investor]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(kp.publicKey, 5e9)
+      );
+    }
+
+    subPaymentMint = await createMint(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      payer.publicKey,
+      null,
+      6
+    );
+    investorPaymentAta = await createAccount(provider.connection, (payer as anchor.Wallet).payer, subPaymentMint, investor.publicKey);
+    await mintTo(provider.connection, (payer as anchor.Wallet).payer, subPaymentMint, investorPaymentAta, payer.publicKey, 1_000_000);
+
+    chainNow = (await provider.connection.getBlockTime(await provider.connection.getSlot()))!;
+  });
+
+  it("SUB-1: Windows that close in the past or before they open are rejected", async () => {
+    await expect(initVault(0, chainNow + 3600, chainNow + 1800)).rejects.toThrow("InvalidSubscriptionWindow");
+    await expect(initVault(0, 0, chainNow - 60)).rejects.toThrow("InvalidSubscriptionWindow");
+  });
+
+  it("SUB-2: Minting is rejected before the window opens", async () => {
+    await initVault(0, chainNow + 3600, chainNow + 7200);
+    await expect(mint(vaultPda(0), 10)).rejects.toThrow("SubscriptionNotStarted");
+  });
+
+  it("SUB-3: Only the authority can extend, and only to a later end", async () => {
+    const vault = vaultPda(0);
+    const extend = (newEnd: number, signer: anchor.web3.Keypair) =>
+      program.methods
+        .extendSubscription(new anchor.BN(newEnd))
+        .accounts({ vault, authority: signer.publicKey })
+        .signers([signer])
+        .rpc();
+
+    await expect(extend(chainNow + 10_800, investor)).rejects.toThrow("Unauthorized");
+    await expect(extend(chainNow + 5400, subOwner)).rejects.toThrow("InvalidSubscriptionWindow");
+    await extend(chainNow + 10_800, subOwner);
+
+    const state = await program.account.vault.fetch(vault);
+    expect(state.subscriptionStart.toNumber()).toBe(chainNow + 3600);
+    expect(state.subscriptionEnd.toNumber()).toBe(chainNow + 10_800);
+  });
+
+  it("SUB-4: A raise below its soft cap ends in refunds", async () => {
+    const vault = vaultPda(1);
+    await expect(initVault(1, 0, 0, 500)).rejects.toThrow("InvalidSoftCap");
+    await initVault(1, 0, chainNow + 3, 500);
+    await mint(vault, 100);
+
+    const finalize = () =>
+      program.methods.finalizeSubscription().accounts({ vault, cranker: payer.publicKey }).rpc();
+    await expect(finalize()).rejects.toThrow("SubscriptionStillOpen");
+    await expect(
+      program.methods
+        .setVaultStatus({ active: {} })
+        .accounts({ vault, authority: subOwner.publicKey })
+        .signers([subOwner])
+        .rpc()
+    ).rejects.toThrow("SoftCapNotReached");
+
+    await new Promise((resolve) => setTimeout(resolve, 4000));
+    await finalize();
+    expect((await program.account.vault.fetch(vault)).status).toEqual({ refunding: {} });
+
+    const shareMint = pda("vault_share_mint", vault);
+    const refundAccounts = {
+      vault,
+      vaultSigner: pda("vault_signer", vault),
+      holder: investor.publicKey,
+      shareholder: stakePda(vault),
+      principalVault: pda("principal-vault", vault),
+      holderShareAccount: getAssociatedTokenAddressSync(shareMint, investor.publicKey),
+      vaultShareMint: shareMint,
+      holderPaymentAccount: investorPaymentAta,
+      paymentMint: subPaymentMint,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      shareTokenProgram: TOKEN_PROGRAM_ID,
+    };
+
+    // SPL share mints have no permanent delegate, so only the holder can burn
+    await expect(
+      program.methods.refund().accounts({ ...refundAccounts, cranker: payer.publicKey }).rpc()
+    ).rejects.toThrow("RefundRequiresHolder");
+
+    const before = (await getAccount(provider.connection, investorPaymentAta)).amount;
+    await program.methods
+      .refund()
+      .accounts({ ...refundAccounts, cranker: investor.publicKey })
+      .signers([investor])
+      .rpc();
+    const after = (await getAccount(provider.connection, investorPaymentAta)).amount;
+
+    expect(Number(after - before)).toBe(100 * 100);
+    const state = await program.account.vault.fetch(vault);
+    expect(state.mintedShares.toNumber()).toBe(0);
+    expect(state.holderCount).toBe(0);
+    expect((await program.account.userStake.fetch(stakePda(vault))).quantity.toNumber()).toBe(0);
+  });
+});
+
+describe("Principal Deployment", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.TokenizedYieldInfrastructure as Program<TokenizedYieldInfrastructure>;
+
+  const payer = provider.wallet;
+
+  let depOwner: anchor.web3.Keypair;
+  let depVaultPda: PublicKey;
+  let depPaymentMint: PublicKey;
+  let operatorAta: PublicKey;
+
+  const pda = (seed: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from(seed), depVaultPda.toBuffer()], program.programId)[0];
+
+  beforeAll(async () => {
+    depOwner = anchor.web3.Keypair.generate();
+    await provider.connection.confirmTransaction(
+      await provider.connection.requestAirdrop(depOwner.publicKey, 5e9)
+    );
+
+    depPaymentMint = await createMint(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      payer.publicKey,
+      null,
+      6
+    );
+    operatorAta = await createAccount(provider.connection, (payer as anchor.Wallet).payer, depPaymentMint, depOwner.publicKey);
+    await mintTo(provider.connection, (payer as anchor.Wallet).payer, depPaymentMint, operatorAta, payer.publicKey, 1_000_000);
+
+    [depVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), depOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .initializeVault(new anchor.BN(0), "Farm Vault", new anchor.BN(1_000_000), new anchor.BN(100), 0, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: depOwner.publicKey,
+        vault: depVaultPda,
+        vaultSigner: pda("vault_signer"),
+        paymentMint: depPaymentMint,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        treasury: pda("treasury"),
+        vaultShareMint: pda("vault_share_mint"),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([depOwner])
+      .rpc();
+  });
+
+  it("DEP-1: Principal only leaves for an operator registered through the timelock", async () => {
+    const release = () =>
+      program.methods
+        .releasePrincipal(new anchor.BN(100))
+        .accounts({
+          vault: depVaultPda,
+          authority: depOwner.publicKey,
+          vaultSigner: pda("vault_signer"),
+          principalVault: pda("principal-vault"),
+          operator: operatorAta,
+          operatorDebt: pda("operator_debt"),
+          paymentMint: depPaymentMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([depOwner])
+        .rpc();
+
+    await program.methods
+      .setVaultStatus({ active: {} })
+      .accounts({ vault: depVaultPda, authority: depOwner.publicKey })
+      .signers([depOwner])
+      .rpc();
+    await expect(release()).rejects.toThrow("InvalidPrincipalOperator");
+
+    const proposalPda = PublicKey.findProgramAddressSync(
+      [Buffer.from("proposal"), depVaultPda.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+    const propose = (newOperator: PublicKey) =>
+      program.methods
+        .proposeGovernanceChange({ updatePrincipalOperator: { newOperator } })
+        .accounts({
+          vault: depVaultPda,
+          authority: depOwner.publicKey,
+          proposal: proposalPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([depOwner])
+        .rpc();
+
+    await expect(propose(PublicKey.default)).rejects.toThrow("InvalidPrincipalOperator");
+    await propose(operatorAta);
+    const proposal = await program.account.governanceProposal.fetch(proposalPda);
+    expect(proposal.status).toEqual({ queued: {} });
+    expect((await program.account.vault.fetch(depVaultPda)).principalOperator.equals(PublicKey.default)).toBe(true);
+  });
+
+  it("DEP-2: Returns are capped at the principal actually deployed", async () => {
+    await expect(
+      program.methods
+        .returnPrincipal(new anchor.BN(100))
+        .accounts({
+          vault: depVaultPda,
+          payer: depOwner.publicKey,
+          payerAta: operatorAta,
+          principalVault: pda("principal-vault"),
+          paymentMint: depPaymentMint,
+          operatorDebt: pda("operator_debt"),
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([depOwner])
+        .rpc()
+    ).rejects.toThrow("ReturnExceedsDeployed");
+    expect((await program.account.vault.fetch(depVaultPda)).principalDeployed.toNumber()).toBe(0);
+  });
+
+  it("DEP-3: The debt ledger validates its terms and only defaults an overdue operator", async () => {
+    const chainNow = (await provider.connection.getBlockTime(await provider.connection.getSlot()))!;
+    const openLedger = (installment: number, interval: number) =>
+      program.methods
+        .initializeOperatorDebt(depOwner.publicKey, 800, new anchor.BN(installment), new anchor.BN(interval), new anchor.BN(chainNow + 86_400), new anchor.BN(3600))
+        .accounts({
+          vault: depVaultPda,
+          authority: depOwner.publicKey,
+          operatorDebt: pda("operator_debt"),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([depOwner])
+        .rpc();
+
+    await expect(openLedger(0, 86_400)).rejects.toThrow("InvalidDebtTerms");
+    await expect(openLedger(1000, 0)).rejects.toThrow("InvalidDebtTerms");
+    await openLedger(1000, 30 * 86_400);
+
+    const debt = await program.account.operatorDebt.fetch(pda("operator_debt"));
+    expect(debt.interestRateBps).toBe(800);
+    expect(debt.defaulted).toBe(false);
+    expect((await program.account.vault.fetch(depVaultPda)).hasOperatorDebt).toBe(true);
+
+    await expect(
+      program.methods
+        .markOperatorDefault()
+        .accounts({ vault: depVaultPda, authority: depOwner.publicKey, operatorDebt: pda("operator_debt") })
+        .signers([depOwner])
+        .rpc()
+    ).rejects.toThrow("DebtNotOverdue");
+  });
+
+  it("DEP-4: Revenue deposits must pass the ledger once it exists", async () => {
+    const stakePda = PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder"), depVaultPda.toBuffer(), depOwner.publicKey.toBuffer()],
+      program.programId
+    )[0];
+    await program.methods
+      .mintShares(new anchor.BN(10))
+      .accounts({
+        vault: depVaultPda,
+        vaultSigner: pda("vault_signer"),
+        payer: depOwner.publicKey,
+        payerAta: operatorAta,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        vaultShareMint: pda("vault_share_mint"),
+        investorShareAta: getAssociatedTokenAddressSync(pda("vault_share_mint"), depOwner.publicKey),
+        shareholder: stakePda,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        paymentMint: depPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([depOwner])
+      .rpc();
+
+    const deposit = (operatorDebt: PublicKey | null) =>
+      program.methods
+        .depositRevenue(new anchor.BN(500))
+        .accounts({
+          vault: depVaultPda,
+          payer: depOwner.publicKey,
+          payerAta: operatorAta,
+          revenueVault: pda("revenue-vault"),
+          treasury: pda("treasury"),
+          vaultSigner: pda("vault_signer"),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint: depPaymentMint,
+          operatorDebt,
+        } as any)
+        .signers([depOwner])
+        .rpc();
+
+    await expect(deposit(null)).rejects.toThrow("OperatorDebtRequired");
+    await deposit(pda("operator_debt"));
+    expect((await getAccount(provider.connection, pda("revenue-vault"))).amount).toBe(BigInt(500));
+  });
+});
+
+describe("Impairment", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.TokenizedYieldInfrastructure as Program<TokenizedYieldInfrastructure>;
+
+  const payer = provider.wallet;
+
+  let impOwner: anchor.web3.Keypair;
+  let investor: anchor.web3.Keypair;
+  let impVaultPda: PublicKey;
+  let impPaymentMint: PublicKey;
+  let investorPaymentAta: PublicKey;
+
+  const pda = (seed: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from(seed), impVaultPda.toBuffer()], program.programId)[0];
+  const stakePda = () =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder"), impVaultPda.toBuffer(), investor.publicKey.toBuffer()],
+      program.programId
+    )[0];
+
+  const writeDown = (impairmentBps: number, signer: anchor.web3.Keypair) =>
+    program.methods
+      .writeDown(impairmentBps)
+      .accounts({ vault: impVaultPda, updater: signer.publicKey })
+      .signers([signer])
+      .rpc();
+
+  beforeAll(async () => {
+    impOwner = anchor.web3.Keypair.generate();
+    investor = anchor.web3.Keypair.generate();
+    for (const kp of [impOwner, investor]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(kp.publicKey, 5e9)
+      );
+    }
+
+    impPaymentMint = await createMint(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      payer.publicKey,
+      null,
+      6
+    );
+    investorPaymentAta = await createAccount(provider.connection, (payer as anchor.Wallet).payer, impPaymentMint, investor.publicKey);
+    await mintTo(provider.connection, (payer as anchor.Wallet).payer, impPaymentMint, investorPaymentAta, payer.publicKey, 1_000_000);
+
+    [impVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), impOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .initializeVault(new anchor.BN(0), "Impaired Farm", new anchor.BN(1_000_000), new anchor.BN(100), 0, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: impOwner.publicKey,
+        vault: impVaultPda,
+        vaultSigner: pda("vault_signer"),
+        paymentMint: impPaymentMint,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        treasury: pda("treasury"),
+        vaultShareMint: pda("vault_share_mint"),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([impOwner])
+      .rpc();
+
+    await program.methods
+      .mintShares(new anchor.BN(100))
+      .accounts({
+        vault: impVaultPda,
+        vaultSigner: pda("vault_signer"),
+        payer: investor.publicKey,
+        payerAta: investorPaymentAta,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        vaultShareMint: pda("vault_share_mint"),
+        investorShareAta: getAssociatedTokenAddressSync(pda("vault_share_mint"), investor.publicKey),
+        shareholder: stakePda(),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        paymentMint: impPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor])
+      .rpc();
+
+    await program.methods
+      .setVaultStatus({ active: {} })
+      .accounts({ vault: impVaultPda, authority: impOwner.publicKey })
+      .signers([impOwner])
+      .rpc();
+  });
+
+  it("IMP-1: Only the authority or oracle can write down, and only upwards", async () => {
+    await expect(writeDown(2500, investor)).rejects.toThrow("Unauthorized");
+
+    await writeDown(2500, impOwner);
+    const vault = await program.account.vault.fetch(impVaultPda);
+    expect(vault.impairmentBps).toBe(2500);
+    // 100 shares * 100 par * 25%
+    expect(vault.principalWrittenDown.toNumber()).toBe(2500);
+    expect(vault.pausedInstructions & 1).toBe(1);
+
+    await expect(writeDown(2000, impOwner)).rejects.toThrow("InvalidImpairment");
+    await expect(writeDown(10_001, impOwner)).rejects.toThrow("InvalidImpairment");
+  });
+
+  it("IMP-2: Redemptions pay the written-down value per share", async () => {
+    const before = (await getAccount(provider.connection, investorPaymentAta)).amount;
+
+    await program.methods
+      .redeemShares(new anchor.BN(40))
+      .accounts({
+        vault: impVaultPda,
+        vaultSigner: pda("vault_signer"),
+        payer: investor.publicKey,
+        shareholder: stakePda(),
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        investorShareAta: getAssociatedTokenAddressSync(pda("vault_share_mint"), investor.publicKey),
+        vaultShareMint: pda("vault_share_mint"),
+        payerAta: investorPaymentAta,
+        paymentMint: impPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor])
+      .rpc();
+
+    const after = (await getAccount(provider.connection, investorPaymentAta)).amount;
+    // 40 shares * 100 par * 75%
+    expect(Number(after - before)).toBe(3000);
+    expect((await getAccount(provider.connection, pda("principal-vault"))).amount).toBe(BigInt(7000));
+  });
+
+  it("IMP-3: A further write-down does not push back the emergency exit", async () => {
+    const firstWrittenDownAt = (await program.account.vault.fetch(impVaultPda)).writtenDownAt.toNumber();
+    expect(firstWrittenDownAt).toBeGreaterThan(0);
+
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+    await writeDown(2501, impOwner);
+
+    const vault = await program.account.vault.fetch(impVaultPda);
+    expect(vault.impairmentBps).toBe(2501);
+    expect(vault.writtenDownAt.toNumber()).toBe(firstWrittenDownAt);
+  });
+});
+
+describe("Emergency Exit", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.TokenizedYieldInfrastructure as Program<TokenizedYieldInfrastructure>;
+
+  const payer = provider.wallet;
+  const PAUSE_REDEEM = 1 << 3;
+
+  let exitOwner: anchor.web3.Keypair;
+  let investor: anchor.web3.Keypair;
+  let exitVaultPda: PublicKey;
+  let exitPaymentMint: PublicKey;
+  let investorPaymentAta: PublicKey;
+
+  const pda = (seed: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from(seed), exitVaultPda.toBuffer()], program.programId)[0];
+  const stakePda = () =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder"), exitVaultPda.toBuffer(), investor.publicKey.toBuffer()],
+      program.programId
+    )[0];
+
+  const emergencyWithdraw = () =>
+    program.methods
+      .emergencyWithdraw()
+      .accounts({
+        vault: exitVaultPda,
+        vaultSigner: pda("vault_signer"),
+        holder: investor.publicKey,
+        shareholder: stakePda(),
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        holderShareAccount: getAssociatedTokenAddressSync(pda("vault_share_mint"), investor.publicKey),
+        vaultShareMint: pda("vault_share_mint"),
+        holderPaymentAccount: investorPaymentAta,
+        paymentMint: exitPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor])
+      .rpc();
+
+  const mintShares = (holder: anchor.web3.Keypair, holderPaymentAta: PublicKey, amount: number) =>
+    program.methods
+      .mintShares(new anchor.BN(amount))
+      .accounts({
+        vault: exitVaultPda,
+        vaultSigner: pda("vault_signer"),
+        payer: holder.publicKey,
+        payerAta: holderPaymentAta,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        vaultShareMint: pda("vault_share_mint"),
+        investorShareAta: getAssociatedTokenAddressSync(pda("vault_share_mint"), holder.publicKey),
+        shareholder: PublicKey.findProgramAddressSync(
+          [Buffer.from("shareholder"), exitVaultPda.toBuffer(), holder.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        paymentMint: exitPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([holder])
+      .rpc();
+
+  beforeAll(async () => {
+    exitOwner = anchor.web3.Keypair.generate();
+    investor = anchor.web3.Keypair.generate();
+    for (const kp of [exitOwner, investor]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(kp.publicKey, 5e9)
+      );
+    }
+
+    exitPaymentMint = await createMint(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      payer.publicKey,
+      null,
+      6
+    );
+    investorPaymentAta = await createAccount(provider.connection, (payer as anchor.Wallet).payer, exitPaymentMint, investor.publicKey);
+    await mintTo(provider.connection, (payer as anchor.Wallet).payer, exitPaymentMint, investorPaymentAta, payer.publicKey, 1_000_000);
+
+    [exitVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), exitOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .initializeVault(new anchor.BN(0), "Exit Vault", new anchor.BN(1_000_000), new anchor.BN(100), 0, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: exitOwner.publicKey,
+        vault: exitVaultPda,
+        vaultSigner: pda("vault_signer"),
+        paymentMint: exitPaymentMint,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        treasury: pda("treasury"),
+        vaultShareMint: pda("vault_share_mint"),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([exitOwner])
+      .rpc();
+
+    await mintShares(investor, investorPaymentAta, 50);
+  });
+
+  it("EMX-1: The exit stays shut until a halt has lasted the full delay", async () => {
+    await expect(emergencyWithdraw()).rejects.toThrow("EmergencyExitNotOpen");
+
+    await program.methods
+      .pause(PAUSE_REDEEM)
+      .accounts({ vault: exitVaultPda, pauser: exitOwner.publicKey })
+      .signers([exitOwner])
+      .rpc();
+    let vault = await program.account.vault.fetch(exitVaultPda);
+    expect(vault.redemptionsHaltedAt.toNumber()).toBeGreaterThan(0);
+    expect(vault.emergencyExitDelay.toNumber()).toBe(14 * 86_400);
+    await expect(emergencyWithdraw()).rejects.toThrow("EmergencyExitNotOpen");
+
+    await program.methods
+      .unpause(PAUSE_REDEEM)
+      .accounts({ vault: exitVaultPda, authority: exitOwner.publicKey })
+      .signers([exitOwner])
+      .rpc();
+    vault = await program.account.vault.fetch(exitVaultPda);
+    expect(vault.redemptionsHaltedAt.toNumber()).toBe(0);
+    expect(vault.mintedShares.toNumber()).toBe(50);
+  });
+
+  it("EMX-2: The exit delay is bounded and changes only through the timelock", async () => {
+    const proposalPda = PublicKey.findProgramAddressSync(
+      [Buffer.from("proposal"), exitVaultPda.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+    const propose = (newDelay: number) =>
+      program.methods
+        .proposeGovernanceChange({ updateEmergencyExitDelay: { newDelay: new anchor.BN(newDelay) } })
+        .accounts({
+          vault: exitVaultPda,
+          authority: exitOwner.publicKey,
+          proposal: proposalPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([exitOwner])
+        .rpc();
+
+    await expect(propose(3600)).rejects.toThrow("InvalidEmergencyExitDelay");
+    await expect(propose(91 * 86_400)).rejects.toThrow("InvalidEmergencyExitDelay");
+    await propose(7 * 86_400);
+    expect((await program.account.governanceProposal.fetch(proposalPda)).status).toEqual({ queued: {} });
+    expect((await program.account.vault.fetch(exitVaultPda)).emergencyExitDelay.toNumber()).toBe(14 * 86_400);
+  });
+});
+
+describe("Emergency Exit (clock warp)", () => {
+  // Runs in-process on bankrun so the 14-day exit delay can be skipped by warping the clock
+  process.env.SBF_OUT_DIR ??= path.join(__dirname, "../target/deploy");
+  const idl = anchor.workspace.TokenizedYieldInfrastructure.idl as TokenizedYieldInfrastructure;
+  const PAUSE_REDEEM = 1 << 3;
+
+  let context: ProgramTestContext;
+  let provider: BankrunProvider;
+  let program: Program<TokenizedYieldInfrastructure>;
+
+  let exitOwner: anchor.web3.Keypair;
+  let investor: anchor.web3.Keypair;
+  let other: anchor.web3.Keypair;
+  let exitVaultPda: PublicKey;
+  let exitPaymentMint: PublicKey;
+
+  const pda = (seed: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from(seed), exitVaultPda.toBuffer()], program.programId)[0];
+  const stakePda = (holder: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder"), exitVaultPda.toBuffer(), holder.toBuffer()],
+      program.programId
+    )[0];
+  const paymentAta = (holder: PublicKey) => getAssociatedTokenAddressSync(exitPaymentMint, holder);
+  const shareAta = (holder: PublicKey) => getAssociatedTokenAddressSync(pda("vault_share_mint"), holder);
+
+  const send = async (instructions: anchor.web3.TransactionInstruction[], signers: anchor.web3.Keypair[] = []) => {
+    const tx = new anchor.web3.Transaction().add(...instructions);
+    tx.recentBlockhash = context.lastBlockhash;
+    tx.feePayer = context.payer.publicKey;
+    tx.sign(context.payer, ...signers);
+    await context.banksClient.processTransaction(tx);
+  };
+  const balance = async (tokenAccount: PublicKey) => {
+    const account = await context.banksClient.getAccount(tokenAccount);
+    return AccountLayout.decode(Buffer.from(account!.data)).amount;
+  };
+
+  const mintShares = (holder: anchor.web3.Keypair, amount: number) =>
+    program.methods
+      .mintShares(new anchor.BN(amount))
+      .accounts({
+        vault: exitVaultPda,
+        vaultSigner: pda("vault_signer"),
+        payer: holder.publicKey,
+        payerAta: paymentAta(holder.publicKey),
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        vaultShareMint: pda("vault_share_mint"),
+        investorShareAta: shareAta(holder.publicKey),
+        shareholder: stakePda(holder.publicKey),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        paymentMint: exitPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([holder])
+      .rpc();
+
+  beforeAll(async () => {
+    context = await start([{ name: "tokenized_yield_infrastructure", programId: new PublicKey(idl.address) }], []);
+    provider = new BankrunProvider(context);
+    program = new Program<TokenizedYieldInfrastructure>(idl, provider);
+
+    exitOwner = anchor.web3.Keypair.generate();
+    investor = anchor.web3.Keypair.generate();
+    other = anchor.web3.Keypair.generate();
+    const mint = anchor.web3.Keypair.generate();
+    exitPaymentMint = mint.publicKey;
+
+    const payer = context.payer.publicKey;
+    await send(
+      [
+        ...[exitOwner, investor, other].map((kp) =>
+          SystemProgram.transfer({ fromPubkey: payer, toPubkey: kp.publicKey, lamports: 5e9 })
+        ),
+        SystemProgram.createAccount({
+          fromPubkey: payer,
+          newAccountPubkey: exitPaymentMint,
+          space: MINT_SIZE,
+          lamports: Number((await context.banksClient.getRent()).minimumBalance(BigInt(MINT_SIZE))),
+          programId: TOKEN_PROGRAM_ID,
+        }),
+        createInitializeMint2Instruction(exitPaymentMint, 6, payer, null),
+      ],
+      [mint]
+    );
+    for (const holder of [payer, investor.publicKey, other.publicKey]) {
+      await send([
+        createAssociatedTokenAccountInstruction(payer, paymentAta(holder), holder, exitPaymentMint),
+        createMintToInstruction(exitPaymentMint, paymentAta(holder), payer, 1_000_000),
+      ]);
+    }
+
+    [exitVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), exitOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+    await program.methods
+      .initializeVault(new anchor.BN(0), "Exit Vault", new anchor.BN(1_000_000), new anchor.BN(100), 0, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: exitOwner.publicKey,
+        vault: exitVaultPda,
+        vaultSigner: pda("vault_signer"),
+        paymentMint: exitPaymentMint,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        treasury: pda("treasury"),
+        vaultShareMint: pda("vault_share_mint"),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([exitOwner])
+      .rpc();
+
+    await mintShares(investor, 50);
+    await mintShares(other, 50);
+  });
+
+  it("EMX-3: A leaver takes its slice and own rewards; the remaining holder can still harvest and redeem", async () => {
+    // 1_000 of revenue over 100 shares: 500 accrues to each holder
+    await program.methods
+      .depositRevenue(new anchor.BN(1_000))
+      .accounts({
+        vault: exitVaultPda,
+        payer: context.payer.publicKey,
+        payerAta: paymentAta(context.payer.publicKey),
+        revenueVault: pda("revenue-vault"),
+        treasury: pda("treasury"),
+        vaultSigner: pda("vault_signer"),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint: exitPaymentMint,
+      })
+      .rpc();
+
+    await program.methods
+      .pause(PAUSE_REDEEM)
+      .accounts({ vault: exitVaultPda, pauser: exitOwner.publicKey })
+      .signers([exitOwner])
+      .rpc();
+
+    // Jump past the default 14-day delay measured from the halt
+    const haltedAt = (await program.account.vault.fetch(exitVaultPda)).redemptionsHaltedAt.toNumber();
+    const clock = await context.banksClient.getClock();
+    context.setClock(
+      new Clock(clock.slot, clock.epochStartTimestamp, clock.epoch, clock.leaderScheduleEpoch, BigInt(haltedAt + 14 * 86_400))
+    );
+
+    const before = await balance(paymentAta(investor.publicKey));
+    await program.methods
+      .emergencyWithdraw()
+      .accounts({
+        vault: exitVaultPda,
+        vaultSigner: pda("vault_signer"),
+        holder: investor.publicKey,
+        shareholder: stakePda(investor.publicKey),
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        holderShareAccount: shareAta(investor.publicKey),
+        vaultShareMint: pda("vault_share_mint"),
+        holderPaymentAccount: paymentAta(investor.publicKey),
+        paymentMint: exitPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor])
+      .rpc();
+
+    // Half of the 10_000 principal plus its own 500 of rewards
+    expect(Number((await balance(paymentAta(investor.publicKey))) - before)).toBe(5_500);
+    expect(await balance(pda("principal-vault"))).toBe(BigInt(5_000));
+    expect(await balance(pda("revenue-vault"))).toBe(BigInt(500));
+    expect((await program.account.vault.fetch(exitVaultPda)).mintedShares.toNumber()).toBe(50);
+    const leaver = await program.account.userStake.fetch(stakePda(investor.publicKey));
+    expect(leaver.quantity.toNumber()).toBe(0);
+    expect(leaver.pendingRewards.toNumber()).toBe(0);
+
+    await program.methods
+      .unpause(PAUSE_REDEEM)
+      .accounts({ vault: exitVaultPda, authority: exitOwner.publicKey })
+      .signers([exitOwner])
+      .rpc();
+
+    const otherBefore = await balance(paymentAta(other.publicKey));
+    await program.methods
+      .harvest()
+      .accounts({
+        vault: exitVaultPda,
+        vaultSigner: pda("vault_signer"),
+        payer: other.publicKey,
+        shareholder: stakePda(other.publicKey),
+        revenueVault: pda("revenue-vault"),
+        userAta: paymentAta(other.publicKey),
+        paymentMint: exitPaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([other])
+      .rpc();
+    await program.methods
+      .redeemShares(new anchor.BN(50))
+      .accounts({
+        vault: exitVaultPda,
+        vaultSigner: pda("vault_signer"),
+        payer: other.publicKey,
+        shareholder: stakePda(other.publicKey),
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        investorShareAta: shareAta(other.publicKey),
+        vaultShareMint: pda("vault_share_mint"),
+        payerAta: paymentAta(other.publicKey),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        paymentMint: exitPaymentMint,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([other])
+      .rpc();
+
+    expect(Number((await balance(paymentAta(other.publicKey))) - otherBefore)).toBe(5_500);
+    expect((await program.account.vault.fetch(exitVaultPda)).mintedShares.toNumber()).toBe(0);
+  });
+});
+
+describe("Redemption Queue", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.TokenizedYieldInfrastructure as Program<TokenizedYieldInfrastructure>;
+
+  const payer = provider.wallet;
+
+  let queueOwner: anchor.web3.Keypair;
+  let investor: anchor.web3.Keypair;
+  let queueVaultPda: PublicKey;
+  let queuePaymentMint: PublicKey;
+  let investorPaymentAta: PublicKey;
+  let investorShareAta: PublicKey;
+
+  const pda = (seed: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from(seed), queueVaultPda.toBuffer()], program.programId)[0];
+  const stakePda = () =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("shareholder"), queueVaultPda.toBuffer(), investor.publicKey.toBuffer()],
+      program.programId
+    )[0];
+  const requestPda = (requestId: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("redemption_request"), queueVaultPda.toBuffer(), new anchor.BN(requestId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+  const requestRedemption = (requestId: number, amount: number) =>
+    program.methods
+      .requestRedemption(new anchor.BN(amount))
+      .accounts({
+        vault: queueVaultPda,
+        holder: investor.publicKey,
+        shareholder: stakePda(),
+        holderShareAccount: investorShareAta,
+        vaultShareMint: pda("vault_share_mint"),
+        request: requestPda(requestId),
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([investor])
+      .rpc();
+
+  const crank = (requestId: number) =>
+    program.methods
+      .processRedemptions()
+      .accounts({
+        vault: queueVaultPda,
+        vaultSigner: pda("vault_signer"),
+        cranker: queueOwner.publicKey,
+        request: requestPda(requestId),
+        shareholder: stakePda(),
+        principalVault: pda("principal-vault"),
+        holderPaymentAccount: investorPaymentAta,
+        paymentMint: queuePaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([queueOwner])
+      .rpc();
+
+  beforeAll(async () => {
+    queueOwner = anchor.web3.Keypair.generate();
+    investor = anchor.web3.Keypair.generate();
+    for (const kp of [queueOwner, investor]) {
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(kp.publicKey, 5e9)
+      );
+    }
+
+    queuePaymentMint = await createMint(
+      provider.connection,
+      (payer as anchor.Wallet).payer,
+      payer.publicKey,
+      null,
+      6
+    );
+    investorPaymentAta = await createAccount(provider.connection, (payer as anchor.Wallet).payer, queuePaymentMint, investor.publicKey);
+    await mintTo(provider.connection, (payer as anchor.Wallet).payer, queuePaymentMint, investorPaymentAta, payer.publicKey, 1_000_000);
+
+    [queueVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), queueOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+    investorShareAta = getAssociatedTokenAddressSync(pda("vault_share_mint"), investor.publicKey);
+
+    await program.methods
+      .initializeVault(new anchor.BN(0), "Queue Vault", new anchor.BN(1_000_000), new anchor.BN(100), 0, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
+      .accounts({
+        owner: queueOwner.publicKey,
+        vault: queueVaultPda,
+        vaultSigner: pda("vault_signer"),
+        paymentMint: queuePaymentMint,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        treasury: pda("treasury"),
+        vaultShareMint: pda("vault_share_mint"),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      })
+      .signers([queueOwner])
+      .rpc();
+
+    await program.methods
+      .mintShares(new anchor.BN(100))
+      .accounts({
+        vault: queueVaultPda,
+        vaultSigner: pda("vault_signer"),
+        payer: investor.publicKey,
+        payerAta: investorPaymentAta,
+        principalVault: pda("principal-vault"),
+        revenueVault: pda("revenue-vault"),
+        vaultShareMint: pda("vault_share_mint"),
+        investorShareAta,
+        shareholder: stakePda(),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        paymentMint: queuePaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor])
+      .rpc();
+
+    await program.methods
+      .setVaultStatus({ active: {} })
+      .accounts({ vault: queueVaultPda, authority: queueOwner.publicKey })
+      .signers([queueOwner])
+      .rpc();
+  });
+
+  it("RQ-1: Queued shares are burned but stay in the ledger", async () => {
+    await requestRedemption(0, 30);
+
+    expect((await getAccount(provider.connection, investorShareAta)).amount).toBe(BigInt(70));
+    const stake = await program.account.userStake.fetch(stakePda());
+    expect(stake.quantity.toNumber()).toBe(100);
+    expect(stake.queuedQuantity.toNumber()).toBe(30);
+    const vault = await program.account.vault.fetch(queueVaultPda);
+    expect(vault.mintedShares.toNumber()).toBe(100);
+    expect(vault.queuedShares.toNumber()).toBe(30);
+
+    // Only the 70 unlocked shares can be queued again
+    await expect(requestRedemption(1, 80)).rejects.toThrow("InsufficientShares");
+  });
+
+  it("RQ-2: The crank fills the head of the queue from liquid principal", async () => {
+    const before = (await getAccount(provider.connection, investorPaymentAta)).amount;
+    await crank(0);
+    const after = (await getAccount(provider.connection, investorPaymentAta)).amount;
+    expect(Number(after - before)).toBe(3000);
+
+    const request = await program.account.redemptionRequest.fetch(requestPda(0));
+    expect(request.status).toEqual({ filled: {} });
+    const stake = await program.account.userStake.fetch(stakePda());
+    expect(stake.quantity.toNumber()).toBe(70);
+    expect(stake.queuedQuantity.toNumber()).toBe(0);
+    const vault = await program.account.vault.fetch(queueVaultPda);
+    expect(vault.mintedShares.toNumber()).toBe(70);
+    expect(vault.nextRedemptionToFill.toNumber()).toBe(1);
+  });
+
+  it("RQ-3: Cancelling returns the shares and the crank skips the request", async () => {
+    await requestRedemption(1, 10);
+    await program.methods
+      .cancelRedemption()
+      .accounts({
+        vault: queueVaultPda,
+        vaultSigner: pda("vault_signer"),
+        holder: investor.publicKey,
+        request: requestPda(1),
+        shareholder: stakePda(),
+        holderShareAccount: investorShareAta,
+        vaultShareMint: pda("vault_share_mint"),
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([investor])
+      .rpc();
+    expect((await getAccount(provider.connection, investorShareAta)).amount).toBe(BigInt(70));
+
+    const before = (await getAccount(provider.connection, investorPaymentAta)).amount;
+    await crank(1);
+    expect((await getAccount(provider.connection, investorPaymentAta)).amount).toBe(before);
+    const vault = await program.account.vault.fetch(queueVaultPda);
+    expect(vault.nextRedemptionToFill.toNumber()).toBe(2);
+    expect(vault.queuedShares.toNumber()).toBe(0);
+    expect(vault.mintedShares.toNumber()).toBe(70);
+  });
+
+  it("RQ-4: The crank cancels a frozen holder's request and re-mints its shares", async () => {
+    await requestRedemption(2, 20);
+    expect((await getAccount(provider.connection, investorShareAta)).amount).toBe(BigInt(50));
+
+    await program.methods
+      .setComplianceAuthority(queueOwner.publicKey)
+      .accounts({ vault: queueVaultPda, authority: queueOwner.publicKey })
+      .signers([queueOwner])
+      .rpc();
+    await program.methods
+      .freezeShareholder(7)
+      .accounts({
+        vault: queueVaultPda,
+        officer: queueOwner.publicKey,
+        holder: investor.publicKey,
+        shareholder: stakePda(),
+        holderShareAta: investorShareAta,
+        vaultShareMint: pda("vault_share_mint"),
+        vaultSigner: pda("vault_signer"),
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([queueOwner])
+      .rpc();
+
+    await expect(crank(2)).rejects.toThrow("InvalidTokenAccountOwner");
+    await program.methods
+      .processRedemptions()
+      .accounts({
+        vault: queueVaultPda,
+        vaultSigner: pda("vault_signer"),
+        cranker: queueOwner.publicKey,
+        request: requestPda(2),
+        shareholder: stakePda(),
+        principalVault: pda("principal-vault"),
+        holderPaymentAccount: investorPaymentAta,
+        paymentMint: queuePaymentMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        holderShareAccount: investorShareAta,
+        vaultShareMint: pda("vault_share_mint"),
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([queueOwner])
+      .rpc();
+
+    const shareAccount = await getAccount(provider.connection, investorShareAta);
+    expect(shareAccount.amount).toBe(BigInt(70));
+    expect(shareAccount.isFrozen).toBe(true);
+    expect((await program.account.redemptionRequest.fetch(requestPda(2))).status).toEqual({ cancelled: {} });
+    expect((await program.account.userStake.fetch(stakePda())).queuedQuantity.toNumber()).toBe(0);
+    const vault = await program.account.vault.fetch(queueVaultPda);
+    expect(vault.nextRedemptionToFill.toNumber()).toBe(3);
+    expect(vault.queuedShares.toNumber()).toBe(0);
+    expect(vault.mintedShares.toNumber()).toBe(70);
+  });
+
+  it("RQ-5: Instant redemption is closed while requests are queued", async () => {
+    await program.methods
+      .unfreezeShareholder()
+      .accounts({
+        vault: queueVaultPda,
+        officer: queueOwner.publicKey,
+        holder: investor.publicKey,
+        shareholder: stakePda(),
+        holderShareAta: investorShareAta,
+        vaultShareMint: pda("vault_share_mint"),
+        vaultSigner: pda("vault_signer"),
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([queueOwner])
+      .rpc();
+    await requestRedemption(3, 10);
+
+    const redeem = (amount: number) =>
+      program.methods
+        .redeemShares(new anchor.BN(amount))
+        .accounts({
+          vault: queueVaultPda,
+          vaultSigner: pda("vault_signer"),
+          payer: investor.publicKey,
+          shareholder: stakePda(),
+          principalVault: pda("principal-vault"),
+          revenueVault: pda("revenue-vault"),
+          investorShareAta,
+          vaultShareMint: pda("vault_share_mint"),
+          payerAta: investorPaymentAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          paymentMint: queuePaymentMint,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([investor])
+        .rpc();
+    await expect(redeem(10)).rejects.toThrow("RedemptionQueueRequired");
+
+    // Once the queue drains and no notice period is set, instant exits reopen
+    await crank(3);
+    expect((await program.account.vault.fetch(queueVaultPda)).queuedShares.toNumber()).toBe(0);
+    await redeem(10);
+    expect((await program.account.vault.fetch(queueVaultPda)).mintedShares.toNumber()).toBe(50);
+  });
 });
 
 // =============================================================================
@@ -1475,7 +5073,7 @@ describe("Fuzz Invariant Engine", () => {
     );
 
     [fuzzVaultPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("vault"), fuzzOwner.publicKey.toBuffer()],
+      [Buffer.from("vault"), fuzzOwner.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
       program.programId
     );
 
@@ -1563,7 +5161,7 @@ describe("Fuzz Invariant Engine", () => {
 
     // Initialize fuzz test vault with 10% fee
     await program.methods
-      .initializeVault("Fuzz Test Vault", new anchor.BN(100_000_000), new anchor.BN(100), 1000)
+      .initializeVault(new anchor.BN(0), "Fuzz Test Vault", new anchor.BN(100_000_000), new anchor.BN(100), 1000, new anchor.BN(0), new anchor.BN(0), new anchor.BN(0))
       .accounts({
         owner: fuzzOwner.publicKey,
         vault: fuzzVaultPda,
@@ -1575,6 +5173,7 @@ describe("Fuzz Invariant Engine", () => {
         vaultShareMint: fuzzVaultShareMintPda,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
       })
@@ -1618,6 +5217,8 @@ describe("Fuzz Invariant Engine", () => {
                   investorShareAta: fuzzUserShareAtas[userIdx],
                   associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
                   tokenProgram: TOKEN_PROGRAM_ID,
+                  paymentMint: fuzzPaymentMint,
+                  shareTokenProgram: TOKEN_PROGRAM_ID,
                   systemProgram: SystemProgram.programId,
                 })
                 .signers([user])
@@ -1640,6 +5241,8 @@ describe("Fuzz Invariant Engine", () => {
                   investorShareAta: fuzzUserShareAtas[userIdx],
                   associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
                   tokenProgram: TOKEN_PROGRAM_ID,
+                  paymentMint: fuzzPaymentMint,
+                  shareTokenProgram: TOKEN_PROGRAM_ID,
                   systemProgram: SystemProgram.programId,
                 })
                 .signers([user])
@@ -1661,6 +5264,7 @@ describe("Fuzz Invariant Engine", () => {
                   treasury: fuzzTreasuryPda,
                   vaultSigner: fuzzVaultSignerPda,
                   tokenProgram: TOKEN_PROGRAM_ID,
+                  paymentMint: fuzzPaymentMint,
                 })
                 .signers([(payer as anchor.Wallet).payer])
                 .rpc();
@@ -1679,6 +5283,7 @@ describe("Fuzz Invariant Engine", () => {
                   revenueVault: fuzzRevenueVaultPda,
                   userAta: fuzzUserAtas[userIdx],
                   tokenProgram: TOKEN_PROGRAM_ID,
+                  paymentMint: fuzzPaymentMint,
                 })
                 .signers([user])
                 .rpc();
@@ -1704,6 +5309,8 @@ describe("Fuzz Invariant Engine", () => {
                       vaultShareMint: fuzzVaultShareMintPda,
                       payerAta: fuzzUserAtas[userIdx],
                       tokenProgram: TOKEN_PROGRAM_ID,
+                      paymentMint: fuzzPaymentMint,
+                      shareTokenProgram: TOKEN_PROGRAM_ID,
                     })
                     .signers([user])
                     .rpc();
@@ -101,8 +101,13 @@ async function main() {
   const program = new Program(idl, provider);
 
   // Derive PDAs
+  const vaultId = new anchor.BN(0);
   const [vaultPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("vault"), walletKeypair.publicKey.toBuffer()],
+    [
+      Buffer.from("vault"),
+      walletKeypair.publicKey.toBuffer(),
+      vaultId.toArrayLike(Buffer, "le", 8),
+    ],
     PROGRAM_ID
   );
 
@@ -190,10 +195,14 @@ async function main() {
     console.log("   Initializing vault...");
     const initTx = await program.methods
       .initializeVault(
+        vaultId,
         "Production Vault",
         new anchor.BN(1_000_000_000), // 1B shares
         new anchor.BN(100), // 100 tokens per share
-        500 // 5% fee
+        500, // 5% fee
+        new anchor.BN(0), // subscription opens immediately
+        new anchor.BN(0), // open-ended subscription
+        new anchor.BN(0) // no soft cap
       )
       .accounts({
         owner: walletKeypair.publicKey,
@@ -206,6 +215,7 @@ async function main() {
         vaultShareMint: vaultShareMintPda,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: anchor.web3.SystemProgram.programId,
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
       })
@@ -240,11 +250,11 @@ async function main() {
     console.log("\n🔐 GOVERNANCE FINALIZATION FLOW");
     console.log("=".repeat(40));
 
-    // Step 1: Update performance fee
-    console.log("\n📝 Step 1: Update Performance Fee to 10%");
+    // Step 1: Lower performance fee (increases are timelocked)
+    console.log("\n📝 Step 1: Lower Performance Fee to 2.5%");
     try {
       const feeTx = await program.methods
-        .updatePerformanceFee(1000) // 10%
+        .updatePerformanceFee(250) // 2.5%
         .accounts({
           vault: vaultPda,
           authority: walletKeypair.publicKey,
@@ -270,13 +280,22 @@ async function main() {
       const fundTx = await connection.requestAirdrop(testAuthority.publicKey, 0.1 * 1e9);
       await connection.confirmTransaction(fundTx);
 
-      const transferTx = await program.methods
-        .transferAuthority(testAuthority.publicKey)
+      await program.methods
+        .proposeAuthority(testAuthority.publicKey)
         .accounts({
           vault: vaultPda,
           authority: walletKeypair.publicKey,
         })
         .rpc();
+
+      const transferTx = await program.methods
+        .acceptAuthority()
+        .accounts({
+          vault: vaultPda,
+          newAuthority: testAuthority.publicKey,
+        })
+        .signers([testAuthority])
+        .rpc();
       
       console.log(`   ✅ Authority transferred: ${transferTx}`);
       console.log(`   New Authority: ${testAuthority.publicKey.toBase58()}`);
@@ -284,14 +303,22 @@ async function main() {
       report.governance.authorityTransferTx = transferTx;
 
       // Transfer back for revocation
-      const transferBackTx = await program.methods
-        .transferAuthority(walletKeypair.publicKey)
+      await program.methods
+        .proposeAuthority(walletKeypair.publicKey)
         .accounts({
           vault: vaultPda,
           authority: testAuthority.publicKey,
         })
         .signers([testAuthority])
         .rpc();
+
+      const transferBackTx = await program.methods
+        .acceptAuthority()
+        .accounts({
+          vault: vaultPda,
+          newAuthority: walletKeypair.publicKey,
+        })
+        .rpc();
       
       console.log(`   ✅ Authority transferred back: ${transferBackTx}`);
     } catch (e: any) {
@@ -325,7 +352,7 @@ async function main() {
     console.log("   Testing update_performance_fee...");
     try {
       await program.methods
-        .updatePerformanceFee(500)
+        .updatePerformanceFee(100)
         .accounts({
           vault: vaultPda,
           authority: walletKeypair.publicKey,
@@ -337,11 +364,11 @@ async function main() {
       report.governance.postRevokeFailures.push("update_performance_fee: BLOCKED");
     }
 
-    // Try transfer_authority
-    console.log("   Testing transfer_authority...");
+    // Try propose_authority
+    console.log("   Testing propose_authority...");
     try {
       await program.methods
-        .transferAuthority(testAuthority.publicKey)
+        .proposeAuthority(testAuthority.publicKey)
         .accounts({
           vault: vaultPda,
           authority: walletKeypair.publicKey,
@@ -350,7 +377,7 @@ async function main() {
       console.log("   ❌ UNEXPECTED: Transfer succeeded!");
     } catch (e: any) {
       console.log(`   ✅ Transfer blocked: ${e.message.slice(0, 50)}...`);
-      report.governance.postRevokeFailures.push("transfer_authority: BLOCKED");
+      report.governance.postRevokeFailures.push("propose_authority: BLOCKED");
     }
 
     // Try revoke_authority again
@@ -35,9 +35,12 @@ const PROGRAM_ID = process.env.NEXT_PUBLIC_PROGRAM_ID || "HZFSmaksGBkhV1eFUbvnAm
 const NETWORK = process.env.NEXT_PUBLIC_SOLANA_NETWORK || "devnet";
 
 // PDA derivation helper
-function deriveVaultPDAs(owner: PublicKey, programId: PublicKey) {
+function deriveVaultPDAs(owner: PublicKey, programId: PublicKey, vaultId: bigint = BigInt(0)) {
+  const vaultIdBytes = Buffer.alloc(8);
+  vaultIdBytes.writeBigUInt64LE(vaultId);
+
   const [vaultPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from("vault"), owner.toBuffer()],
+    [Buffer.from("vault"), owner.toBuffer(), vaultIdBytes],
     programId
   );
 